@@ -0,0 +1,349 @@
+//! Procedural macros for `feat`.
+//!
+//! These are re-exported by `feat` when its `macros` feature is enabled, and should
+//! be used through it.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+    FnArg, GenericParam, Ident, ItemFn, Pat, PatIdent, PatType, Path, Token, Type,
+};
+
+/// The targets accepted by `specialize`, and the names of their tokens.
+const TARGETS: &[(&str, &str)] = &[
+    ("sse", "Sse"),
+    ("sse2", "Sse2"),
+    ("sse3", "Sse3"),
+    ("ssse3", "Ssse3"),
+    ("sse41", "Sse41"),
+    ("sse42", "Sse42"),
+    ("avx", "Avx"),
+    ("avx2", "Avx2"),
+    ("fma", "Fma"),
+    ("avx512f", "Avx512F"),
+    ("avx512bw", "Avx512BW"),
+    ("avx512dq", "Avx512DQ"),
+    ("avx512vl", "Avx512VL"),
+    ("avx512vbmi", "Avx512Vbmi"),
+    ("x86_64_v2", "X86V2"),
+    ("x86_64_v3", "X86V3"),
+    ("x86_64_v4", "X86V4"),
+];
+
+/// The name of the target without any extra features.
+const FALLBACK: &str = "fallback";
+
+// Documented on the re-export in `feat`, so that the examples are tested there.
+#[proc_macro_attribute]
+pub fn specialize(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let targets = parse_macro_input!(attr as Targets);
+    let item = parse_macro_input!(item as ItemFn);
+
+    match expand(targets, item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+/// A target to specialize for.
+enum Target {
+    /// A target with a token.
+    Token { name: Ident, token: Ident },
+    /// The target without any extra features.
+    Fallback,
+}
+
+/// The arguments given to `specialize`.
+struct Targets {
+    targets: Vec<Target>,
+    /// The path to `feat`, from `crate = path`.
+    krate: Path,
+}
+
+impl Parse for Targets {
+    fn parse(input: ParseStream) -> syn::Result<Targets> {
+        let mut names = Vec::<Ident>::new();
+        let mut krate = None;
+
+        while !input.is_empty() {
+            if input.peek(Token![crate]) {
+                let keyword = input.parse::<Token![crate]>()?;
+                input.parse::<Token![=]>()?;
+
+                if krate.is_some() {
+                    return Err(syn::Error::new(
+                        keyword.span(),
+                        "the path to `feat` is given more than once",
+                    ));
+                }
+
+                krate = Some(input.parse::<Path>()?);
+            } else {
+                names.push(input.parse()?);
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        if names.is_empty() {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "expected at least one target to specialize for",
+            ));
+        }
+
+        let mut seen = Vec::<String>::new();
+        let mut targets = Vec::new();
+
+        for name in names {
+            let string = name.to_string();
+
+            if seen.contains(&string) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("the target `{string}` is given more than once"),
+                ));
+            }
+
+            if matches!(targets.last(), Some(Target::Fallback)) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("`{FALLBACK}` must be the last target"),
+                ));
+            }
+
+            if string == FALLBACK {
+                targets.push(Target::Fallback);
+            } else if let Some((_, token)) = TARGETS.iter().find(|(target, _)| *target == string) {
+                targets.push(Target::Token {
+                    token: Ident::new(token, name.span()),
+                    name,
+                });
+            } else {
+                let expected = TARGETS
+                    .iter()
+                    .map(|(target, _)| format!("`{target}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!(
+                        "unknown target `{string}`, expected one of {expected} or `{FALLBACK}`"
+                    ),
+                ));
+            }
+
+            seen.push(string);
+        }
+
+        Ok(Targets {
+            targets,
+            krate: krate.unwrap_or_else(|| syn::parse_quote!(::feat)),
+        })
+    }
+}
+
+fn expand(Targets { targets, krate }: Targets, item: ItemFn) -> syn::Result<TokenStream2> {
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+
+    if let Some(constness) = sig.constness {
+        return Err(syn::Error::new(
+            constness.span(),
+            "`const` functions cannot be specialized",
+        ));
+    }
+
+    if let Some(asyncness) = sig.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            "`async` functions cannot be specialized",
+        ));
+    }
+
+    let name = &sig.ident;
+    let token = Ident::new("token", Span::call_site());
+    let has_receiver = sig.receiver().is_some();
+
+    // The signature of the dispatching function, with every argument bound to an
+    // identifier so that it can be forwarded.
+    let mut outer = sig.clone();
+    let mut forward = Vec::new();
+
+    for (index, input) in outer.inputs.iter_mut().enumerate() {
+        match input {
+            FnArg::Receiver(receiver) => {
+                if receiver.reference.is_none() {
+                    receiver.mutability = None;
+                }
+
+                forward.push(quote!(self));
+            }
+            FnArg::Typed(PatType { pat, .. }) => {
+                let ident = match &**pat {
+                    Pat::Ident(PatIdent {
+                        ident,
+                        subpat: None,
+                        by_ref: None,
+                        ..
+                    }) => ident.clone(),
+                    _ => format_ident!("__arg{}", index),
+                };
+
+                **pat = Pat::Ident(PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: ident.clone(),
+                    subpat: None,
+                });
+                forward.push(quote!(#ident));
+            }
+        }
+    }
+
+    // Generic parameters are forwarded explicitly, unless `impl Trait` is used.
+    let has_impl_trait = sig.inputs.iter().any(|input| match input {
+        FnArg::Typed(PatType { ty, .. }) => matches!(**ty, Type::ImplTrait(_)),
+        FnArg::Receiver(_) => false,
+    });
+    let turbofish = {
+        let params = sig
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => Some(&param.ident),
+                GenericParam::Const(param) => Some(&param.ident),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        if params.is_empty() || has_impl_trait {
+            quote!()
+        } else {
+            quote!(::<#(#params),*>)
+        }
+    };
+
+    let mut copies = Vec::new();
+    let mut branches = Vec::new();
+    let mut has_fallback = false;
+
+    for target in &targets {
+        let (copy_name, token_ty) = match target {
+            Target::Token {
+                name: target,
+                token,
+            } => (
+                format_ident!("__{}_{}", name, target),
+                quote!(#krate::__private::x86::#token),
+            ),
+            Target::Fallback => (format_ident!("__{}_{}", name, FALLBACK), quote!(())),
+        };
+
+        // The copy takes the token right after the receiver, if there is one. It keeps
+        // the `unsafe` of the original, as its body may rely on the caller's contract.
+        let mut copy = sig.clone();
+        copy.ident = copy_name.clone();
+        copy.inputs.insert(
+            usize::from(has_receiver),
+            syn::parse_quote!(#[allow(unused_variables)] #token: #token_ty),
+        );
+
+        let path = if has_receiver {
+            quote!(Self::#copy_name)
+        } else {
+            quote!(#copy_name)
+        };
+
+        match target {
+            Target::Token {
+                token: token_name, ..
+            } => {
+                copies.push(quote! {
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    #krate::__dispatch_target_feature! {
+                        #token_name
+                        #[doc(hidden)]
+                        #copy #block
+                    }
+                });
+
+                let mut args = forward.clone();
+                args.insert(usize::from(has_receiver), quote!(#token));
+
+                branches.push(quote! {
+                    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+                    if let ::core::option::Option::Some(#token) = <#token_ty>::try_new() {
+                        // SAFETY: The token proves that the required features are available.
+                        return unsafe { #path #turbofish(#(#args),*) };
+                    }
+                });
+            }
+            Target::Fallback => {
+                has_fallback = true;
+
+                let mut args = forward.clone();
+                args.insert(usize::from(has_receiver), quote!(()));
+
+                copies.push(quote! {
+                    #[doc(hidden)]
+                    #copy #block
+                });
+
+                branches.push(if sig.unsafety.is_some() {
+                    quote! {
+                        // SAFETY: The caller upholds the contract of this function.
+                        unsafe { #path #turbofish(#(#args),*) }
+                    }
+                } else {
+                    quote! {
+                        #path #turbofish(#(#args),*)
+                    }
+                });
+            }
+        }
+    }
+
+    if !has_fallback {
+        let message = format!("no specialization of `{name}` is supported by the current CPU");
+
+        branches.push(quote! {
+            ::core::panic!(#message)
+        });
+    }
+
+    Ok(if has_receiver {
+        quote! {
+            #(#copies)*
+
+            #(#attrs)*
+            #vis #outer {
+                #(#branches)*
+            }
+        }
+    } else {
+        quote! {
+            #(#attrs)*
+            #vis #outer {
+                #(#copies)*
+
+                #(#branches)*
+            }
+        }
+    })
+}
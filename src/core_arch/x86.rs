@@ -6,7 +6,63 @@ pub(crate) use ::core::arch::x86_64 as raw;
 #[doc(inline)]
 pub(crate) use ::core::arch::x86 as raw;
 
+pub(crate) mod detect;
+pub use detect::UnsupportedError;
+
+mod feature;
+pub use feature::{Feature, FeatureSet, Iter as FeatureSetIter, ParseFeatureError};
+mod token;
+pub use token::{Has, Token};
+
 mod sse;
 pub use sse::*;
 
+mod sse2;
+pub use sse2::*;
+
+mod sse3;
+pub use sse3::*;
+
+mod ssse3;
+pub use ssse3::*;
+
+mod sse41;
+pub use sse41::*;
+
+mod sse42;
+pub use sse42::*;
+
+mod avx;
+pub use avx::*;
+
+mod avx2;
+pub use avx2::*;
+
+mod fma;
+pub use fma::*;
+
+mod kmask;
+pub use kmask::*;
+
+mod avx512f;
+pub use avx512f::*;
+
+mod avx512bw;
+pub use avx512bw::*;
+
+mod avx512dq;
+pub use avx512dq::*;
+
+mod avx512vl;
+pub use avx512vl::*;
+
+mod avx512vbmi;
+pub use avx512vbmi::*;
+
+mod level;
+pub use level::*;
+
+#[cfg(test)]
+mod test;
+
 pub mod float;
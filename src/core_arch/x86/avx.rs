@@ -0,0 +1,1065 @@
+use crate::types::{
+    mask::{m32x4, m32x8, m64x2, m64x4},
+    vector::{f32x4, f32x8, f64x2, f64x4, i32x4, i32x8, i64x2, i64x4},
+};
+
+use super::{
+    float::{cmp_imm, Cmp},
+    raw::{
+        _mm256_add_pd, _mm256_add_ps, _mm256_addsub_pd, _mm256_addsub_ps, _mm256_blend_pd,
+        _mm256_blend_ps, _mm256_blendv_pd, _mm256_blendv_ps, _mm256_broadcast_pd,
+        _mm256_broadcast_ps, _mm256_broadcast_sd, _mm256_broadcast_ss, _mm256_castpd_si256,
+        _mm256_castps_si256, _mm256_castsi256_pd, _mm256_castsi256_ps, _mm256_ceil_pd,
+        _mm256_ceil_ps, _mm256_cmp_pd, _mm256_cmp_ps, _mm256_div_pd, _mm256_div_ps,
+        _mm256_floor_pd, _mm256_floor_ps, _mm256_hadd_pd, _mm256_hadd_ps, _mm256_hsub_pd,
+        _mm256_hsub_ps, _mm256_max_pd, _mm256_max_ps, _mm256_min_pd, _mm256_min_ps,
+        _mm256_movemask_pd, _mm256_movemask_ps, _mm256_mul_pd, _mm256_mul_ps,
+        _mm256_permute2f128_pd, _mm256_permute2f128_ps, _mm256_permute_pd, _mm256_permute_ps,
+        _mm256_permutevar_pd, _mm256_permutevar_ps, _mm256_rcp_ps, _mm256_round_pd,
+        _mm256_round_ps, _mm256_rsqrt_ps, _mm256_shuffle_pd, _mm256_shuffle_ps, _mm256_sqrt_pd,
+        _mm256_sqrt_ps, _mm256_sub_pd, _mm256_sub_ps, _mm256_unpackhi_pd, _mm256_unpackhi_ps,
+        _mm256_unpacklo_pd, _mm256_unpacklo_ps, _mm_broadcast_ss, _mm_castpd_si128,
+        _mm_castps_si128, _mm_cmp_pd, _mm_cmp_ps, _mm_permute_pd, _mm_permute_ps,
+        _mm_permutevar_pd, _mm_permutevar_ps,
+    },
+    token::token,
+};
+
+token! {
+    /// A token proving that AVX is available.
+    pub struct Avx("AVX") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Avx = "avx",
+    }
+}
+
+// Floating point arithmetic.
+impl Avx {
+    #[doc(alias = "_mm256_add_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_add_ps(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_sub_ps(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mul_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_mul_ps(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_div_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_div_ps(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_min_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_min_ps(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_max_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_max_ps(a.avx, b.avx) }.into()
+    }
+
+    /// Subtract the even lanes and add the odd lanes.
+    #[doc(alias = "_mm256_addsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn addsub_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_addsub_ps(a.avx, b.avx) }.into()
+    }
+
+    /// Horizontally add adjacent pairs of lanes within each 128-bit half.
+    #[doc(alias = "_mm256_hadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hadd_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_hadd_ps(a.avx, b.avx) }.into()
+    }
+
+    /// Horizontally subtract adjacent pairs of lanes within each 128-bit half.
+    #[doc(alias = "_mm256_hsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hsub_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_hsub_ps(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sqrt_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f32x8(self, a: f32x8) -> f32x8 {
+        unsafe { _mm256_sqrt_ps(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_floor_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn floor_f32x8(self, a: f32x8) -> f32x8 {
+        unsafe { _mm256_floor_ps(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_ceil_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ceil_f32x8(self, a: f32x8) -> f32x8 {
+        unsafe { _mm256_ceil_ps(a.avx) }.into()
+    }
+
+    /// Round every lane according to `ROUNDING`, which is made up of the
+    /// `_MM_FROUND_*` constants.
+    #[doc(alias = "_mm256_round_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn round_f32x8<const ROUNDING: i32>(self, a: f32x8) -> f32x8 {
+        unsafe { _mm256_round_ps::<ROUNDING>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_add_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_add_pd(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_sub_pd(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mul_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_mul_pd(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_div_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_div_pd(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_min_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_min_pd(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_max_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_max_pd(a.avx, b.avx) }.into()
+    }
+
+    /// Subtract the even lanes and add the odd lanes.
+    #[doc(alias = "_mm256_addsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn addsub_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_addsub_pd(a.avx, b.avx) }.into()
+    }
+
+    /// Horizontally add adjacent pairs of lanes within each 128-bit half.
+    #[doc(alias = "_mm256_hadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hadd_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_hadd_pd(a.avx, b.avx) }.into()
+    }
+
+    /// Horizontally subtract adjacent pairs of lanes within each 128-bit half.
+    #[doc(alias = "_mm256_hsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hsub_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_hsub_pd(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sqrt_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f64x4(self, a: f64x4) -> f64x4 {
+        unsafe { _mm256_sqrt_pd(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_floor_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn floor_f64x4(self, a: f64x4) -> f64x4 {
+        unsafe { _mm256_floor_pd(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_ceil_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ceil_f64x4(self, a: f64x4) -> f64x4 {
+        unsafe { _mm256_ceil_pd(a.avx) }.into()
+    }
+
+    /// Round every lane according to `ROUNDING`, which is made up of the
+    /// `_MM_FROUND_*` constants.
+    #[doc(alias = "_mm256_round_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn round_f64x4<const ROUNDING: i32>(self, a: f64x4) -> f64x4 {
+        unsafe { _mm256_round_pd::<ROUNDING>(a.avx) }.into()
+    }
+
+    /// Approximate the reciprocal of every lane.
+    ///
+    /// The maximum relative error is less than 1.5 * 2^-12.
+    #[doc(alias = "_mm256_rcp_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn recip_f32x8(self, a: f32x8) -> f32x8 {
+        unsafe { _mm256_rcp_ps(a.avx) }.into()
+    }
+
+    /// Approximate the reciprocal of the square root of every lane.
+    ///
+    /// The maximum relative error is less than 1.5 * 2^-12.
+    #[doc(alias = "_mm256_rsqrt_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn recip_sqrt_f32x8(self, a: f32x8) -> f32x8 {
+        unsafe { _mm256_rsqrt_ps(a.avx) }.into()
+    }
+}
+
+// Floating point comparisons.
+impl Avx {
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// When `cmp` is known at compile time, this compiles to a single comparison with an
+    /// immediate predicate.
+    #[doc(alias = "_mm256_cmp_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f32x8(self, a: f32x8, b: f32x8, cmp: Cmp) -> m32x8 {
+        let int = unsafe {
+            cmp_imm!(cmp, IMM => _mm256_castps_si256(_mm256_cmp_ps::<IMM>(a.avx, b.avx)))
+        };
+
+        // SAFETY: Every lane of a comparison result is either all ones or all zeros.
+        unsafe { m32x8::from_int_unchecked(int.into()) }
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// When `cmp` is known at compile time, this compiles to a single comparison with an
+    /// immediate predicate.
+    #[doc(alias = "_mm256_cmp_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f64x4(self, a: f64x4, b: f64x4, cmp: Cmp) -> m64x4 {
+        let int = unsafe {
+            cmp_imm!(cmp, IMM => _mm256_castpd_si256(_mm256_cmp_pd::<IMM>(a.avx, b.avx)))
+        };
+
+        // SAFETY: Every lane of a comparison result is either all ones or all zeros.
+        unsafe { m64x4::from_int_unchecked(int.into()) }
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// When `cmp` is known at compile time, this compiles to a single comparison with an
+    /// immediate predicate.
+    #[doc(alias = "_mm_cmp_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f32x4(self, a: f32x4, b: f32x4, cmp: Cmp) -> m32x4 {
+        let int =
+            unsafe { cmp_imm!(cmp, IMM => _mm_castps_si128(_mm_cmp_ps::<IMM>(a.sse, b.sse))) };
+
+        // SAFETY: Every lane of a comparison result is either all ones or all zeros.
+        unsafe { m32x4::from_int_unchecked(int.into()) }
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// When `cmp` is known at compile time, this compiles to a single comparison with an
+    /// immediate predicate.
+    #[doc(alias = "_mm_cmp_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f64x2(self, a: f64x2, b: f64x2, cmp: Cmp) -> m64x2 {
+        let int =
+            unsafe { cmp_imm!(cmp, IMM => _mm_castpd_si128(_mm_cmp_pd::<IMM>(a.sse, b.sse))) };
+
+        // SAFETY: Every lane of a comparison result is either all ones or all zeros.
+        unsafe { m64x2::from_int_unchecked(int.into()) }
+    }
+}
+
+// Sign bit extraction.
+//
+// Bit `i` of the result is the highest bit of lane `i`.
+impl Avx {
+    #[doc(alias = "_mm256_movemask_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn movemask_f32x8(self, a: f32x8) -> u8 {
+        unsafe { _mm256_movemask_ps(a.avx) as u8 }
+    }
+
+    #[doc(alias = "_mm256_movemask_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn movemask_f64x4(self, a: f64x4) -> u8 {
+        unsafe { _mm256_movemask_pd(a.avx) as u8 }
+    }
+}
+
+// Blends.
+impl Avx {
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm256_blend_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_f32x8<const IMM8: i32>(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_blend_ps::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the sign bit of the corresponding lane in `mask` is
+    /// set, and from `a` otherwise.
+    #[doc(alias = "_mm256_blendv_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blendv_f32x8(self, a: f32x8, b: f32x8, mask: i32x8) -> f32x8 {
+        unsafe { _mm256_blendv_ps(a.avx, b.avx, _mm256_castsi256_ps(mask.avx)) }.into()
+    }
+
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm256_blend_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_f64x4<const IMM8: i32>(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_blend_pd::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the sign bit of the corresponding lane in `mask` is
+    /// set, and from `a` otherwise.
+    #[doc(alias = "_mm256_blendv_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blendv_f64x4(self, a: f64x4, b: f64x4, mask: i64x4) -> f64x4 {
+        unsafe { _mm256_blendv_pd(a.avx, b.avx, _mm256_castsi256_pd(mask.avx)) }.into()
+    }
+}
+
+// Permutes and shuffles.
+impl Avx {
+    /// Shuffle lanes within each 128-bit lane using the control in `IMM8`.
+    #[doc(alias = "_mm_permute_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_f32x4<const IMM8: i32>(self, a: f32x4) -> f32x4 {
+        unsafe { _mm_permute_ps::<IMM8>(a.sse) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit lane using the control in `idx`.
+    #[doc(alias = "_mm_permutevar_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_var_f32x4(self, a: f32x4, idx: i32x4) -> f32x4 {
+        unsafe { _mm_permutevar_ps(a.sse, idx.sse) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit lane using the control in `IMM8`.
+    #[doc(alias = "_mm_permute_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_f64x2<const IMM8: i32>(self, a: f64x2) -> f64x2 {
+        unsafe { _mm_permute_pd::<IMM8>(a.sse) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit lane using the control in `idx`.
+    #[doc(alias = "_mm_permutevar_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_var_f64x2(self, a: f64x2, idx: i64x2) -> f64x2 {
+        unsafe { _mm_permutevar_pd(a.sse, idx.sse) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit lane using the control in `IMM8`.
+    #[doc(alias = "_mm256_permute_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_f32x8<const IMM8: i32>(self, a: f32x8) -> f32x8 {
+        unsafe { _mm256_permute_ps::<IMM8>(a.avx) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit lane using the control in `idx`.
+    #[doc(alias = "_mm256_permutevar_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_var_f32x8(self, a: f32x8, idx: i32x8) -> f32x8 {
+        unsafe { _mm256_permutevar_ps(a.avx, idx.avx) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit lane using the control in `IMM8`.
+    #[doc(alias = "_mm256_permute_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_f64x4<const IMM8: i32>(self, a: f64x4) -> f64x4 {
+        unsafe { _mm256_permute_pd::<IMM8>(a.avx) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit lane using the control in `idx`.
+    #[doc(alias = "_mm256_permutevar_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_var_f64x4(self, a: f64x4, idx: i64x4) -> f64x4 {
+        unsafe { _mm256_permutevar_pd(a.avx, idx.avx) }.into()
+    }
+
+    /// Select each 128-bit half of the result from the halves of `a` and `b` using the
+    /// control in `IMM8`.
+    #[doc(alias = "_mm256_permute2f128_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_halves_f32x8<const IMM8: i32>(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_permute2f128_ps::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    /// Shuffle lanes from `a` and `b` within each 128-bit lane using the control in `IMM8`.
+    #[doc(alias = "_mm256_shuffle_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle_f32x8<const IMM8: i32>(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_shuffle_ps::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_unpacklo_ps(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_f32x8(self, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_unpackhi_ps(a.avx, b.avx) }.into()
+    }
+
+    /// Select each 128-bit half of the result from the halves of `a` and `b` using the
+    /// control in `IMM8`.
+    #[doc(alias = "_mm256_permute2f128_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_halves_f64x4<const IMM8: i32>(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_permute2f128_pd::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    /// Shuffle lanes from `a` and `b` within each 128-bit lane using the control in `IMM8`.
+    #[doc(alias = "_mm256_shuffle_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle_f64x4<const IMM8: i32>(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_shuffle_pd::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_unpacklo_pd(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_f64x4(self, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_unpackhi_pd(a.avx, b.avx) }.into()
+    }
+}
+
+// Broadcasts.
+impl Avx {
+    #[doc(alias = "_mm_broadcast_ss")]
+    #[inline(always)]
+    #[must_use]
+    pub fn broadcast_f32x4(self, value: f32) -> f32x4 {
+        unsafe { _mm_broadcast_ss(&value) }.into()
+    }
+
+    #[doc(alias = "_mm256_broadcast_ss")]
+    #[inline(always)]
+    #[must_use]
+    pub fn broadcast_f32x8(self, value: f32) -> f32x8 {
+        unsafe { _mm256_broadcast_ss(&value) }.into()
+    }
+
+    #[doc(alias = "_mm256_broadcast_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn broadcast_f64x4(self, value: f64) -> f64x4 {
+        unsafe { _mm256_broadcast_sd(&value) }.into()
+    }
+
+    /// Copy `value` into both halves of a vector.
+    #[doc(alias = "_mm256_broadcast_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn broadcast_f32x4_to_f32x8(self, value: f32x4) -> f32x8 {
+        unsafe { _mm256_broadcast_ps(&value.sse) }.into()
+    }
+
+    /// Copy `value` into both halves of a vector.
+    #[doc(alias = "_mm256_broadcast_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn broadcast_f64x2_to_f64x4(self, value: f64x2) -> f64x4 {
+        unsafe { _mm256_broadcast_pd(&value.sse) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::{f32x4, f32x8, f64x2, f64x4, i32x4, i32x8, i64x2, i64x4},
+        util::test::{assert_lanes, Rng},
+    };
+
+    use super::{
+        super::{
+            float::Cmp,
+            raw::{
+                _MM_FROUND_NO_EXC, _MM_FROUND_TO_NEAREST_INT, _MM_FROUND_TO_NEG_INF,
+                _MM_FROUND_TO_POS_INF, _MM_FROUND_TO_ZERO,
+            },
+            test::{lane_wise, token_or_skip, unpacks, ROUNDS},
+        },
+        Avx,
+    };
+
+    #[test]
+    fn arith() {
+        let token = token_or_skip!(Avx);
+
+        lane_wise! {
+            token;
+            add_f32x8 (a, b): f32x8 => a + b;
+            sub_f32x8 (a, b): f32x8 => a - b;
+            mul_f32x8 (a, b): f32x8 => a * b;
+            div_f32x8 (a, b): f32x8 => a / b;
+            min_f32x8 (a, b): f32x8 => if a < b { a } else { b };
+            max_f32x8 (a, b): f32x8 => if a > b { a } else { b };
+            sqrt_f32x8 (a): f32x8 => a.sqrt();
+            floor_f32x8 (a): f32x8 => a.floor();
+            ceil_f32x8 (a): f32x8 => a.ceil();
+
+            add_f64x4 (a, b): f64x4 => a + b;
+            sub_f64x4 (a, b): f64x4 => a - b;
+            mul_f64x4 (a, b): f64x4 => a * b;
+            div_f64x4 (a, b): f64x4 => a / b;
+            min_f64x4 (a, b): f64x4 => if a < b { a } else { b };
+            max_f64x4 (a, b): f64x4 => if a > b { a } else { b };
+            sqrt_f64x4 (a): f64x4 => a.sqrt();
+            floor_f64x4 (a): f64x4 => a.floor();
+            ceil_f64x4 (a): f64x4 => a.ceil();
+        }
+    }
+
+    #[test]
+    fn round() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        macro_rules! check {
+            ($($rounding:ident => $model:ident),*) => {$({
+                const ROUNDING: i32 = $rounding | _MM_FROUND_NO_EXC;
+
+                for _ in 0..ROUNDS {
+                    let a: [f32; 8] = rng.array();
+                    let b: [f64; 4] = rng.array();
+
+                    assert_lanes(
+                        &token.round_f32x8::<ROUNDING>(f32x8::from_array(a)).to_array(),
+                        &a.map(|a| a.$model()),
+                        (stringify!($rounding), a),
+                    );
+                    assert_lanes(
+                        &token.round_f64x4::<ROUNDING>(f64x4::from_array(b)).to_array(),
+                        &b.map(|b| b.$model()),
+                        (stringify!($rounding), b),
+                    );
+                }
+            })*};
+        }
+
+        check! {
+            _MM_FROUND_TO_NEAREST_INT => round_ties_even,
+            _MM_FROUND_TO_NEG_INF => floor,
+            _MM_FROUND_TO_POS_INF => ceil,
+            _MM_FROUND_TO_ZERO => trunc
+        }
+    }
+
+    #[test]
+    fn horizontal() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        // Within every 128-bit lane, the even lanes subtract and the odd lanes add, and the
+        // horizontal operations take the pairs of `a` and then those of `b`.
+        macro_rules! check {
+            ($($vector:ident: $addsub:ident, $hadd:ident, $hsub:ident;)*) => {$(
+                for _ in 0..ROUNDS {
+                    let a = $vector::from_array(rng.array()).to_array();
+                    let b = $vector::from_array(rng.array()).to_array();
+                    let size = 16 / core::mem::size_of_val(&a[0]);
+                    let horizontal = |f: fn(_, _) -> _| -> Vec<_> {
+                        a.chunks(size)
+                            .zip(b.chunks(size))
+                            .flat_map(|(a, b)| {
+                                a.chunks(2).chain(b.chunks(2)).map(move |pair| f(pair[0], pair[1]))
+                            })
+                            .collect()
+                    };
+                    let addsub: Vec<_> = (0..a.len())
+                        .map(|i| if i % 2 == 0 { a[i] - b[i] } else { a[i] + b[i] })
+                        .collect();
+                    let (sum, difference) = (horizontal(|x, y| x + y), horizontal(|x, y| x - y));
+                    let (va, vb) = ($vector::from_array(a), $vector::from_array(b));
+
+                    assert_lanes(&token.$addsub(va, vb).to_array(), &addsub, (a, b));
+                    assert_lanes(&token.$hadd(va, vb).to_array(), &sum, (a, b));
+                    assert_lanes(&token.$hsub(va, vb).to_array(), &difference, (a, b));
+                }
+            )*};
+        }
+
+        check! {
+            f32x8: addsub_f32x8, hadd_f32x8, hsub_f32x8;
+            f64x4: addsub_f64x4, hadd_f64x4, hsub_f64x4;
+        }
+    }
+
+    #[test]
+    fn recip() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+        let max_error = 1.5 / 4096.0;
+
+        for _ in 0..ROUNDS {
+            // Keep the lanes normal, as are their reciprocals.
+            let a: [f32; 8] = rng.array::<f32, 8>().map(|a| {
+                if a.is_normal() && a.abs() < 1e37 {
+                    a
+                } else {
+                    1.0
+                }
+            });
+            let recip = token.recip_f32x8(f32x8::from_array(a)).to_array();
+            let recip_sqrt = token
+                .recip_sqrt_f32x8(f32x8::from_array(a.map(f32::abs)))
+                .to_array();
+
+            for i in 0..8 {
+                let expected = 1.0 / a[i] as f64;
+                let error = ((recip[i] as f64 - expected) / expected).abs();
+
+                assert!(error < max_error, "{} for {}", recip[i], a[i]);
+
+                let expected = 1.0 / (a[i].abs() as f64).sqrt();
+                let error = ((recip_sqrt[i] as f64 - expected) / expected).abs();
+
+                assert!(error < max_error, "{} for {}", recip_sqrt[i], a[i]);
+            }
+        }
+
+        let special =
+            f32x8::from_array([0.0, f32::INFINITY, 0.0, f32::INFINITY, 0.0, 0.0, 0.0, 0.0]);
+
+        assert_eq!(
+            token.recip_f32x8(special).to_array()[..2],
+            [f32::INFINITY, 0.0]
+        );
+        assert_eq!(
+            token.recip_sqrt_f32x8(special).to_array()[..2],
+            [f32::INFINITY, 0.0]
+        );
+    }
+
+    #[test]
+    fn compare() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        macro_rules! check {
+            ($($method:ident: $vector:ident => $eval:ident;)*) => {$(
+                for _ in 0..ROUNDS {
+                    let a = $vector::from_array(rng.array()).to_array();
+                    let mut b = $vector::from_array(rng.array()).to_array();
+
+                    // Make sure that some of the lanes are equal.
+                    for (a, b) in a.iter().zip(&mut b) {
+                        if rng.gen() {
+                            *b = *a;
+                        }
+                    }
+
+                    for cmp in (0..32).filter_map(Cmp::from_i32) {
+                        let expected: Vec<bool> =
+                            (0..a.len()).map(|i| cmp.$eval(a[i], b[i])).collect();
+                        let actual =
+                            token.$method($vector::from_array(a), $vector::from_array(b), cmp);
+
+                        assert_lanes(&actual.to_bools(), &expected, (cmp, a, b));
+                    }
+                }
+            )*};
+        }
+
+        check! {
+            cmp_f32x4: f32x4 => eval_f32;
+            cmp_f32x8: f32x8 => eval_f32;
+            cmp_f64x2: f64x2 => eval_f64;
+            cmp_f64x4: f64x4 => eval_f64;
+        }
+    }
+
+    #[test]
+    fn movemask() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        for _ in 0..ROUNDS {
+            let a: [f32; 8] = rng.array();
+            let expected = (0..8).fold(0, |bits, i| bits | (a[i].is_sign_negative() as u8) << i);
+
+            assert_eq!(
+                token.movemask_f32x8(f32x8::from_array(a)),
+                expected,
+                "{a:?}"
+            );
+
+            let a: [f64; 4] = rng.array();
+            let expected = (0..4).fold(0, |bits, i| bits | (a[i].is_sign_negative() as u8) << i);
+
+            assert_eq!(
+                token.movemask_f64x4(f64x4::from_array(a)),
+                expected,
+                "{a:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn blend() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        macro_rules! check {
+            ($($imm:literal),*) => {$(
+                let a: [f32; 8] = rng.array();
+                let b: [f32; 8] = rng.array();
+
+                assert_lanes(
+                    &token
+                        .blend_f32x8::<$imm>(f32x8::from_array(a), f32x8::from_array(b))
+                        .to_array(),
+                    &core::array::from_fn::<_, 8, _>(|i| {
+                        if $imm >> i & 1 != 0 { b[i] } else { a[i] }
+                    }),
+                    ($imm, a, b),
+                );
+
+                let a: [f64; 4] = rng.array();
+                let b: [f64; 4] = rng.array();
+
+                assert_lanes(
+                    &token
+                        .blend_f64x4::<{ $imm & 0xf }>(f64x4::from_array(a), f64x4::from_array(b))
+                        .to_array(),
+                    &core::array::from_fn::<_, 4, _>(|i| {
+                        if $imm >> i & 1 != 0 { b[i] } else { a[i] }
+                    }),
+                    ($imm, a, b),
+                );
+            )*};
+        }
+
+        check!(0x00, 0x01, 0x5a, 0x80, 0xc3, 0xff);
+
+        for _ in 0..ROUNDS {
+            let (a, b, mask): ([f32; 8], [f32; 8], [i32; 8]) =
+                (rng.array(), rng.array(), rng.array());
+            let actual = token.blendv_f32x8(
+                f32x8::from_array(a),
+                f32x8::from_array(b),
+                i32x8::from_array(mask),
+            );
+            let expected: [f32; 8] =
+                core::array::from_fn(|i| if mask[i] < 0 { b[i] } else { a[i] });
+
+            assert_lanes(&actual.to_array(), &expected, (a, b, mask));
+
+            let (a, b, mask): ([f64; 4], [f64; 4], [i64; 4]) =
+                (rng.array(), rng.array(), rng.array());
+            let actual = token.blendv_f64x4(
+                f64x4::from_array(a),
+                f64x4::from_array(b),
+                i64x4::from_array(mask),
+            );
+            let expected: [f64; 4] =
+                core::array::from_fn(|i| if mask[i] < 0 { b[i] } else { a[i] });
+
+            assert_lanes(&actual.to_array(), &expected, (a, b, mask));
+        }
+    }
+
+    #[test]
+    fn permute() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        // Every 128-bit lane is permuted with the same control, which takes two bits per
+        // `f32` lane and one bit per `f64` lane.
+        macro_rules! check {
+            ($($imm:literal),*) => {$(
+                let a: [f32; 8] = rng.array();
+                let expected: [f32; 8] =
+                    core::array::from_fn(|i| a[i / 4 * 4 + ($imm >> (i % 4 * 2) & 3)]);
+
+                assert_lanes(
+                    &token
+                        .permute_f32x4::<$imm>(f32x4::from_array([a[0], a[1], a[2], a[3]]))
+                        .to_array(),
+                    &expected[..4],
+                    ($imm, a),
+                );
+                assert_lanes(
+                    &token.permute_f32x8::<$imm>(f32x8::from_array(a)).to_array(),
+                    &expected,
+                    ($imm, a),
+                );
+
+                let a: [f64; 4] = rng.array();
+                let expected: [f64; 4] = core::array::from_fn(|i| a[i / 2 * 2 + ($imm >> i & 1)]);
+
+                assert_lanes(
+                    &token
+                        .permute_f64x2::<{ $imm & 3 }>(f64x2::from_array([a[0], a[1]]))
+                        .to_array(),
+                    &expected[..2],
+                    ($imm, a),
+                );
+                assert_lanes(
+                    &token.permute_f64x4::<{ $imm & 0xf }>(f64x4::from_array(a)).to_array(),
+                    &expected,
+                    ($imm, a),
+                );
+            )*};
+        }
+
+        check!(0x00, 0x1b, 0x4e, 0xa5, 0xe4, 0xff);
+
+        // The variable forms read the low two bits of every `i32` index, and the second
+        // bit of every `i64` index.
+        for _ in 0..ROUNDS {
+            let (a, idx): ([f32; 8], [i32; 8]) = (rng.array(), rng.array());
+            let expected: [f32; 8] = core::array::from_fn(|i| a[i / 4 * 4 + (idx[i] & 3) as usize]);
+
+            assert_lanes(
+                &token
+                    .permute_var_f32x4(
+                        f32x4::from_array([a[0], a[1], a[2], a[3]]),
+                        i32x4::from_array([idx[0], idx[1], idx[2], idx[3]]),
+                    )
+                    .to_array(),
+                &expected[..4],
+                (a, idx),
+            );
+            assert_lanes(
+                &token
+                    .permute_var_f32x8(f32x8::from_array(a), i32x8::from_array(idx))
+                    .to_array(),
+                &expected,
+                (a, idx),
+            );
+
+            let (a, idx): ([f64; 4], [i64; 4]) = (rng.array(), rng.array());
+            let expected: [f64; 4] =
+                core::array::from_fn(|i| a[i / 2 * 2 + (idx[i] >> 1 & 1) as usize]);
+
+            assert_lanes(
+                &token
+                    .permute_var_f64x2(
+                        f64x2::from_array([a[0], a[1]]),
+                        i64x2::from_array([idx[0], idx[1]]),
+                    )
+                    .to_array(),
+                &expected[..2],
+                (a, idx),
+            );
+            assert_lanes(
+                &token
+                    .permute_var_f64x4(f64x4::from_array(a), i64x4::from_array(idx))
+                    .to_array(),
+                &expected,
+                (a, idx),
+            );
+        }
+    }
+
+    #[test]
+    fn permute_halves() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        // Every half of the result takes four bits of the control, where the low two bits
+        // select one of the halves of `a` and `b`, and the highest bit zeroes it.
+        macro_rules! check {
+            ($($imm:literal),*) => {$(
+                let a: [f32; 8] = rng.array();
+                let b: [f32; 8] = rng.array();
+                let halves = [&a[..4], &a[4..], &b[..4], &b[4..]];
+                let expected: [f32; 8] = core::array::from_fn(|i| {
+                    let control = $imm >> (i / 4 * 4);
+                    if control & 8 != 0 { 0.0 } else { halves[control & 3][i % 4] }
+                });
+
+                assert_lanes(
+                    &token
+                        .permute_halves_f32x8::<$imm>(f32x8::from_array(a), f32x8::from_array(b))
+                        .to_array(),
+                    &expected,
+                    ($imm, a, b),
+                );
+
+                let a: [f64; 4] = rng.array();
+                let b: [f64; 4] = rng.array();
+                let halves = [&a[..2], &a[2..], &b[..2], &b[2..]];
+                let expected: [f64; 4] = core::array::from_fn(|i| {
+                    let control = $imm >> (i / 2 * 4);
+                    if control & 8 != 0 { 0.0 } else { halves[control & 3][i % 2] }
+                });
+
+                assert_lanes(
+                    &token
+                        .permute_halves_f64x4::<$imm>(f64x4::from_array(a), f64x4::from_array(b))
+                        .to_array(),
+                    &expected,
+                    ($imm, a, b),
+                );
+            )*};
+        }
+
+        check!(0x00, 0x01, 0x20, 0x31, 0x13, 0x08, 0x80, 0x32);
+    }
+
+    #[test]
+    fn shuffle() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        // Within every 128-bit lane, the lower half of the result comes from `a` and the
+        // upper half from `b` for `f32`, while `f64` alternates between them.
+        macro_rules! check {
+            ($($imm:literal),*) => {$(
+                let a: [f32; 8] = rng.array();
+                let b: [f32; 8] = rng.array();
+                let expected: [f32; 8] = core::array::from_fn(|i| {
+                    let source = if i % 4 < 2 { &a } else { &b };
+                    source[i / 4 * 4 + ($imm >> (i % 4 * 2) & 3)]
+                });
+
+                assert_lanes(
+                    &token
+                        .shuffle_f32x8::<$imm>(f32x8::from_array(a), f32x8::from_array(b))
+                        .to_array(),
+                    &expected,
+                    ($imm, a, b),
+                );
+
+                let a: [f64; 4] = rng.array();
+                let b: [f64; 4] = rng.array();
+                let expected: [f64; 4] = core::array::from_fn(|i| {
+                    let source = if i % 2 == 0 { &a } else { &b };
+                    source[i / 2 * 2 + ($imm >> i & 1)]
+                });
+
+                assert_lanes(
+                    &token
+                        .shuffle_f64x4::<$imm>(f64x4::from_array(a), f64x4::from_array(b))
+                        .to_array(),
+                    &expected,
+                    ($imm, a, b),
+                );
+            )*};
+        }
+
+        check!(0x00, 0x1b, 0x4e, 0xa5, 0xe4, 0xff);
+
+        unpacks! {
+            token;
+            unpack_lo_f32x8 / unpack_hi_f32x8: f32x8;
+            unpack_lo_f64x4 / unpack_hi_f64x4: f64x4;
+        }
+    }
+
+    #[test]
+    fn broadcast() {
+        let token = token_or_skip!(Avx);
+        let mut rng = Rng::new(1);
+
+        for _ in 0..ROUNDS {
+            let (a, b): (f32, f64) = (rng.gen(), rng.gen());
+
+            assert_lanes(&token.broadcast_f32x4(a).to_array(), &[a; 4], a);
+            assert_lanes(&token.broadcast_f32x8(a).to_array(), &[a; 8], a);
+            assert_lanes(&token.broadcast_f64x4(b).to_array(), &[b; 4], b);
+
+            let a: [f32; 4] = rng.array();
+            let b: [f64; 2] = rng.array();
+
+            assert_lanes(
+                &token
+                    .broadcast_f32x4_to_f32x8(f32x4::from_array(a))
+                    .to_array(),
+                &[a, a].concat(),
+                a,
+            );
+            assert_lanes(
+                &token
+                    .broadcast_f64x2_to_f64x4(f64x2::from_array(b))
+                    .to_array(),
+                &[b, b].concat(),
+                b,
+            );
+        }
+    }
+}
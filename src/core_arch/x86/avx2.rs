@@ -0,0 +1,1990 @@
+use crate::types::vector::{
+    f32x4, f32x8, f64x4, i16x16, i32x4, i32x8, i64x2, i64x4, i8x32, u16x16, u32x4, u32x8, u64x2,
+    u64x4, u8x32,
+};
+
+use super::{
+    raw::{
+        _mm256_abs_epi16, _mm256_abs_epi32, _mm256_abs_epi8, _mm256_add_epi16, _mm256_add_epi32,
+        _mm256_add_epi64, _mm256_add_epi8, _mm256_adds_epi16, _mm256_adds_epi8, _mm256_adds_epu16,
+        _mm256_adds_epu8, _mm256_and_si256, _mm256_andnot_si256, _mm256_avg_epu16, _mm256_avg_epu8,
+        _mm256_blend_epi16, _mm256_blend_epi32, _mm256_blendv_epi8, _mm256_cmpeq_epi16,
+        _mm256_cmpeq_epi32, _mm256_cmpeq_epi64, _mm256_cmpeq_epi8, _mm256_cmpgt_epi16,
+        _mm256_cmpgt_epi32, _mm256_cmpgt_epi64, _mm256_cmpgt_epi8, _mm256_i32gather_epi32,
+        _mm256_i32gather_epi64, _mm256_i32gather_pd, _mm256_i32gather_ps, _mm256_i64gather_epi32,
+        _mm256_i64gather_epi64, _mm256_i64gather_pd, _mm256_i64gather_ps, _mm256_madd_epi16,
+        _mm256_max_epi16, _mm256_max_epi32, _mm256_max_epi8, _mm256_max_epu16, _mm256_max_epu32,
+        _mm256_max_epu8, _mm256_min_epi16, _mm256_min_epi32, _mm256_min_epi8, _mm256_min_epu16,
+        _mm256_min_epu32, _mm256_min_epu8, _mm256_movemask_epi8, _mm256_mul_epi32,
+        _mm256_mul_epu32, _mm256_mulhi_epi16, _mm256_mulhi_epu16, _mm256_mullo_epi16,
+        _mm256_mullo_epi32, _mm256_or_si256, _mm256_packs_epi16, _mm256_packs_epi32,
+        _mm256_packus_epi16, _mm256_packus_epi32, _mm256_permute4x64_epi64, _mm256_permute4x64_pd,
+        _mm256_permutevar8x32_epi32, _mm256_permutevar8x32_ps, _mm256_sad_epu8,
+        _mm256_shuffle_epi32, _mm256_shuffle_epi8, _mm256_sll_epi16, _mm256_sll_epi32,
+        _mm256_sll_epi64, _mm256_slli_epi16, _mm256_slli_epi32, _mm256_slli_epi64,
+        _mm256_sllv_epi32, _mm256_sllv_epi64, _mm256_sra_epi16, _mm256_sra_epi32,
+        _mm256_srai_epi16, _mm256_srai_epi32, _mm256_srav_epi32, _mm256_srl_epi16,
+        _mm256_srl_epi32, _mm256_srl_epi64, _mm256_srli_epi16, _mm256_srli_epi32,
+        _mm256_srli_epi64, _mm256_srlv_epi32, _mm256_srlv_epi64, _mm256_sub_epi16,
+        _mm256_sub_epi32, _mm256_sub_epi64, _mm256_sub_epi8, _mm256_subs_epi16, _mm256_subs_epi8,
+        _mm256_subs_epu16, _mm256_subs_epu8, _mm256_unpackhi_epi16, _mm256_unpackhi_epi32,
+        _mm256_unpackhi_epi64, _mm256_unpackhi_epi8, _mm256_unpacklo_epi16, _mm256_unpacklo_epi32,
+        _mm256_unpacklo_epi64, _mm256_unpacklo_epi8, _mm256_xor_si256, _mm_sllv_epi32,
+        _mm_sllv_epi64, _mm_srav_epi32, _mm_srlv_epi32, _mm_srlv_epi64,
+    },
+    token::token,
+};
+
+token! {
+    /// A token proving that AVX2 is available.
+    pub struct Avx2("AVX2") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Avx = "avx",
+        Avx2 = "avx2",
+    }
+}
+
+// Wrapping integer arithmetic.
+impl Avx2 {
+    #[doc(alias = "_mm256_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_add_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_add_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_add_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_add_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_add_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_add_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_add_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_add_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_sub_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_sub_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_sub_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_sub_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_sub_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_sub_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_sub_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_sub_epi64(a.avx, b.avx) }.into()
+    }
+}
+
+// Saturating integer arithmetic.
+impl Avx2 {
+    #[doc(alias = "_mm256_adds_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_adds_epu8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_adds_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_adds_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_adds_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_adds_epu16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_adds_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_adds_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_subs_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_subs_epu8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_subs_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_subs_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_subs_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_subs_epu16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_subs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_subs_epi16(a.avx, b.avx) }.into()
+    }
+}
+
+// Integer multiplication, averages, absolute values and sums of absolute
+// differences.
+impl Avx2 {
+    #[doc(alias = "_mm256_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_mullo_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_mullo_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_mullo_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_mullo_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mulhi_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_high_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_mulhi_epu16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mulhi_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_high_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_mulhi_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mul_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_even_u32x8(self, a: u32x8, b: u32x8) -> u64x4 {
+        unsafe { _mm256_mul_epu32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_mul_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_even_i32x8(self, a: i32x8, b: i32x8) -> i64x4 {
+        unsafe { _mm256_mul_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_madd_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_pairs_i16x16(self, a: i16x16, b: i16x16) -> i32x8 {
+        unsafe { _mm256_madd_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_avg_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn avg_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_avg_epu8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_avg_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn avg_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_avg_epu16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sad_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sum_abs_diff_u8x32(self, a: u8x32, b: u8x32) -> u64x4 {
+        unsafe { _mm256_sad_epu8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_abs_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn abs_i8x32(self, a: i8x32) -> i8x32 {
+        unsafe { _mm256_abs_epi8(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_abs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn abs_i16x16(self, a: i16x16) -> i16x16 {
+        unsafe { _mm256_abs_epi16(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_abs_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn abs_i32x8(self, a: i32x8) -> i32x8 {
+        unsafe { _mm256_abs_epi32(a.avx) }.into()
+    }
+}
+
+// Integer minimum and maximum.
+impl Avx2 {
+    #[doc(alias = "_mm256_min_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_min_epu8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_max_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_max_epu8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_min_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_min_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_max_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_max_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_min_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_min_epu16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_max_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_max_epu16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_min_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_min_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_max_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_max_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_min_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_min_epu32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_max_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_max_epu32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_min_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_min_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_max_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_max_epi32(a.avx, b.avx) }.into()
+    }
+}
+
+// Integer comparisons.
+//
+// Every lane of the result is either all ones or all zeros.
+impl Avx2 {
+    #[doc(alias = "_mm256_cmpeq_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_cmpeq_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpeq_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_cmpeq_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpeq_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_cmpeq_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpeq_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_cmpeq_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpeq_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_cmpeq_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpeq_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_cmpeq_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpeq_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_cmpeq_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpeq_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_cmpeq_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpgt_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_cmpgt_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpgt_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_cmpgt_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpgt_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_cmpgt_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpgt_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_cmpgt_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpgt_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_cmpgt_epi8(b.avx, a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpgt_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_cmpgt_epi16(b.avx, a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpgt_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_cmpgt_epi32(b.avx, a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_cmpgt_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_cmpgt_epi64(b.avx, a.avx) }.into()
+    }
+}
+
+// Shuffles, permutes and blends.
+impl Avx2 {
+    /// Shuffle the bytes within each 128-bit half of `a` using the indices in `idx`.
+    ///
+    /// Only the lowest four bits of each index are used, so bytes never cross between
+    /// halves. Any index with its highest bit set results in zero.
+    #[doc(alias = "_mm256_shuffle_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle_bytes_u8x32(self, a: u8x32, idx: u8x32) -> u8x32 {
+        unsafe { _mm256_shuffle_epi8(a.avx, idx.avx) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit half using the control in `IMM8`.
+    #[doc(alias = "_mm256_shuffle_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle_u32x8<const IMM8: i32>(self, a: u32x8) -> u32x8 {
+        unsafe { _mm256_shuffle_epi32::<IMM8>(a.avx) }.into()
+    }
+
+    /// Shuffle lanes within each 128-bit half using the control in `IMM8`.
+    #[doc(alias = "_mm256_shuffle_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle_i32x8<const IMM8: i32>(self, a: i32x8) -> i32x8 {
+        unsafe { _mm256_shuffle_epi32::<IMM8>(a.avx) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest three bits of each index are used.
+    #[doc(alias = "_mm256_permutevar8x32_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_u32x8(self, a: u32x8, idx: u32x8) -> u32x8 {
+        unsafe { _mm256_permutevar8x32_epi32(a.avx, idx.avx) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest three bits of each index are used.
+    #[doc(alias = "_mm256_permutevar8x32_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_i32x8(self, a: i32x8, idx: u32x8) -> i32x8 {
+        unsafe { _mm256_permutevar8x32_epi32(a.avx, idx.avx) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest three bits of each index are used.
+    #[doc(alias = "_mm256_permutevar8x32_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_f32x8(self, a: f32x8, idx: u32x8) -> f32x8 {
+        unsafe { _mm256_permutevar8x32_ps(a.avx, idx.avx) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the control in `IMM8`.
+    #[doc(alias = "_mm256_permute4x64_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_u64x4<const IMM8: i32>(self, a: u64x4) -> u64x4 {
+        unsafe { _mm256_permute4x64_epi64::<IMM8>(a.avx) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the control in `IMM8`.
+    #[doc(alias = "_mm256_permute4x64_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_i64x4<const IMM8: i32>(self, a: i64x4) -> i64x4 {
+        unsafe { _mm256_permute4x64_epi64::<IMM8>(a.avx) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the control in `IMM8`.
+    #[doc(alias = "_mm256_permute4x64_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_f64x4<const IMM8: i32>(self, a: f64x4) -> f64x4 {
+        unsafe { _mm256_permute4x64_pd::<IMM8>(a.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm256_blend_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u16x16<const IMM8: i32>(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_blend_epi16::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm256_blend_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i16x16<const IMM8: i32>(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_blend_epi16::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm256_blend_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u32x8<const IMM8: i32>(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_blend_epi32::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm256_blend_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i32x8<const IMM8: i32>(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_blend_epi32::<IMM8>(a.avx, b.avx) }.into()
+    }
+
+    /// Select bytes from `b` where the highest bit of the corresponding byte in `mask`
+    /// is set, and from `a` otherwise.
+    #[doc(alias = "_mm256_blendv_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blendv_u8x32(self, a: u8x32, b: u8x32, mask: u8x32) -> u8x32 {
+        unsafe { _mm256_blendv_epi8(a.avx, b.avx, mask.avx) }.into()
+    }
+
+    /// Select bytes from `b` where the highest bit of the corresponding byte in `mask`
+    /// is set, and from `a` otherwise.
+    #[doc(alias = "_mm256_blendv_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blendv_i8x32(self, a: i8x32, b: i8x32, mask: i8x32) -> i8x32 {
+        unsafe { _mm256_blendv_epi8(a.avx, b.avx, mask.avx) }.into()
+    }
+}
+
+// Sign bit extraction.
+//
+// Bit `i` of the result is the highest bit of lane `i`.
+impl Avx2 {
+    #[doc(alias = "_mm256_movemask_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn movemask_u8x32(self, a: u8x32) -> u32 {
+        unsafe { _mm256_movemask_epi8(a.avx) as u32 }
+    }
+
+    #[doc(alias = "_mm256_movemask_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn movemask_i8x32(self, a: i8x32) -> u32 {
+        unsafe { _mm256_movemask_epi8(a.avx) as u32 }
+    }
+}
+
+// Integer bitwise logic.
+impl Avx2 {
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm256_and_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_and_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm256_or_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_or_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm256_xor_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_xor_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm256_andnot_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_andnot_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm256_and_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_and_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm256_or_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_or_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm256_xor_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_xor_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm256_andnot_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_andnot_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm256_and_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_and_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm256_or_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_or_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm256_xor_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_xor_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm256_andnot_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_andnot_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm256_and_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_and_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm256_or_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_or_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm256_xor_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_xor_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm256_andnot_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_andnot_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm256_and_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_and_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm256_or_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_or_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm256_xor_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_xor_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm256_andnot_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_andnot_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm256_and_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_and_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm256_or_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_or_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm256_xor_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_xor_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm256_andnot_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_andnot_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm256_and_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_and_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm256_or_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_or_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm256_xor_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_xor_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm256_andnot_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_andnot_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm256_and_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_and_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm256_or_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_or_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm256_xor_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_xor_si256(a.avx, b.avx) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm256_andnot_si256")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_andnot_si256(a.avx, b.avx) }.into()
+    }
+}
+
+// Uniform shifts.
+//
+// Shifting by at least the bit width of a lane results in zero, or in the
+// case of arithmetic shifts, every bit being a copy of the sign bit.
+impl Avx2 {
+    #[doc(alias = "_mm256_slli_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_u16x16<const IMM8: i32>(self, a: u16x16) -> u16x16 {
+        unsafe { _mm256_slli_epi16::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_slli_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_i16x16<const IMM8: i32>(self, a: i16x16) -> i16x16 {
+        unsafe { _mm256_slli_epi16::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_slli_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_u32x8<const IMM8: i32>(self, a: u32x8) -> u32x8 {
+        unsafe { _mm256_slli_epi32::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_slli_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_i32x8<const IMM8: i32>(self, a: i32x8) -> i32x8 {
+        unsafe { _mm256_slli_epi32::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_slli_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_u64x4<const IMM8: i32>(self, a: u64x4) -> u64x4 {
+        unsafe { _mm256_slli_epi64::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_slli_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_i64x4<const IMM8: i32>(self, a: i64x4) -> i64x4 {
+        unsafe { _mm256_slli_epi64::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_srli_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_u16x16<const IMM8: i32>(self, a: u16x16) -> u16x16 {
+        unsafe { _mm256_srli_epi16::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_srai_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_i16x16<const IMM8: i32>(self, a: i16x16) -> i16x16 {
+        unsafe { _mm256_srai_epi16::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_srli_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_u32x8<const IMM8: i32>(self, a: u32x8) -> u32x8 {
+        unsafe { _mm256_srli_epi32::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_srai_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_i32x8<const IMM8: i32>(self, a: i32x8) -> i32x8 {
+        unsafe { _mm256_srai_epi32::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_srli_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_u64x4<const IMM8: i32>(self, a: u64x4) -> u64x4 {
+        unsafe { _mm256_srli_epi64::<IMM8>(a.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sll_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_u16x16(self, a: u16x16, count: u64x2) -> u16x16 {
+        unsafe { _mm256_sll_epi16(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_sll_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_i16x16(self, a: i16x16, count: u64x2) -> i16x16 {
+        unsafe { _mm256_sll_epi16(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_sll_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_u32x8(self, a: u32x8, count: u64x2) -> u32x8 {
+        unsafe { _mm256_sll_epi32(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_sll_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_i32x8(self, a: i32x8, count: u64x2) -> i32x8 {
+        unsafe { _mm256_sll_epi32(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_sll_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_u64x4(self, a: u64x4, count: u64x2) -> u64x4 {
+        unsafe { _mm256_sll_epi64(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_sll_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_i64x4(self, a: i64x4, count: u64x2) -> i64x4 {
+        unsafe { _mm256_sll_epi64(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_srl_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_u16x16(self, a: u16x16, count: u64x2) -> u16x16 {
+        unsafe { _mm256_srl_epi16(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_sra_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_i16x16(self, a: i16x16, count: u64x2) -> i16x16 {
+        unsafe { _mm256_sra_epi16(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_srl_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_u32x8(self, a: u32x8, count: u64x2) -> u32x8 {
+        unsafe { _mm256_srl_epi32(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_sra_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_i32x8(self, a: i32x8, count: u64x2) -> i32x8 {
+        unsafe { _mm256_sra_epi32(a.avx, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_srl_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_u64x4(self, a: u64x4, count: u64x2) -> u64x4 {
+        unsafe { _mm256_srl_epi64(a.avx, count.sse) }.into()
+    }
+}
+
+// Per-lane shifts.
+//
+// Every lane of `a` is shifted by the corresponding lane of `count`. Shifting by
+// at least the bit width of a lane results in zero, or in the case of arithmetic
+// shifts, every bit being a copy of the sign bit.
+impl Avx2 {
+    #[doc(alias = "_mm_sllv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_u32x4(self, a: u32x4, count: u32x4) -> u32x4 {
+        unsafe { _mm_sllv_epi32(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sllv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_i32x4(self, a: i32x4, count: u32x4) -> i32x4 {
+        unsafe { _mm_sllv_epi32(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sllv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_u64x2(self, a: u64x2, count: u64x2) -> u64x2 {
+        unsafe { _mm_sllv_epi64(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sllv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_i64x2(self, a: i64x2, count: u64x2) -> i64x2 {
+        unsafe { _mm_sllv_epi64(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_sllv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_u32x8(self, a: u32x8, count: u32x8) -> u32x8 {
+        unsafe { _mm256_sllv_epi32(a.avx, count.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sllv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_i32x8(self, a: i32x8, count: u32x8) -> i32x8 {
+        unsafe { _mm256_sllv_epi32(a.avx, count.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sllv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_u64x4(self, a: u64x4, count: u64x4) -> u64x4 {
+        unsafe { _mm256_sllv_epi64(a.avx, count.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_sllv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_i64x4(self, a: i64x4, count: u64x4) -> i64x4 {
+        unsafe { _mm256_sllv_epi64(a.avx, count.avx) }.into()
+    }
+
+    #[doc(alias = "_mm_srlv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_u32x4(self, a: u32x4, count: u32x4) -> u32x4 {
+        unsafe { _mm_srlv_epi32(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srav_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_i32x4(self, a: i32x4, count: u32x4) -> i32x4 {
+        unsafe { _mm_srav_epi32(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srlv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_u64x2(self, a: u64x2, count: u64x2) -> u64x2 {
+        unsafe { _mm_srlv_epi64(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm256_srlv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_u32x8(self, a: u32x8, count: u32x8) -> u32x8 {
+        unsafe { _mm256_srlv_epi32(a.avx, count.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_srav_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_i32x8(self, a: i32x8, count: u32x8) -> i32x8 {
+        unsafe { _mm256_srav_epi32(a.avx, count.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_srlv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_u64x4(self, a: u64x4, count: u64x4) -> u64x4 {
+        unsafe { _mm256_srlv_epi64(a.avx, count.avx) }.into()
+    }
+}
+
+// Packing and unpacking.
+//
+// These operate on each 128-bit half independently.
+impl Avx2 {
+    #[doc(alias = "_mm256_packs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn pack_saturate_i16x16(self, a: i16x16, b: i16x16) -> i8x32 {
+        unsafe { _mm256_packs_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_packus_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn pack_saturate_unsigned_i16x16(self, a: i16x16, b: i16x16) -> u8x32 {
+        unsafe { _mm256_packus_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_packs_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn pack_saturate_i32x8(self, a: i32x8, b: i32x8) -> i16x16 {
+        unsafe { _mm256_packs_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_packus_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn pack_saturate_unsigned_i32x8(self, a: i32x8, b: i32x8) -> u16x16 {
+        unsafe { _mm256_packus_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_unpacklo_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_unpacklo_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_unpacklo_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_unpacklo_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_unpacklo_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_unpacklo_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_unpacklo_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpacklo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_unpacklo_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_u8x32(self, a: u8x32, b: u8x32) -> u8x32 {
+        unsafe { _mm256_unpackhi_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_i8x32(self, a: i8x32, b: i8x32) -> i8x32 {
+        unsafe { _mm256_unpackhi_epi8(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_u16x16(self, a: u16x16, b: u16x16) -> u16x16 {
+        unsafe { _mm256_unpackhi_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_i16x16(self, a: i16x16, b: i16x16) -> i16x16 {
+        unsafe { _mm256_unpackhi_epi16(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_u32x8(self, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_unpackhi_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_i32x8(self, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_unpackhi_epi32(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_u64x4(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_unpackhi_epi64(a.avx, b.avx) }.into()
+    }
+
+    #[doc(alias = "_mm256_unpackhi_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_i64x4(self, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_unpackhi_epi64(a.avx, b.avx) }.into()
+    }
+}
+
+// Gathers.
+impl Avx2 {
+    /// Load a lane from `base` offset by `idx * SCALE` bytes for every lane of `idx`.
+    ///
+    /// `SCALE` must be either 1, 2, 4 or 8.
+    ///
+    /// # Safety
+    ///
+    /// Every address computed from `base` and `idx` must be valid for reads of
+    /// 4 bytes.
+    #[doc(alias = "_mm256_i32gather_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn gather_i32_f32x8<const SCALE: i32>(self, base: *const f32, idx: i32x8) -> f32x8 {
+        unsafe { _mm256_i32gather_ps::<SCALE>(base, idx.avx) }.into()
+    }
+
+    /// Load a lane from `base` offset by `idx * SCALE` bytes for every lane of `idx`.
+    ///
+    /// `SCALE` must be either 1, 2, 4 or 8.
+    ///
+    /// # Safety
+    ///
+    /// Every address computed from `base` and `idx` must be valid for reads of
+    /// 4 bytes.
+    #[doc(alias = "_mm256_i64gather_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn gather_i64_f32x4<const SCALE: i32>(self, base: *const f32, idx: i64x4) -> f32x4 {
+        unsafe { _mm256_i64gather_ps::<SCALE>(base, idx.avx) }.into()
+    }
+
+    /// Load a lane from `base` offset by `idx * SCALE` bytes for every lane of `idx`.
+    ///
+    /// `SCALE` must be either 1, 2, 4 or 8.
+    ///
+    /// # Safety
+    ///
+    /// Every address computed from `base` and `idx` must be valid for reads of
+    /// 8 bytes.
+    #[doc(alias = "_mm256_i32gather_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn gather_i32_f64x4<const SCALE: i32>(self, base: *const f64, idx: i32x4) -> f64x4 {
+        unsafe { _mm256_i32gather_pd::<SCALE>(base, idx.sse) }.into()
+    }
+
+    /// Load a lane from `base` offset by `idx * SCALE` bytes for every lane of `idx`.
+    ///
+    /// `SCALE` must be either 1, 2, 4 or 8.
+    ///
+    /// # Safety
+    ///
+    /// Every address computed from `base` and `idx` must be valid for reads of
+    /// 8 bytes.
+    #[doc(alias = "_mm256_i64gather_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn gather_i64_f64x4<const SCALE: i32>(self, base: *const f64, idx: i64x4) -> f64x4 {
+        unsafe { _mm256_i64gather_pd::<SCALE>(base, idx.avx) }.into()
+    }
+
+    /// Load a lane from `base` offset by `idx * SCALE` bytes for every lane of `idx`.
+    ///
+    /// `SCALE` must be either 1, 2, 4 or 8.
+    ///
+    /// # Safety
+    ///
+    /// Every address computed from `base` and `idx` must be valid for reads of
+    /// 4 bytes.
+    #[doc(alias = "_mm256_i32gather_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn gather_i32_u32x8<const SCALE: i32>(self, base: *const u32, idx: i32x8) -> u32x8 {
+        unsafe { _mm256_i32gather_epi32::<SCALE>(base.cast(), idx.avx) }.into()
+    }
+
+    /// Load a lane from `base` offset by `idx * SCALE` bytes for every lane of `idx`.
+    ///
+    /// `SCALE` must be either 1, 2, 4 or 8.
+    ///
+    /// # Safety
+    ///
+    /// Every address computed from `base` and `idx` must be valid for reads of
+    /// 4 bytes.
+    #[doc(alias = "_mm256_i64gather_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn gather_i64_u32x4<const SCALE: i32>(self, base: *const u32, idx: i64x4) -> u32x4 {
+        unsafe { _mm256_i64gather_epi32::<SCALE>(base.cast(), idx.avx) }.into()
+    }
+
+    /// Load a lane from `base` offset by `idx * SCALE` bytes for every lane of `idx`.
+    ///
+    /// `SCALE` must be either 1, 2, 4 or 8.
+    ///
+    /// # Safety
+    ///
+    /// Every address computed from `base` and `idx` must be valid for reads of
+    /// 8 bytes.
+    #[doc(alias = "_mm256_i32gather_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn gather_i32_u64x4<const SCALE: i32>(self, base: *const u64, idx: i32x4) -> u64x4 {
+        unsafe { _mm256_i32gather_epi64::<SCALE>(base.cast(), idx.sse) }.into()
+    }
+
+    /// Load a lane from `base` offset by `idx * SCALE` bytes for every lane of `idx`.
+    ///
+    /// `SCALE` must be either 1, 2, 4 or 8.
+    ///
+    /// # Safety
+    ///
+    /// Every address computed from `base` and `idx` must be valid for reads of
+    /// 8 bytes.
+    #[doc(alias = "_mm256_i64gather_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn gather_i64_u64x4<const SCALE: i32>(self, base: *const u64, idx: i64x4) -> u64x4 {
+        unsafe { _mm256_i64gather_epi64::<SCALE>(base.cast(), idx.avx) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::{
+            f32x8, f64x4, i16x16, i32x4, i32x8, i64x2, i64x4, i8x32, u16x16, u32x4, u32x8, u64x2,
+            u64x4, u8x32,
+        },
+        util::test::{assert_lanes, Rng},
+    };
+
+    use super::{
+        super::test::{
+            groups, lane_wise, packs, shift_count, shifts, token_or_skip, unpacks, ROUNDS,
+        },
+        Avx2,
+    };
+
+    #[test]
+    fn arith() {
+        let token = token_or_skip!(Avx2);
+
+        lane_wise! {
+            token;
+            add_u8x32 (a, b): u8x32 => a.wrapping_add(b);
+            add_i8x32 (a, b): i8x32 => a.wrapping_add(b);
+            add_u16x16 (a, b): u16x16 => a.wrapping_add(b);
+            add_i16x16 (a, b): i16x16 => a.wrapping_add(b);
+            add_u32x8 (a, b): u32x8 => a.wrapping_add(b);
+            add_i32x8 (a, b): i32x8 => a.wrapping_add(b);
+            add_u64x4 (a, b): u64x4 => a.wrapping_add(b);
+            add_i64x4 (a, b): i64x4 => a.wrapping_add(b);
+
+            sub_u8x32 (a, b): u8x32 => a.wrapping_sub(b);
+            sub_i8x32 (a, b): i8x32 => a.wrapping_sub(b);
+            sub_u16x16 (a, b): u16x16 => a.wrapping_sub(b);
+            sub_i16x16 (a, b): i16x16 => a.wrapping_sub(b);
+            sub_u32x8 (a, b): u32x8 => a.wrapping_sub(b);
+            sub_i32x8 (a, b): i32x8 => a.wrapping_sub(b);
+            sub_u64x4 (a, b): u64x4 => a.wrapping_sub(b);
+            sub_i64x4 (a, b): i64x4 => a.wrapping_sub(b);
+
+            saturating_add_u8x32 (a, b): u8x32 => a.saturating_add(b);
+            saturating_add_i8x32 (a, b): i8x32 => a.saturating_add(b);
+            saturating_add_u16x16 (a, b): u16x16 => a.saturating_add(b);
+            saturating_add_i16x16 (a, b): i16x16 => a.saturating_add(b);
+            saturating_sub_u8x32 (a, b): u8x32 => a.saturating_sub(b);
+            saturating_sub_i8x32 (a, b): i8x32 => a.saturating_sub(b);
+            saturating_sub_u16x16 (a, b): u16x16 => a.saturating_sub(b);
+            saturating_sub_i16x16 (a, b): i16x16 => a.saturating_sub(b);
+
+            mul_u16x16 (a, b): u16x16 => a.wrapping_mul(b);
+            mul_i16x16 (a, b): i16x16 => a.wrapping_mul(b);
+            mul_u32x8 (a, b): u32x8 => a.wrapping_mul(b);
+            mul_i32x8 (a, b): i32x8 => a.wrapping_mul(b);
+            mul_high_u16x16 (a, b): u16x16 => ((a as u32 * b as u32) >> 16) as u16;
+            mul_high_i16x16 (a, b): i16x16 => ((a as i32 * b as i32) >> 16) as i16;
+            avg_u8x32 (a, b): u8x32 => ((a as u16 + b as u16 + 1) >> 1) as u8;
+            avg_u16x16 (a, b): u16x16 => ((a as u32 + b as u32 + 1) >> 1) as u16;
+            abs_i8x32 (a): i8x32 => a.wrapping_abs();
+            abs_i16x16 (a): i16x16 => a.wrapping_abs();
+            abs_i32x8 (a): i32x8 => a.wrapping_abs();
+
+            min_u8x32 (a, b): u8x32 => a.min(b);
+            max_u8x32 (a, b): u8x32 => a.max(b);
+            min_i8x32 (a, b): i8x32 => a.min(b);
+            max_i8x32 (a, b): i8x32 => a.max(b);
+            min_u16x16 (a, b): u16x16 => a.min(b);
+            max_u16x16 (a, b): u16x16 => a.max(b);
+            min_i16x16 (a, b): i16x16 => a.min(b);
+            max_i16x16 (a, b): i16x16 => a.max(b);
+            min_u32x8 (a, b): u32x8 => a.min(b);
+            max_u32x8 (a, b): u32x8 => a.max(b);
+            min_i32x8 (a, b): i32x8 => a.min(b);
+            max_i32x8 (a, b): i32x8 => a.max(b);
+        }
+
+        groups! {
+            token;
+            mul_even_u32x8 (a, b): u32x8 => a[0] as u64 * b[0] as u64;
+            mul_even_i32x8 (a, b): i32x8 => a[0] as i64 * b[0] as i64;
+            mul_add_pairs_i16x16 (a, b): i16x16
+                => (a[0] as i32 * b[0] as i32).wrapping_add(a[1] as i32 * b[1] as i32);
+            sum_abs_diff_u8x32 (a, b): u8x32
+                => a.iter().zip(b).map(|(&a, &b)| u64::from(a.abs_diff(b))).sum::<u64>();
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let token = token_or_skip!(Avx2);
+
+        lane_wise! {
+            token;
+            eq_u8x32 (a, b): u8x32 => if a == b { !0 } else { 0 };
+            eq_i8x32 (a, b): i8x32 => if a == b { !0 } else { 0 };
+            eq_u16x16 (a, b): u16x16 => if a == b { !0 } else { 0 };
+            eq_i16x16 (a, b): i16x16 => if a == b { !0 } else { 0 };
+            eq_u32x8 (a, b): u32x8 => if a == b { !0 } else { 0 };
+            eq_i32x8 (a, b): i32x8 => if a == b { !0 } else { 0 };
+            eq_u64x4 (a, b): u64x4 => if a == b { !0 } else { 0 };
+            eq_i64x4 (a, b): i64x4 => if a == b { !0 } else { 0 };
+            gt_i8x32 (a, b): i8x32 => if a > b { !0 } else { 0 };
+            gt_i16x16 (a, b): i16x16 => if a > b { !0 } else { 0 };
+            gt_i32x8 (a, b): i32x8 => if a > b { !0 } else { 0 };
+            gt_i64x4 (a, b): i64x4 => if a > b { !0 } else { 0 };
+            lt_i8x32 (a, b): i8x32 => if a < b { !0 } else { 0 };
+            lt_i16x16 (a, b): i16x16 => if a < b { !0 } else { 0 };
+            lt_i32x8 (a, b): i32x8 => if a < b { !0 } else { 0 };
+            lt_i64x4 (a, b): i64x4 => if a < b { !0 } else { 0 };
+        }
+    }
+
+    #[test]
+    fn bitwise() {
+        let token = token_or_skip!(Avx2);
+
+        lane_wise! {
+            token;
+            and_u8x32 (a, b): u8x32 => a & b;
+            or_u8x32 (a, b): u8x32 => a | b;
+            xor_u8x32 (a, b): u8x32 => a ^ b;
+            andnot_u8x32 (a, b): u8x32 => !a & b;
+            and_i8x32 (a, b): i8x32 => a & b;
+            or_i8x32 (a, b): i8x32 => a | b;
+            xor_i8x32 (a, b): i8x32 => a ^ b;
+            andnot_i8x32 (a, b): i8x32 => !a & b;
+            and_u16x16 (a, b): u16x16 => a & b;
+            or_u16x16 (a, b): u16x16 => a | b;
+            xor_u16x16 (a, b): u16x16 => a ^ b;
+            andnot_u16x16 (a, b): u16x16 => !a & b;
+            and_i16x16 (a, b): i16x16 => a & b;
+            or_i16x16 (a, b): i16x16 => a | b;
+            xor_i16x16 (a, b): i16x16 => a ^ b;
+            andnot_i16x16 (a, b): i16x16 => !a & b;
+            and_u32x8 (a, b): u32x8 => a & b;
+            or_u32x8 (a, b): u32x8 => a | b;
+            xor_u32x8 (a, b): u32x8 => a ^ b;
+            andnot_u32x8 (a, b): u32x8 => !a & b;
+            and_i32x8 (a, b): i32x8 => a & b;
+            or_i32x8 (a, b): i32x8 => a | b;
+            xor_i32x8 (a, b): i32x8 => a ^ b;
+            andnot_i32x8 (a, b): i32x8 => !a & b;
+            and_u64x4 (a, b): u64x4 => a & b;
+            or_u64x4 (a, b): u64x4 => a | b;
+            xor_u64x4 (a, b): u64x4 => a ^ b;
+            andnot_u64x4 (a, b): u64x4 => !a & b;
+            and_i64x4 (a, b): i64x4 => a & b;
+            or_i64x4 (a, b): i64x4 => a | b;
+            xor_i64x4 (a, b): i64x4 => a ^ b;
+            andnot_i64x4 (a, b): i64x4 => !a & b;
+        }
+    }
+
+    #[test]
+    fn movemask() {
+        let token = token_or_skip!(Avx2);
+        let mut rng = Rng::new(1);
+
+        for _ in 0..ROUNDS {
+            let a: [u8; 32] = rng.array();
+            let expected = (0..32).fold(0, |bits, i| bits | ((a[i] >> 7) as u32) << i);
+
+            assert_eq!(
+                token.movemask_u8x32(u8x32::from_array(a)),
+                expected,
+                "{a:?}"
+            );
+            assert_eq!(
+                token.movemask_i8x32(i8x32::from_array(a.map(|a| a as i8))),
+                expected,
+                "{a:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn shuffle_bytes() {
+        let token = token_or_skip!(Avx2);
+        let mut rng = Rng::new(1);
+
+        // Bytes never cross between the 128-bit halves, and an index with its highest
+        // bit set gives zero.
+        for _ in 0..ROUNDS {
+            let a: [u8; 32] = rng.array();
+            let idx: [u8; 32] = rng.array();
+            let expected: [u8; 32] = core::array::from_fn(|i| {
+                if idx[i] & 0x80 != 0 {
+                    0
+                } else {
+                    a[i / 16 * 16 + (idx[i] & 15) as usize]
+                }
+            });
+            let actual = token.shuffle_bytes_u8x32(u8x32::from_array(a), u8x32::from_array(idx));
+
+            assert_lanes(&actual.to_array(), &expected, (a, idx));
+        }
+    }
+
+    #[test]
+    fn permute() {
+        let token = token_or_skip!(Avx2);
+        let mut rng = Rng::new(1);
+
+        // The 32-bit shuffles permute every 128-bit half with the same control, while the
+        // 64-bit permutes cross the halves.
+        macro_rules! check {
+            ($($imm:literal),*) => {$(
+                let a: [u32; 8] = rng.array();
+                let expected: [u32; 8] =
+                    core::array::from_fn(|i| a[i / 4 * 4 + ($imm >> (i % 4 * 2) & 3)]);
+
+                assert_lanes(
+                    &token.shuffle_u32x8::<$imm>(u32x8::from_array(a)).to_array(),
+                    &expected,
+                    ($imm, a),
+                );
+                assert_lanes(
+                    &token.shuffle_i32x8::<$imm>(i32x8::from_array(a.map(|a| a as i32))).to_array(),
+                    &expected.map(|a| a as i32),
+                    ($imm, a),
+                );
+
+                let a: [u64; 4] = rng.array();
+                let b: [f64; 4] = rng.array();
+                let select = |i: usize| $imm >> (i * 2) & 3;
+
+                assert_lanes(
+                    &token.permute_lanes_u64x4::<$imm>(u64x4::from_array(a)).to_array(),
+                    &core::array::from_fn::<_, 4, _>(|i| a[select(i)]),
+                    ($imm, a),
+                );
+                assert_lanes(
+                    &token
+                        .permute_lanes_i64x4::<$imm>(i64x4::from_array(a.map(|a| a as i64)))
+                        .to_array(),
+                    &core::array::from_fn::<_, 4, _>(|i| a[select(i)] as i64),
+                    ($imm, a),
+                );
+                assert_lanes(
+                    &token.permute_lanes_f64x4::<$imm>(f64x4::from_array(b)).to_array(),
+                    &core::array::from_fn::<_, 4, _>(|i| b[select(i)]),
+                    ($imm, b),
+                );
+            )*};
+        }
+
+        check!(0x00, 0x1b, 0x4e, 0xa5, 0xd8, 0xe4, 0xff);
+
+        // Only the lowest three bits of every index are used.
+        for _ in 0..ROUNDS {
+            let a: [u32; 8] = rng.array();
+            let b: [f32; 8] = rng.array();
+            let idx: [u32; 8] = rng.array();
+            let select = |i: usize| (idx[i] & 7) as usize;
+
+            assert_lanes(
+                &token
+                    .permute_lanes_u32x8(u32x8::from_array(a), u32x8::from_array(idx))
+                    .to_array(),
+                &core::array::from_fn::<_, 8, _>(|i| a[select(i)]),
+                (a, idx),
+            );
+            assert_lanes(
+                &token
+                    .permute_lanes_i32x8(
+                        i32x8::from_array(a.map(|a| a as i32)),
+                        u32x8::from_array(idx),
+                    )
+                    .to_array(),
+                &core::array::from_fn::<_, 8, _>(|i| a[select(i)] as i32),
+                (a, idx),
+            );
+            assert_lanes(
+                &token
+                    .permute_lanes_f32x8(f32x8::from_array(b), u32x8::from_array(idx))
+                    .to_array(),
+                &core::array::from_fn::<_, 8, _>(|i| b[select(i)]),
+                (b, idx),
+            );
+        }
+    }
+
+    #[test]
+    fn blend() {
+        let token = token_or_skip!(Avx2);
+        let mut rng = Rng::new(1);
+
+        // The 16-bit blends use the same eight bits of the control for both 128-bit halves.
+        macro_rules! check {
+            ($($imm:literal),*) => {$(
+                let a: [u16; 16] = rng.array();
+                let b: [u16; 16] = rng.array();
+                let expected: [u16; 16] =
+                    core::array::from_fn(|i| if $imm >> (i % 8) & 1 != 0 { b[i] } else { a[i] });
+
+                assert_lanes(
+                    &token
+                        .blend_u16x16::<$imm>(u16x16::from_array(a), u16x16::from_array(b))
+                        .to_array(),
+                    &expected,
+                    ($imm, a, b),
+                );
+                assert_lanes(
+                    &token
+                        .blend_i16x16::<$imm>(
+                            i16x16::from_array(a.map(|a| a as i16)),
+                            i16x16::from_array(b.map(|b| b as i16)),
+                        )
+                        .to_array(),
+                    &expected.map(|lane| lane as i16),
+                    ($imm, a, b),
+                );
+
+                let a: [u32; 8] = rng.array();
+                let b: [u32; 8] = rng.array();
+                let expected: [u32; 8] =
+                    core::array::from_fn(|i| if $imm >> i & 1 != 0 { b[i] } else { a[i] });
+
+                assert_lanes(
+                    &token
+                        .blend_u32x8::<$imm>(u32x8::from_array(a), u32x8::from_array(b))
+                        .to_array(),
+                    &expected,
+                    ($imm, a, b),
+                );
+                assert_lanes(
+                    &token
+                        .blend_i32x8::<$imm>(
+                            i32x8::from_array(a.map(|a| a as i32)),
+                            i32x8::from_array(b.map(|b| b as i32)),
+                        )
+                        .to_array(),
+                    &expected.map(|lane| lane as i32),
+                    ($imm, a, b),
+                );
+            )*};
+        }
+
+        check!(0x00, 0x01, 0x5a, 0x80, 0xc3, 0xff);
+
+        for _ in 0..ROUNDS {
+            let (a, b, mask): ([u8; 32], [u8; 32], [u8; 32]) =
+                (rng.array(), rng.array(), rng.array());
+            let expected: [u8; 32] =
+                core::array::from_fn(|i| if mask[i] & 0x80 != 0 { b[i] } else { a[i] });
+
+            assert_lanes(
+                &token
+                    .blendv_u8x32(
+                        u8x32::from_array(a),
+                        u8x32::from_array(b),
+                        u8x32::from_array(mask),
+                    )
+                    .to_array(),
+                &expected,
+                (a, b, mask),
+            );
+            assert_lanes(
+                &token
+                    .blendv_i8x32(
+                        i8x32::from_array(a.map(|a| a as i8)),
+                        i8x32::from_array(b.map(|b| b as i8)),
+                        i8x32::from_array(mask.map(|mask| mask as i8)),
+                    )
+                    .to_array(),
+                &expected.map(|lane| lane as i8),
+                (a, b, mask),
+            );
+        }
+    }
+
+    #[test]
+    fn shift() {
+        let token = token_or_skip!(Avx2);
+
+        shifts! {
+            token;
+            imm shl_u16x16: u16x16 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_i16x16: i16x16 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_u32x8: u32x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_i32x8: i32x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_u64x4: u64x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_i64x4: i64x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shr_u16x16: u16x16 => |a, count| a.checked_shr(count).unwrap_or(0);
+            imm shr_i16x16: i16x16 => |a, count| a >> count.min(15);
+            imm shr_u32x8: u32x8 => |a, count| a.checked_shr(count).unwrap_or(0);
+            imm shr_i32x8: i32x8 => |a, count| a >> count.min(31);
+            imm shr_u64x4: u64x4 => |a, count| a.checked_shr(count).unwrap_or(0);
+
+            by shl_by_u16x16: u16x16 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_i16x16: i16x16 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_u32x8: u32x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_i32x8: i32x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_u64x4: u64x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_i64x4: i64x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shr_by_u16x16: u16x16 => |a, count| a.checked_shr(count).unwrap_or(0);
+            by shr_by_i16x16: i16x16 => |a, count| a >> count.min(15);
+            by shr_by_u32x8: u32x8 => |a, count| a.checked_shr(count).unwrap_or(0);
+            by shr_by_i32x8: i32x8 => |a, count| a >> count.min(31);
+            by shr_by_u64x4: u64x4 => |a, count| a.checked_shr(count).unwrap_or(0);
+
+            var shl_var_u32x4: u32x4, u32x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            var shl_var_i32x4: i32x4, u32x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            var shl_var_u64x2: u64x2, u64x2 => |a, count| a.checked_shl(count).unwrap_or(0);
+            var shl_var_i64x2: i64x2, u64x2 => |a, count| a.checked_shl(count).unwrap_or(0);
+            var shl_var_u32x8: u32x8, u32x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            var shl_var_i32x8: i32x8, u32x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            var shl_var_u64x4: u64x4, u64x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            var shl_var_i64x4: i64x4, u64x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            var shr_var_u32x4: u32x4, u32x4 => |a, count| a.checked_shr(count).unwrap_or(0);
+            var shr_var_i32x4: i32x4, u32x4 => |a, count| a >> count.min(31);
+            var shr_var_u64x2: u64x2, u64x2 => |a, count| a.checked_shr(count).unwrap_or(0);
+            var shr_var_u32x8: u32x8, u32x8 => |a, count| a.checked_shr(count).unwrap_or(0);
+            var shr_var_i32x8: i32x8, u32x8 => |a, count| a >> count.min(31);
+            var shr_var_u64x4: u64x4, u64x4 => |a, count| a.checked_shr(count).unwrap_or(0);
+        }
+    }
+
+    #[test]
+    fn pack_unpack() {
+        let token = token_or_skip!(Avx2);
+
+        packs! {
+            token;
+            pack_saturate_i16x16: i16x16 => i8;
+            pack_saturate_unsigned_i16x16: i16x16 => u8;
+            pack_saturate_i32x8: i32x8 => i16;
+            pack_saturate_unsigned_i32x8: i32x8 => u16;
+        }
+
+        unpacks! {
+            token;
+            unpack_lo_u8x32 / unpack_hi_u8x32: u8x32;
+            unpack_lo_i8x32 / unpack_hi_i8x32: i8x32;
+            unpack_lo_u16x16 / unpack_hi_u16x16: u16x16;
+            unpack_lo_i16x16 / unpack_hi_i16x16: i16x16;
+            unpack_lo_u32x8 / unpack_hi_u32x8: u32x8;
+            unpack_lo_i32x8 / unpack_hi_i32x8: i32x8;
+            unpack_lo_u64x4 / unpack_hi_u64x4: u64x4;
+            unpack_lo_i64x4 / unpack_hi_i64x4: i64x4;
+        }
+    }
+
+    #[test]
+    fn gather() {
+        let token = token_or_skip!(Avx2);
+        let mut rng = Rng::new(1);
+
+        // The base points into the middle of the table, so that negative indices stay in
+        // bounds for every scale. The model looks the lane up in the bytes of the table.
+        let table: [u8; 1024] = rng.array();
+        let middle = table.len() / 2;
+        let base = table[middle..].as_ptr();
+
+        macro_rules! check {
+            ($($method:ident: $idx:ident => $scalar:ident;)*) => {$(
+                check!(@scale $method, $idx, $scalar, [1, 2, 4, 8]);
+            )*};
+
+            (@scale $method:ident, $idx:ident, $scalar:ident, [$($scale:literal),*]) => {$(
+                for _ in 0..ROUNDS {
+                    let idx: [i64; $idx::LANES] =
+                        core::array::from_fn(|_| (rng.next_u64() % 121) as i64 - 60);
+                    let expected = idx.map(|i| {
+                        let start = (middle as i64 + i * $scale) as usize;
+                        let bytes = &table[start..][..core::mem::size_of::<$scalar>()];
+
+                        $scalar::from_ne_bytes(bytes.try_into().unwrap())
+                    });
+
+                    // SAFETY: Every index is at most 60 away from the middle of the table,
+                    //         which leaves room for a lane at every scale.
+                    let actual = unsafe {
+                        token.$method::<$scale>(base.cast(), $idx::from_array(idx.map(|i| i as _)))
+                    };
+
+                    assert_lanes(&actual.to_array(), &expected, (stringify!($method), $scale, idx));
+                }
+            )*};
+        }
+
+        check! {
+            gather_i32_f32x8: i32x8 => f32;
+            gather_i64_f32x4: i64x4 => f32;
+            gather_i32_f64x4: i32x4 => f64;
+            gather_i64_f64x4: i64x4 => f64;
+            gather_i32_u32x8: i32x8 => u32;
+            gather_i64_u32x4: i64x4 => u32;
+            gather_i32_u64x4: i32x4 => u64;
+            gather_i64_u64x4: i64x4 => u64;
+        }
+
+        // The indices are signed, and scaled before they are added to the base.
+        let lanes: [u32; 8] = core::array::from_fn(|i| i as u32 * 10);
+        let base = lanes[4..].as_ptr();
+        let idx = i32x8::from_array([-4, -3, -2, -1, 0, 1, 2, 3]);
+
+        // SAFETY: Every index stays within `lanes`.
+        let actual = unsafe { token.gather_i32_u32x8::<4>(base, idx) };
+
+        assert_eq!(actual.to_array(), lanes);
+    }
+}
@@ -0,0 +1,1225 @@
+use crate::types::vector::{i16x32, i8x64, u16x32, u8x64};
+
+use super::{
+    raw::{
+        _mm512_add_epi16, _mm512_add_epi8, _mm512_adds_epi16, _mm512_adds_epi8, _mm512_adds_epu16,
+        _mm512_adds_epu8, _mm512_cmpeq_epi16_mask, _mm512_cmpeq_epi8_mask, _mm512_cmpeq_epu16_mask,
+        _mm512_cmpeq_epu8_mask, _mm512_cmpge_epi16_mask, _mm512_cmpge_epi8_mask,
+        _mm512_cmpge_epu16_mask, _mm512_cmpge_epu8_mask, _mm512_cmpgt_epi16_mask,
+        _mm512_cmpgt_epi8_mask, _mm512_cmpgt_epu16_mask, _mm512_cmpgt_epu8_mask,
+        _mm512_cmple_epi16_mask, _mm512_cmple_epi8_mask, _mm512_cmple_epu16_mask,
+        _mm512_cmple_epu8_mask, _mm512_cmplt_epi16_mask, _mm512_cmplt_epi8_mask,
+        _mm512_cmplt_epu16_mask, _mm512_cmplt_epu8_mask, _mm512_cmpneq_epi16_mask,
+        _mm512_cmpneq_epi8_mask, _mm512_cmpneq_epu16_mask, _mm512_cmpneq_epu8_mask,
+        _mm512_mask_add_epi16, _mm512_mask_add_epi8, _mm512_mask_adds_epi16, _mm512_mask_adds_epi8,
+        _mm512_mask_adds_epu16, _mm512_mask_adds_epu8, _mm512_mask_blend_epi16,
+        _mm512_mask_blend_epi8, _mm512_mask_max_epi16, _mm512_mask_max_epi8, _mm512_mask_max_epu16,
+        _mm512_mask_max_epu8, _mm512_mask_min_epi16, _mm512_mask_min_epi8, _mm512_mask_min_epu16,
+        _mm512_mask_min_epu8, _mm512_mask_mullo_epi16, _mm512_mask_sub_epi16, _mm512_mask_sub_epi8,
+        _mm512_mask_subs_epi16, _mm512_mask_subs_epi8, _mm512_mask_subs_epu16,
+        _mm512_mask_subs_epu8, _mm512_maskz_add_epi16, _mm512_maskz_add_epi8,
+        _mm512_maskz_adds_epi16, _mm512_maskz_adds_epi8, _mm512_maskz_adds_epu16,
+        _mm512_maskz_adds_epu8, _mm512_maskz_max_epi16, _mm512_maskz_max_epi8,
+        _mm512_maskz_max_epu16, _mm512_maskz_max_epu8, _mm512_maskz_min_epi16,
+        _mm512_maskz_min_epi8, _mm512_maskz_min_epu16, _mm512_maskz_min_epu8,
+        _mm512_maskz_mullo_epi16, _mm512_maskz_sub_epi16, _mm512_maskz_sub_epi8,
+        _mm512_maskz_subs_epi16, _mm512_maskz_subs_epi8, _mm512_maskz_subs_epu16,
+        _mm512_maskz_subs_epu8, _mm512_max_epi16, _mm512_max_epi8, _mm512_max_epu16,
+        _mm512_max_epu8, _mm512_min_epi16, _mm512_min_epi8, _mm512_min_epu16, _mm512_min_epu8,
+        _mm512_movepi16_mask, _mm512_movepi8_mask, _mm512_movm_epi16, _mm512_movm_epi8,
+        _mm512_mullo_epi16, _mm512_shuffle_epi8, _mm512_sub_epi16, _mm512_sub_epi8,
+        _mm512_subs_epi16, _mm512_subs_epi8, _mm512_subs_epu16, _mm512_subs_epu8,
+    },
+    token::token,
+    KMask32, KMask64,
+};
+
+token! {
+    /// A token proving that AVX-512F and AVX-512BW are available.
+    pub struct Avx512BW("AVX-512BW") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Avx = "avx",
+        Avx2 = "avx2",
+        Fma = "fma",
+        F16c = "f16c",
+        Avx512f = "avx512f",
+        Avx512bw = "avx512bw",
+    }
+}
+
+// Integer arithmetic.
+impl Avx512BW {
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_add_epi8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u8x64_mask(self, src: u8x64, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_mask_add_epi8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u8x64_maskz(self, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_maskz_add_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    #[doc(alias = "_mm512_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_sub_epi8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u8x64_mask(self, src: u8x64, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_mask_sub_epi8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u8x64_maskz(self, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_maskz_sub_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    #[doc(alias = "_mm512_adds_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_adds_epu8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_adds_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u8x64_mask(self, src: u8x64, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_mask_adds_epu8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_adds_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u8x64_maskz(self, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_maskz_adds_epu8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    #[doc(alias = "_mm512_subs_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_subs_epu8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_subs_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u8x64_mask(self, src: u8x64, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_mask_subs_epu8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_subs_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u8x64_maskz(self, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_maskz_subs_epu8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_min_epu8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u8x64_mask(self, src: u8x64, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_mask_min_epu8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u8x64_maskz(self, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_maskz_min_epu8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_max_epu8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u8x64_mask(self, src: u8x64, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_mask_max_epu8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u8x64_maskz(self, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_maskz_max_epu8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_add_epi8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i8x64_mask(self, src: i8x64, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_mask_add_epi8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i8x64_maskz(self, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_maskz_add_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    #[doc(alias = "_mm512_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_sub_epi8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i8x64_mask(self, src: i8x64, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_mask_sub_epi8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i8x64_maskz(self, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_maskz_sub_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    #[doc(alias = "_mm512_adds_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_adds_epi8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_adds_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i8x64_mask(self, src: i8x64, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_mask_adds_epi8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_adds_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i8x64_maskz(self, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_maskz_adds_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    #[doc(alias = "_mm512_subs_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_subs_epi8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_subs_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i8x64_mask(self, src: i8x64, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_mask_subs_epi8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_subs_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i8x64_maskz(self, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_maskz_subs_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_min_epi8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i8x64_mask(self, src: i8x64, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_mask_min_epi8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i8x64_maskz(self, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_maskz_min_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_max_epi8(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i8x64_mask(self, src: i8x64, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_mask_max_epi8(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i8x64_maskz(self, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_maskz_max_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_add_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u16x32_mask(self, src: u16x32, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_mask_add_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u16x32_maskz(self, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_maskz_add_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    #[doc(alias = "_mm512_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_sub_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u16x32_mask(self, src: u16x32, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_mask_sub_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u16x32_maskz(self, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_maskz_sub_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    #[doc(alias = "_mm512_adds_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_adds_epu16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_adds_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u16x32_mask(
+        self,
+        src: u16x32,
+        k: KMask32,
+        a: u16x32,
+        b: u16x32,
+    ) -> u16x32 {
+        unsafe { _mm512_mask_adds_epu16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_adds_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u16x32_maskz(self, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_maskz_adds_epu16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    #[doc(alias = "_mm512_subs_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_subs_epu16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_subs_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u16x32_mask(
+        self,
+        src: u16x32,
+        k: KMask32,
+        a: u16x32,
+        b: u16x32,
+    ) -> u16x32 {
+        unsafe { _mm512_mask_subs_epu16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_subs_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u16x32_maskz(self, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_maskz_subs_epu16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_mullo_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u16x32_mask(self, src: u16x32, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_mask_mullo_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u16x32_maskz(self, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_maskz_mullo_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_min_epu16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u16x32_mask(self, src: u16x32, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_mask_min_epu16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u16x32_maskz(self, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_maskz_min_epu16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_max_epu16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u16x32_mask(self, src: u16x32, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_mask_max_epu16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u16x32_maskz(self, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_maskz_max_epu16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_add_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i16x32_mask(self, src: i16x32, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_mask_add_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i16x32_maskz(self, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_maskz_add_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    #[doc(alias = "_mm512_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_sub_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i16x32_mask(self, src: i16x32, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_mask_sub_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i16x32_maskz(self, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_maskz_sub_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    #[doc(alias = "_mm512_adds_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_adds_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_adds_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i16x32_mask(
+        self,
+        src: i16x32,
+        k: KMask32,
+        a: i16x32,
+        b: i16x32,
+    ) -> i16x32 {
+        unsafe { _mm512_mask_adds_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_adds_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i16x32_maskz(self, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_maskz_adds_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    #[doc(alias = "_mm512_subs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_subs_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_subs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i16x32_mask(
+        self,
+        src: i16x32,
+        k: KMask32,
+        a: i16x32,
+        b: i16x32,
+    ) -> i16x32 {
+        unsafe { _mm512_mask_subs_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, saturating on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_subs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i16x32_maskz(self, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_maskz_subs_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_mullo_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i16x32_mask(self, src: i16x32, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_mask_mullo_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i16x32_maskz(self, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_maskz_mullo_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_min_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i16x32_mask(self, src: i16x32, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_mask_min_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i16x32_maskz(self, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_maskz_min_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_max_epi16(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i16x32_mask(self, src: i16x32, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_mask_max_epi16(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i16x32_maskz(self, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_maskz_max_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+}
+
+// Integer comparisons.
+impl Avx512BW {
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm512_cmpeq_epu8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u8x64(self, a: u8x64, b: u8x64) -> KMask64 {
+        unsafe { _mm512_cmpeq_epu8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm512_cmpneq_epu8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_u8x64(self, a: u8x64, b: u8x64) -> KMask64 {
+        unsafe { _mm512_cmpneq_epu8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm512_cmplt_epu8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_u8x64(self, a: u8x64, b: u8x64) -> KMask64 {
+        unsafe { _mm512_cmplt_epu8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm512_cmple_epu8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_u8x64(self, a: u8x64, b: u8x64) -> KMask64 {
+        unsafe { _mm512_cmple_epu8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm512_cmpgt_epu8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_u8x64(self, a: u8x64, b: u8x64) -> KMask64 {
+        unsafe { _mm512_cmpgt_epu8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm512_cmpge_epu8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_u8x64(self, a: u8x64, b: u8x64) -> KMask64 {
+        unsafe { _mm512_cmpge_epu8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm512_cmpeq_epi8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i8x64(self, a: i8x64, b: i8x64) -> KMask64 {
+        unsafe { _mm512_cmpeq_epi8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm512_cmpneq_epi8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_i8x64(self, a: i8x64, b: i8x64) -> KMask64 {
+        unsafe { _mm512_cmpneq_epi8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm512_cmplt_epi8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i8x64(self, a: i8x64, b: i8x64) -> KMask64 {
+        unsafe { _mm512_cmplt_epi8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm512_cmple_epi8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_i8x64(self, a: i8x64, b: i8x64) -> KMask64 {
+        unsafe { _mm512_cmple_epi8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm512_cmpgt_epi8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i8x64(self, a: i8x64, b: i8x64) -> KMask64 {
+        unsafe { _mm512_cmpgt_epi8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm512_cmpge_epi8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_i8x64(self, a: i8x64, b: i8x64) -> KMask64 {
+        unsafe { _mm512_cmpge_epi8_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm512_cmpeq_epu16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u16x32(self, a: u16x32, b: u16x32) -> KMask32 {
+        unsafe { _mm512_cmpeq_epu16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm512_cmpneq_epu16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_u16x32(self, a: u16x32, b: u16x32) -> KMask32 {
+        unsafe { _mm512_cmpneq_epu16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm512_cmplt_epu16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_u16x32(self, a: u16x32, b: u16x32) -> KMask32 {
+        unsafe { _mm512_cmplt_epu16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm512_cmple_epu16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_u16x32(self, a: u16x32, b: u16x32) -> KMask32 {
+        unsafe { _mm512_cmple_epu16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm512_cmpgt_epu16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_u16x32(self, a: u16x32, b: u16x32) -> KMask32 {
+        unsafe { _mm512_cmpgt_epu16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm512_cmpge_epu16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_u16x32(self, a: u16x32, b: u16x32) -> KMask32 {
+        unsafe { _mm512_cmpge_epu16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm512_cmpeq_epi16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i16x32(self, a: i16x32, b: i16x32) -> KMask32 {
+        unsafe { _mm512_cmpeq_epi16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm512_cmpneq_epi16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_i16x32(self, a: i16x32, b: i16x32) -> KMask32 {
+        unsafe { _mm512_cmpneq_epi16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm512_cmplt_epi16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i16x32(self, a: i16x32, b: i16x32) -> KMask32 {
+        unsafe { _mm512_cmplt_epi16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm512_cmple_epi16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_i16x32(self, a: i16x32, b: i16x32) -> KMask32 {
+        unsafe { _mm512_cmple_epi16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm512_cmpgt_epi16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i16x32(self, a: i16x32, b: i16x32) -> KMask32 {
+        unsafe { _mm512_cmpgt_epi16_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm512_cmpge_epi16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_i16x32(self, a: i16x32, b: i16x32) -> KMask32 {
+        unsafe { _mm512_cmpge_epi16_mask(a.avx512, b.avx512) }.into()
+    }
+}
+
+// Blends, shuffles and mask conversions.
+impl Avx512BW {
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u8x64(self, k: KMask64, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_mask_blend_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i8x64(self, k: KMask64, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_mask_blend_epi8(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u16x32(self, k: KMask32, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_mask_blend_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i16x32(self, k: KMask32, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_mask_blend_epi16(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Shuffle the bytes within each 128-bit lane of `a` using the indices in `idx`.
+    ///
+    /// Only the lowest four bits of each index are used, so bytes never cross between
+    /// 128-bit lanes. Any index with its highest bit set results in zero.
+    #[doc(alias = "_mm512_shuffle_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle_bytes_u8x64(self, a: u8x64, idx: u8x64) -> u8x64 {
+        unsafe { _mm512_shuffle_epi8(a.avx512, idx.avx512) }.into()
+    }
+
+    /// Collect the sign bit of every lane into a mask.
+    #[doc(alias = "_mm512_movepi8_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn to_kmask_i8x64(self, a: i8x64) -> KMask64 {
+        unsafe { _mm512_movepi8_mask(a.avx512) }.into()
+    }
+
+    /// Set every lane whose bit in `k` is set to all ones, and every other lane to zero.
+    #[doc(alias = "_mm512_movm_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn from_kmask_i8x64(self, k: KMask64) -> i8x64 {
+        unsafe { _mm512_movm_epi8(k.0) }.into()
+    }
+
+    /// Collect the sign bit of every lane into a mask.
+    #[doc(alias = "_mm512_movepi16_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn to_kmask_i16x32(self, a: i16x32) -> KMask32 {
+        unsafe { _mm512_movepi16_mask(a.avx512) }.into()
+    }
+
+    /// Set every lane whose bit in `k` is set to all ones, and every other lane to zero.
+    #[doc(alias = "_mm512_movm_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn from_kmask_i16x32(self, k: KMask32) -> i16x32 {
+        unsafe { _mm512_movm_epi16(k.0) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::{i16x32, i8x64, u16x32, u8x64},
+        util::test::{assert_lanes, Rng},
+    };
+
+    use super::{
+        super::{
+            test::{blends, compares, masked, token_or_skip, ROUNDS},
+            KMask32, KMask64,
+        },
+        Avx512BW,
+    };
+
+    #[test]
+    fn masked_8() {
+        let token = token_or_skip!(Avx512BW);
+
+        masked! {
+            token;
+            add_u8x64 / add_u8x64_mask / add_u8x64_maskz (a, b): u8x64, KMask64
+                => a.wrapping_add(b);
+            sub_u8x64 / sub_u8x64_mask / sub_u8x64_maskz (a, b): u8x64, KMask64
+                => a.wrapping_sub(b);
+            saturating_add_u8x64 / saturating_add_u8x64_mask / saturating_add_u8x64_maskz
+                (a, b): u8x64, KMask64 => a.saturating_add(b);
+            saturating_sub_u8x64 / saturating_sub_u8x64_mask / saturating_sub_u8x64_maskz
+                (a, b): u8x64, KMask64 => a.saturating_sub(b);
+            min_u8x64 / min_u8x64_mask / min_u8x64_maskz (a, b): u8x64, KMask64 => a.min(b);
+            max_u8x64 / max_u8x64_mask / max_u8x64_maskz (a, b): u8x64, KMask64 => a.max(b);
+
+            add_i8x64 / add_i8x64_mask / add_i8x64_maskz (a, b): i8x64, KMask64
+                => a.wrapping_add(b);
+            sub_i8x64 / sub_i8x64_mask / sub_i8x64_maskz (a, b): i8x64, KMask64
+                => a.wrapping_sub(b);
+            saturating_add_i8x64 / saturating_add_i8x64_mask / saturating_add_i8x64_maskz
+                (a, b): i8x64, KMask64 => a.saturating_add(b);
+            saturating_sub_i8x64 / saturating_sub_i8x64_mask / saturating_sub_i8x64_maskz
+                (a, b): i8x64, KMask64 => a.saturating_sub(b);
+            min_i8x64 / min_i8x64_mask / min_i8x64_maskz (a, b): i8x64, KMask64 => a.min(b);
+            max_i8x64 / max_i8x64_mask / max_i8x64_maskz (a, b): i8x64, KMask64 => a.max(b);
+        }
+    }
+
+    #[test]
+    fn masked_16() {
+        let token = token_or_skip!(Avx512BW);
+
+        masked! {
+            token;
+            add_u16x32 / add_u16x32_mask / add_u16x32_maskz (a, b): u16x32, KMask32
+                => a.wrapping_add(b);
+            sub_u16x32 / sub_u16x32_mask / sub_u16x32_maskz (a, b): u16x32, KMask32
+                => a.wrapping_sub(b);
+            saturating_add_u16x32 / saturating_add_u16x32_mask / saturating_add_u16x32_maskz
+                (a, b): u16x32, KMask32 => a.saturating_add(b);
+            saturating_sub_u16x32 / saturating_sub_u16x32_mask / saturating_sub_u16x32_maskz
+                (a, b): u16x32, KMask32 => a.saturating_sub(b);
+            mul_u16x32 / mul_u16x32_mask / mul_u16x32_maskz (a, b): u16x32, KMask32
+                => a.wrapping_mul(b);
+            min_u16x32 / min_u16x32_mask / min_u16x32_maskz (a, b): u16x32, KMask32 => a.min(b);
+            max_u16x32 / max_u16x32_mask / max_u16x32_maskz (a, b): u16x32, KMask32 => a.max(b);
+
+            add_i16x32 / add_i16x32_mask / add_i16x32_maskz (a, b): i16x32, KMask32
+                => a.wrapping_add(b);
+            sub_i16x32 / sub_i16x32_mask / sub_i16x32_maskz (a, b): i16x32, KMask32
+                => a.wrapping_sub(b);
+            saturating_add_i16x32 / saturating_add_i16x32_mask / saturating_add_i16x32_maskz
+                (a, b): i16x32, KMask32 => a.saturating_add(b);
+            saturating_sub_i16x32 / saturating_sub_i16x32_mask / saturating_sub_i16x32_maskz
+                (a, b): i16x32, KMask32 => a.saturating_sub(b);
+            mul_i16x32 / mul_i16x32_mask / mul_i16x32_maskz (a, b): i16x32, KMask32
+                => a.wrapping_mul(b);
+            min_i16x32 / min_i16x32_mask / min_i16x32_maskz (a, b): i16x32, KMask32 => a.min(b);
+            max_i16x32 / max_i16x32_mask / max_i16x32_maskz (a, b): i16x32, KMask32 => a.max(b);
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let token = token_or_skip!(Avx512BW);
+
+        compares! {
+            token;
+            u8x64 => eq_u8x64, ne_u8x64, lt_u8x64, le_u8x64, gt_u8x64, ge_u8x64;
+            i8x64 => eq_i8x64, ne_i8x64, lt_i8x64, le_i8x64, gt_i8x64, ge_i8x64;
+            u16x32 => eq_u16x32, ne_u16x32, lt_u16x32, le_u16x32, gt_u16x32, ge_u16x32;
+            i16x32 => eq_i16x32, ne_i16x32, lt_i16x32, le_i16x32, gt_i16x32, ge_i16x32;
+        }
+    }
+
+    #[test]
+    fn blend() {
+        let token = token_or_skip!(Avx512BW);
+
+        blends! {
+            token;
+            blend_u8x64: u8x64, KMask64;
+            blend_i8x64: i8x64, KMask64;
+            blend_u16x32: u16x32, KMask32;
+            blend_i16x32: i16x32, KMask32;
+        }
+    }
+
+    #[test]
+    fn kmask_conversions() {
+        let token = token_or_skip!(Avx512BW);
+        let mut rng = Rng::new(1);
+
+        for _ in 0..ROUNDS {
+            let a: [i8; 64] = rng.array();
+            let expected = (0..64)
+                .filter(|&i| a[i] < 0)
+                .fold(0, |bits, i| bits | 1 << i);
+
+            assert_eq!(
+                token.to_kmask_i8x64(i8x64::from_array(a)).to_bits(),
+                expected
+            );
+
+            let k = KMask64::from_bits(rng.gen());
+            let expected: [i8; 64] = core::array::from_fn(|i| -(k.test(i as u32) as i8));
+
+            assert_eq!(token.from_kmask_i8x64(k).to_array(), expected);
+
+            let a: [i16; 32] = rng.array();
+            let expected = (0..32)
+                .filter(|&i| a[i] < 0)
+                .fold(0, |bits, i| bits | 1 << i);
+
+            assert_eq!(
+                token.to_kmask_i16x32(i16x32::from_array(a)).to_bits(),
+                expected
+            );
+
+            let k = KMask32::from_bits(rng.gen());
+            let expected: [i16; 32] = core::array::from_fn(|i| -(k.test(i as u32) as i16));
+
+            assert_eq!(token.from_kmask_i16x32(k).to_array(), expected);
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use crate::types::vector::{f32x16, f64x8, i32x16, i64x8, u64x8};
+
+use super::{
+    raw::{
+        _mm512_and_pd, _mm512_and_ps, _mm512_andnot_pd, _mm512_andnot_ps, _mm512_mask_and_pd,
+        _mm512_mask_and_ps, _mm512_mask_andnot_pd, _mm512_mask_andnot_ps, _mm512_mask_mullo_epi64,
+        _mm512_mask_or_pd, _mm512_mask_or_ps, _mm512_mask_xor_pd, _mm512_mask_xor_ps,
+        _mm512_maskz_and_pd, _mm512_maskz_and_ps, _mm512_maskz_andnot_pd, _mm512_maskz_andnot_ps,
+        _mm512_maskz_mullo_epi64, _mm512_maskz_or_pd, _mm512_maskz_or_ps, _mm512_maskz_xor_pd,
+        _mm512_maskz_xor_ps, _mm512_movepi32_mask, _mm512_movepi64_mask, _mm512_movm_epi32,
+        _mm512_movm_epi64, _mm512_mullo_epi64, _mm512_or_pd, _mm512_or_ps, _mm512_xor_pd,
+        _mm512_xor_ps,
+    },
+    token::token,
+    KMask16, KMask8,
+};
+
+token! {
+    /// A token proving that AVX-512F and AVX-512DQ are available.
+    pub struct Avx512DQ("AVX-512DQ") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Avx = "avx",
+        Avx2 = "avx2",
+        Fma = "fma",
+        F16c = "f16c",
+        Avx512f = "avx512f",
+        Avx512dq = "avx512dq",
+    }
+}
+
+// Integer arithmetic.
+impl Avx512DQ {
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_mullo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_mullo_epi64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_mullo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u64x8_mask(self, src: u64x8, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_mask_mullo_epi64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_mullo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u64x8_maskz(self, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_maskz_mullo_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_mullo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_mullo_epi64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_mullo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i64x8_mask(self, src: i64x8, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_mask_mullo_epi64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_mullo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i64x8_maskz(self, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_maskz_mullo_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+}
+
+// Floating point bitwise logic.
+impl Avx512DQ {
+    /// Compute the bitwise AND of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_and_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_and_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_and_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_and_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_and_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_and_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_or_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_or_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_or_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_or_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_or_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_or_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_xor_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_xor_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_xor_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_xor_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_xor_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_xor_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_andnot_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `b` and the complement of `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_andnot_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_andnot_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `b` and the complement of `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_andnot_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_andnot_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_and_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_and_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_and_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_and_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_and_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_and_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_or_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_or_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_or_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_or_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_or_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_or_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_xor_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_xor_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_xor_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_xor_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_xor_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_xor_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_andnot_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `b` and the complement of `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_andnot_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_andnot_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of every lane of `b` and the complement of `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_andnot_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_andnot_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+}
+
+// Mask conversions.
+impl Avx512DQ {
+    /// Collect the sign bit of every lane into a mask.
+    #[doc(alias = "_mm512_movepi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn to_kmask_i32x16(self, a: i32x16) -> KMask16 {
+        unsafe { _mm512_movepi32_mask(a.avx512) }.into()
+    }
+
+    /// Set every lane whose bit in `k` is set to all ones, and every other lane to zero.
+    #[doc(alias = "_mm512_movm_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn from_kmask_i32x16(self, k: KMask16) -> i32x16 {
+        unsafe { _mm512_movm_epi32(k.0) }.into()
+    }
+
+    /// Collect the sign bit of every lane into a mask.
+    #[doc(alias = "_mm512_movepi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn to_kmask_i64x8(self, a: i64x8) -> KMask8 {
+        unsafe { _mm512_movepi64_mask(a.avx512) }.into()
+    }
+
+    /// Set every lane whose bit in `k` is set to all ones, and every other lane to zero.
+    #[doc(alias = "_mm512_movm_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn from_kmask_i64x8(self, k: KMask8) -> i64x8 {
+        unsafe { _mm512_movm_epi64(k.0) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::{f32x16, f64x8, i32x16, i64x8, u64x8},
+        util::test::{assert_lanes, Rng},
+    };
+
+    use super::{
+        super::{
+            test::{masked, token_or_skip, ROUNDS},
+            KMask16, KMask8,
+        },
+        Avx512DQ,
+    };
+
+    #[test]
+    fn masked_mul() {
+        let token = token_or_skip!(Avx512DQ);
+
+        masked! {
+            token;
+            mul_u64x8 / mul_u64x8_mask / mul_u64x8_maskz (a, b): u64x8, KMask8 => a.wrapping_mul(b);
+            mul_i64x8 / mul_i64x8_mask / mul_i64x8_maskz (a, b): i64x8, KMask8 => a.wrapping_mul(b);
+        }
+    }
+
+    #[test]
+    fn masked_bitwise() {
+        let token = token_or_skip!(Avx512DQ);
+
+        masked! {
+            token;
+            and_f32x16 / and_f32x16_mask / and_f32x16_maskz (a, b): f32x16, KMask16
+                => f32::from_bits(a.to_bits() & b.to_bits());
+            or_f32x16 / or_f32x16_mask / or_f32x16_maskz (a, b): f32x16, KMask16
+                => f32::from_bits(a.to_bits() | b.to_bits());
+            xor_f32x16 / xor_f32x16_mask / xor_f32x16_maskz (a, b): f32x16, KMask16
+                => f32::from_bits(a.to_bits() ^ b.to_bits());
+            andnot_f32x16 / andnot_f32x16_mask / andnot_f32x16_maskz (a, b): f32x16, KMask16
+                => f32::from_bits(!a.to_bits() & b.to_bits());
+
+            and_f64x8 / and_f64x8_mask / and_f64x8_maskz (a, b): f64x8, KMask8
+                => f64::from_bits(a.to_bits() & b.to_bits());
+            or_f64x8 / or_f64x8_mask / or_f64x8_maskz (a, b): f64x8, KMask8
+                => f64::from_bits(a.to_bits() | b.to_bits());
+            xor_f64x8 / xor_f64x8_mask / xor_f64x8_maskz (a, b): f64x8, KMask8
+                => f64::from_bits(a.to_bits() ^ b.to_bits());
+            andnot_f64x8 / andnot_f64x8_mask / andnot_f64x8_maskz (a, b): f64x8, KMask8
+                => f64::from_bits(!a.to_bits() & b.to_bits());
+        }
+    }
+
+    #[test]
+    fn kmask_conversions() {
+        let token = token_or_skip!(Avx512DQ);
+        let mut rng = Rng::new(1);
+
+        for _ in 0..ROUNDS {
+            let a: [i32; 16] = rng.array();
+            let expected = (0..16)
+                .filter(|&i| a[i] < 0)
+                .fold(0, |bits, i| bits | 1 << i);
+
+            assert_eq!(
+                token.to_kmask_i32x16(i32x16::from_array(a)).to_bits(),
+                expected
+            );
+
+            let k = KMask16::from_bits(rng.gen());
+            let expected: [i32; 16] = core::array::from_fn(|i| -(k.test(i as u32) as i32));
+
+            assert_eq!(token.from_kmask_i32x16(k).to_array(), expected);
+
+            let a: [i64; 8] = rng.array();
+            let expected = (0..8)
+                .filter(|&i| a[i] < 0)
+                .fold(0, |bits, i| bits | 1 << i);
+
+            assert_eq!(
+                token.to_kmask_i64x8(i64x8::from_array(a)).to_bits(),
+                expected
+            );
+
+            let k = KMask8::from_bits(rng.gen());
+            let expected: [i64; 8] = core::array::from_fn(|i| -(k.test(i as u32) as i64));
+
+            assert_eq!(token.from_kmask_i64x8(k).to_array(), expected);
+        }
+    }
+}
@@ -0,0 +1,2271 @@
+use crate::types::vector::{
+    f32x16, f64x8, i16x32, i32x16, i64x8, i8x64, u16x32, u32x16, u64x8, u8x64,
+};
+
+use super::{
+    float::{cmp_imm, Cmp},
+    raw::{
+        _mm512_add_epi32, _mm512_add_epi64, _mm512_add_pd, _mm512_add_ps, _mm512_and_si512,
+        _mm512_andnot_si512, _mm512_cmp_pd_mask, _mm512_cmp_ps_mask, _mm512_cmpeq_epi32_mask,
+        _mm512_cmpeq_epi64_mask, _mm512_cmpeq_epu32_mask, _mm512_cmpeq_epu64_mask,
+        _mm512_cmpge_epi32_mask, _mm512_cmpge_epi64_mask, _mm512_cmpge_epu32_mask,
+        _mm512_cmpge_epu64_mask, _mm512_cmpgt_epi32_mask, _mm512_cmpgt_epi64_mask,
+        _mm512_cmpgt_epu32_mask, _mm512_cmpgt_epu64_mask, _mm512_cmple_epi32_mask,
+        _mm512_cmple_epi64_mask, _mm512_cmple_epu32_mask, _mm512_cmple_epu64_mask,
+        _mm512_cmplt_epi32_mask, _mm512_cmplt_epi64_mask, _mm512_cmplt_epu32_mask,
+        _mm512_cmplt_epu64_mask, _mm512_cmpneq_epi32_mask, _mm512_cmpneq_epi64_mask,
+        _mm512_cmpneq_epu32_mask, _mm512_cmpneq_epu64_mask, _mm512_div_pd, _mm512_div_ps,
+        _mm512_fmadd_pd, _mm512_fmadd_ps, _mm512_fmsub_pd, _mm512_fmsub_ps, _mm512_fnmadd_pd,
+        _mm512_fnmadd_ps, _mm512_fnmsub_pd, _mm512_fnmsub_ps, _mm512_mask_add_epi32,
+        _mm512_mask_add_epi64, _mm512_mask_add_pd, _mm512_mask_add_ps, _mm512_mask_blend_epi32,
+        _mm512_mask_blend_epi64, _mm512_mask_blend_pd, _mm512_mask_blend_ps,
+        _mm512_mask_cmp_pd_mask, _mm512_mask_cmp_ps_mask, _mm512_mask_compress_epi32,
+        _mm512_mask_compress_epi64, _mm512_mask_compress_pd, _mm512_mask_compress_ps,
+        _mm512_mask_div_pd, _mm512_mask_div_ps, _mm512_mask_expand_epi32, _mm512_mask_expand_epi64,
+        _mm512_mask_expand_pd, _mm512_mask_expand_ps, _mm512_mask_max_epi32, _mm512_mask_max_epi64,
+        _mm512_mask_max_epu32, _mm512_mask_max_epu64, _mm512_mask_max_pd, _mm512_mask_max_ps,
+        _mm512_mask_min_epi32, _mm512_mask_min_epi64, _mm512_mask_min_epu32, _mm512_mask_min_epu64,
+        _mm512_mask_min_pd, _mm512_mask_min_ps, _mm512_mask_mul_pd, _mm512_mask_mul_ps,
+        _mm512_mask_mullo_epi32, _mm512_mask_sqrt_pd, _mm512_mask_sqrt_ps, _mm512_mask_sub_epi32,
+        _mm512_mask_sub_epi64, _mm512_mask_sub_pd, _mm512_mask_sub_ps, _mm512_maskz_add_epi32,
+        _mm512_maskz_add_epi64, _mm512_maskz_add_pd, _mm512_maskz_add_ps,
+        _mm512_maskz_compress_epi32, _mm512_maskz_compress_epi64, _mm512_maskz_compress_pd,
+        _mm512_maskz_compress_ps, _mm512_maskz_div_pd, _mm512_maskz_div_ps,
+        _mm512_maskz_expand_epi32, _mm512_maskz_expand_epi64, _mm512_maskz_expand_pd,
+        _mm512_maskz_expand_ps, _mm512_maskz_max_epi32, _mm512_maskz_max_epi64,
+        _mm512_maskz_max_epu32, _mm512_maskz_max_epu64, _mm512_maskz_max_pd, _mm512_maskz_max_ps,
+        _mm512_maskz_min_epi32, _mm512_maskz_min_epi64, _mm512_maskz_min_epu32,
+        _mm512_maskz_min_epu64, _mm512_maskz_min_pd, _mm512_maskz_min_ps, _mm512_maskz_mul_pd,
+        _mm512_maskz_mul_ps, _mm512_maskz_mullo_epi32, _mm512_maskz_sqrt_pd, _mm512_maskz_sqrt_ps,
+        _mm512_maskz_sub_epi32, _mm512_maskz_sub_epi64, _mm512_maskz_sub_pd, _mm512_maskz_sub_ps,
+        _mm512_max_epi32, _mm512_max_epi64, _mm512_max_epu32, _mm512_max_epu64, _mm512_max_pd,
+        _mm512_max_ps, _mm512_min_epi32, _mm512_min_epi64, _mm512_min_epu32, _mm512_min_epu64,
+        _mm512_min_pd, _mm512_min_ps, _mm512_mul_pd, _mm512_mul_ps, _mm512_mullo_epi32,
+        _mm512_or_si512, _mm512_permutex2var_epi32, _mm512_permutex2var_epi64,
+        _mm512_permutex2var_pd, _mm512_permutex2var_ps, _mm512_permutexvar_epi32,
+        _mm512_permutexvar_epi64, _mm512_permutexvar_pd, _mm512_permutexvar_ps, _mm512_sllv_epi32,
+        _mm512_sllv_epi64, _mm512_sqrt_pd, _mm512_sqrt_ps, _mm512_srav_epi32, _mm512_srav_epi64,
+        _mm512_srlv_epi32, _mm512_srlv_epi64, _mm512_sub_epi32, _mm512_sub_epi64, _mm512_sub_pd,
+        _mm512_sub_ps, _mm512_ternarylogic_epi32, _mm512_ternarylogic_epi64, _mm512_xor_si512,
+    },
+    token::token,
+    KMask16, KMask8,
+};
+
+token! {
+    /// A token proving that AVX-512F is available.
+    pub struct Avx512F("AVX-512F") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Avx = "avx",
+        Avx2 = "avx2",
+        Fma = "fma",
+        F16c = "f16c",
+        Avx512f = "avx512f",
+    }
+}
+
+// Floating point arithmetic.
+impl Avx512F {
+    /// Add every lane of `a` and `b`.
+    #[doc(alias = "_mm512_add_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_add_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_add_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_add_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`.
+    #[doc(alias = "_mm512_sub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_sub_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_sub_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_sub_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`.
+    #[doc(alias = "_mm512_mul_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mul_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_mul_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_mul_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_mul_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_mul_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Divide every lane of `a` by `b`.
+    #[doc(alias = "_mm512_div_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_div_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Divide every lane of `a` by `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_div_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_div_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Divide every lane of `a` by `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_div_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_div_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_min_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_min_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_min_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f32x16(self, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_max_ps(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_max_ps(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f32x16_maskz(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_max_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the square root of every lane of `a`.
+    #[doc(alias = "_mm512_sqrt_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f32x16(self, a: f32x16) -> f32x16 {
+        unsafe { _mm512_sqrt_ps(a.avx512) }.into()
+    }
+
+    /// Compute the square root of every lane of `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sqrt_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_sqrt_ps(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Compute the square root of every lane of `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sqrt_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f32x16_maskz(self, k: KMask16, a: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_sqrt_ps(k.0, a.avx512) }.into()
+    }
+
+    /// Compute `(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm512_fmadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_f32x16(self, a: f32x16, b: f32x16, c: f32x16) -> f32x16 {
+        unsafe { _mm512_fmadd_ps(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Compute `(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm512_fmsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_sub_f32x16(self, a: f32x16, b: f32x16, c: f32x16) -> f32x16 {
+        unsafe { _mm512_fmsub_ps(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Compute `-(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm512_fnmadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_add_f32x16(self, a: f32x16, b: f32x16, c: f32x16) -> f32x16 {
+        unsafe { _mm512_fnmadd_ps(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Compute `-(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm512_fnmsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_sub_f32x16(self, a: f32x16, b: f32x16, c: f32x16) -> f32x16 {
+        unsafe { _mm512_fnmsub_ps(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`.
+    #[doc(alias = "_mm512_add_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_add_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_add_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_add_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`.
+    #[doc(alias = "_mm512_sub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_sub_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_sub_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_sub_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`.
+    #[doc(alias = "_mm512_mul_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mul_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_mul_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_mul_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_mul_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_mul_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Divide every lane of `a` by `b`.
+    #[doc(alias = "_mm512_div_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_div_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Divide every lane of `a` by `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_div_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_div_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Divide every lane of `a` by `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_div_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_div_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_min_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_min_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_min_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f64x8(self, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_max_pd(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_max_pd(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f64x8_maskz(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_max_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the square root of every lane of `a`.
+    #[doc(alias = "_mm512_sqrt_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f64x8(self, a: f64x8) -> f64x8 {
+        unsafe { _mm512_sqrt_pd(a.avx512) }.into()
+    }
+
+    /// Compute the square root of every lane of `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sqrt_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_sqrt_pd(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Compute the square root of every lane of `a`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sqrt_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f64x8_maskz(self, k: KMask8, a: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_sqrt_pd(k.0, a.avx512) }.into()
+    }
+
+    /// Compute `(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm512_fmadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_f64x8(self, a: f64x8, b: f64x8, c: f64x8) -> f64x8 {
+        unsafe { _mm512_fmadd_pd(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Compute `(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm512_fmsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_sub_f64x8(self, a: f64x8, b: f64x8, c: f64x8) -> f64x8 {
+        unsafe { _mm512_fmsub_pd(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Compute `-(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm512_fnmadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_add_f64x8(self, a: f64x8, b: f64x8, c: f64x8) -> f64x8 {
+        unsafe { _mm512_fnmadd_pd(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Compute `-(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm512_fnmsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_sub_f64x8(self, a: f64x8, b: f64x8, c: f64x8) -> f64x8 {
+        unsafe { _mm512_fnmsub_pd(a.avx512, b.avx512, c.avx512) }.into()
+    }
+}
+
+// Floating point comparisons.
+impl Avx512F {
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// When `cmp` is known at compile time, this compiles to a single comparison with an
+    /// immediate predicate.
+    #[doc(alias = "_mm512_cmp_ps_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f32x16(self, a: f32x16, b: f32x16, cmp: Cmp) -> KMask16 {
+        unsafe { cmp_imm!(cmp, IMM => _mm512_cmp_ps_mask::<IMM>(a.avx512, b.avx512)) }.into()
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// Lanes whose bit in `k` is not set are cleared in the result.
+    #[doc(alias = "_mm512_mask_cmp_ps_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f32x16_mask(self, k: KMask16, a: f32x16, b: f32x16, cmp: Cmp) -> KMask16 {
+        unsafe { cmp_imm!(cmp, IMM => _mm512_mask_cmp_ps_mask::<IMM>(k.0, a.avx512, b.avx512)) }
+            .into()
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// When `cmp` is known at compile time, this compiles to a single comparison with an
+    /// immediate predicate.
+    #[doc(alias = "_mm512_cmp_pd_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f64x8(self, a: f64x8, b: f64x8, cmp: Cmp) -> KMask8 {
+        unsafe { cmp_imm!(cmp, IMM => _mm512_cmp_pd_mask::<IMM>(a.avx512, b.avx512)) }.into()
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// Lanes whose bit in `k` is not set are cleared in the result.
+    #[doc(alias = "_mm512_mask_cmp_pd_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f64x8_mask(self, k: KMask8, a: f64x8, b: f64x8, cmp: Cmp) -> KMask8 {
+        unsafe { cmp_imm!(cmp, IMM => _mm512_mask_cmp_pd_mask::<IMM>(k.0, a.avx512, b.avx512)) }
+            .into()
+    }
+}
+
+// Integer arithmetic.
+impl Avx512F {
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_add_epi32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u32x16_mask(self, src: u32x16, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_mask_add_epi32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u32x16_maskz(self, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_maskz_add_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    #[doc(alias = "_mm512_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_sub_epi32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u32x16_mask(self, src: u32x16, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_mask_sub_epi32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u32x16_maskz(self, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_maskz_sub_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_mullo_epi32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u32x16_mask(self, src: u32x16, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_mask_mullo_epi32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u32x16_maskz(self, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_maskz_mullo_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_min_epu32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u32x16_mask(self, src: u32x16, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_mask_min_epu32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u32x16_maskz(self, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_maskz_min_epu32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_max_epu32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u32x16_mask(self, src: u32x16, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_mask_max_epu32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u32x16_maskz(self, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_maskz_max_epu32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_add_epi32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i32x16_mask(self, src: i32x16, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_mask_add_epi32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i32x16_maskz(self, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_maskz_add_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    #[doc(alias = "_mm512_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_sub_epi32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i32x16_mask(self, src: i32x16, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_mask_sub_epi32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i32x16_maskz(self, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_maskz_sub_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_mullo_epi32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i32x16_mask(self, src: i32x16, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_mask_mullo_epi32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i32x16_maskz(self, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_maskz_mullo_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_min_epi32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i32x16_mask(self, src: i32x16, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_mask_min_epi32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i32x16_maskz(self, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_maskz_min_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_max_epi32(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i32x16_mask(self, src: i32x16, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_mask_max_epi32(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i32x16_maskz(self, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_maskz_max_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_add_epi64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u64x8_mask(self, src: u64x8, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_mask_add_epi64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u64x8_maskz(self, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_maskz_add_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    #[doc(alias = "_mm512_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_sub_epi64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u64x8_mask(self, src: u64x8, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_mask_sub_epi64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u64x8_maskz(self, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_maskz_sub_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_epu64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_min_epu64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_epu64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u64x8_mask(self, src: u64x8, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_mask_min_epu64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_epu64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u64x8_maskz(self, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_maskz_min_epu64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_epu64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_max_epu64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_epu64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u64x8_mask(self, src: u64x8, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_mask_max_epu64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_epu64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u64x8_maskz(self, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_maskz_max_epu64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    #[doc(alias = "_mm512_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_add_epi64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i64x8_mask(self, src: i64x8, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_mask_add_epi64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Add every lane of `a` and `b`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i64x8_maskz(self, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_maskz_add_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    #[doc(alias = "_mm512_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_sub_epi64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i64x8_mask(self, src: i64x8, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_mask_sub_epi64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Subtract every lane of `b` from `a`, wrapping on overflow.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i64x8_maskz(self, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_maskz_sub_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_min_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_min_epi64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_min_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i64x8_mask(self, src: i64x8, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_mask_min_epi64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the minimum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_min_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i64x8_maskz(self, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_maskz_min_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    #[doc(alias = "_mm512_max_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_max_epi64(a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_max_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i64x8_mask(self, src: i64x8, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_mask_max_epi64(src.avx512, k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Get the maximum of every lane of `a` and `b`.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_max_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i64x8_maskz(self, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_maskz_max_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+}
+
+// Integer bitwise logic.
+impl Avx512F {
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm512_and_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_and_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm512_or_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_or_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm512_xor_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_xor_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u8x64(self, a: u8x64, b: u8x64) -> u8x64 {
+        unsafe { _mm512_andnot_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm512_and_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_and_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm512_or_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_or_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm512_xor_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_xor_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i8x64(self, a: i8x64, b: i8x64) -> i8x64 {
+        unsafe { _mm512_andnot_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm512_and_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_and_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm512_or_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_or_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm512_xor_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_xor_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u16x32(self, a: u16x32, b: u16x32) -> u16x32 {
+        unsafe { _mm512_andnot_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm512_and_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_and_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm512_or_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_or_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm512_xor_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_xor_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i16x32(self, a: i16x32, b: i16x32) -> i16x32 {
+        unsafe { _mm512_andnot_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm512_and_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_and_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm512_or_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_or_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm512_xor_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_xor_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u32x16(self, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_andnot_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm512_and_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_and_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm512_or_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_or_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm512_xor_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_xor_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i32x16(self, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_andnot_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm512_and_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_and_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm512_or_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_or_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm512_xor_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_xor_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u64x8(self, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_andnot_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm512_and_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_and_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm512_or_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_or_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm512_xor_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_xor_si512(a.avx512, b.avx512) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm512_andnot_si512")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i64x8(self, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_andnot_si512(a.avx512, b.avx512) }.into()
+    }
+}
+
+// Shifts and bitwise logic.
+impl Avx512F {
+    /// Shift every lane of `a` left by the corresponding lane of `count`.
+    ///
+    /// Shifting by at least the bit width of a lane results in zero.
+    #[doc(alias = "_mm512_sllv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_u32x16(self, a: u32x16, count: u32x16) -> u32x16 {
+        unsafe { _mm512_sllv_epi32(a.avx512, count.avx512) }.into()
+    }
+
+    /// Shift every lane of `a` right by the corresponding lane of `count`.
+    ///
+    /// Shifting by at least the bit width of a lane results in zero.
+    #[doc(alias = "_mm512_srlv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_u32x16(self, a: u32x16, count: u32x16) -> u32x16 {
+        unsafe { _mm512_srlv_epi32(a.avx512, count.avx512) }.into()
+    }
+
+    /// Compute an arbitrary bitwise function of `a`, `b` and `c`.
+    ///
+    /// For every bit, the bits of `a`, `b` and `c` form a 3-bit index (with `a` being
+    /// the most significant) into the truth table `IMM8`.
+    #[doc(alias = "_mm512_ternarylogic_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ternary_logic_u32x16<const IMM8: i32>(self, a: u32x16, b: u32x16, c: u32x16) -> u32x16 {
+        unsafe { _mm512_ternarylogic_epi32::<IMM8>(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Shift every lane of `a` left by the corresponding lane of `count`.
+    ///
+    /// Shifting by at least the bit width of a lane results in zero.
+    #[doc(alias = "_mm512_sllv_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_i32x16(self, a: i32x16, count: u32x16) -> i32x16 {
+        unsafe { _mm512_sllv_epi32(a.avx512, count.avx512) }.into()
+    }
+
+    /// Shift every lane of `a` right by the corresponding lane of `count`.
+    ///
+    /// Shifting by at least the bit width of a lane results in every bit being a copy of
+    /// the sign bit.
+    #[doc(alias = "_mm512_srav_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_i32x16(self, a: i32x16, count: u32x16) -> i32x16 {
+        unsafe { _mm512_srav_epi32(a.avx512, count.avx512) }.into()
+    }
+
+    /// Compute an arbitrary bitwise function of `a`, `b` and `c`.
+    ///
+    /// For every bit, the bits of `a`, `b` and `c` form a 3-bit index (with `a` being
+    /// the most significant) into the truth table `IMM8`.
+    #[doc(alias = "_mm512_ternarylogic_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ternary_logic_i32x16<const IMM8: i32>(self, a: i32x16, b: i32x16, c: i32x16) -> i32x16 {
+        unsafe { _mm512_ternarylogic_epi32::<IMM8>(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Shift every lane of `a` left by the corresponding lane of `count`.
+    ///
+    /// Shifting by at least the bit width of a lane results in zero.
+    #[doc(alias = "_mm512_sllv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_u64x8(self, a: u64x8, count: u64x8) -> u64x8 {
+        unsafe { _mm512_sllv_epi64(a.avx512, count.avx512) }.into()
+    }
+
+    /// Shift every lane of `a` right by the corresponding lane of `count`.
+    ///
+    /// Shifting by at least the bit width of a lane results in zero.
+    #[doc(alias = "_mm512_srlv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_u64x8(self, a: u64x8, count: u64x8) -> u64x8 {
+        unsafe { _mm512_srlv_epi64(a.avx512, count.avx512) }.into()
+    }
+
+    /// Compute an arbitrary bitwise function of `a`, `b` and `c`.
+    ///
+    /// For every bit, the bits of `a`, `b` and `c` form a 3-bit index (with `a` being
+    /// the most significant) into the truth table `IMM8`.
+    #[doc(alias = "_mm512_ternarylogic_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ternary_logic_u64x8<const IMM8: i32>(self, a: u64x8, b: u64x8, c: u64x8) -> u64x8 {
+        unsafe { _mm512_ternarylogic_epi64::<IMM8>(a.avx512, b.avx512, c.avx512) }.into()
+    }
+
+    /// Shift every lane of `a` left by the corresponding lane of `count`.
+    ///
+    /// Shifting by at least the bit width of a lane results in zero.
+    #[doc(alias = "_mm512_sllv_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_var_i64x8(self, a: i64x8, count: u64x8) -> i64x8 {
+        unsafe { _mm512_sllv_epi64(a.avx512, count.avx512) }.into()
+    }
+
+    /// Shift every lane of `a` right by the corresponding lane of `count`.
+    ///
+    /// Shifting by at least the bit width of a lane results in every bit being a copy of
+    /// the sign bit.
+    #[doc(alias = "_mm512_srav_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_var_i64x8(self, a: i64x8, count: u64x8) -> i64x8 {
+        unsafe { _mm512_srav_epi64(a.avx512, count.avx512) }.into()
+    }
+
+    /// Compute an arbitrary bitwise function of `a`, `b` and `c`.
+    ///
+    /// For every bit, the bits of `a`, `b` and `c` form a 3-bit index (with `a` being
+    /// the most significant) into the truth table `IMM8`.
+    #[doc(alias = "_mm512_ternarylogic_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ternary_logic_i64x8<const IMM8: i32>(self, a: i64x8, b: i64x8, c: i64x8) -> i64x8 {
+        unsafe { _mm512_ternarylogic_epi64::<IMM8>(a.avx512, b.avx512, c.avx512) }.into()
+    }
+}
+
+// Integer comparisons.
+impl Avx512F {
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm512_cmpeq_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u32x16(self, a: u32x16, b: u32x16) -> KMask16 {
+        unsafe { _mm512_cmpeq_epu32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm512_cmpneq_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_u32x16(self, a: u32x16, b: u32x16) -> KMask16 {
+        unsafe { _mm512_cmpneq_epu32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm512_cmplt_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_u32x16(self, a: u32x16, b: u32x16) -> KMask16 {
+        unsafe { _mm512_cmplt_epu32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm512_cmple_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_u32x16(self, a: u32x16, b: u32x16) -> KMask16 {
+        unsafe { _mm512_cmple_epu32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm512_cmpgt_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_u32x16(self, a: u32x16, b: u32x16) -> KMask16 {
+        unsafe { _mm512_cmpgt_epu32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm512_cmpge_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_u32x16(self, a: u32x16, b: u32x16) -> KMask16 {
+        unsafe { _mm512_cmpge_epu32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm512_cmpeq_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i32x16(self, a: i32x16, b: i32x16) -> KMask16 {
+        unsafe { _mm512_cmpeq_epi32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm512_cmpneq_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_i32x16(self, a: i32x16, b: i32x16) -> KMask16 {
+        unsafe { _mm512_cmpneq_epi32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm512_cmplt_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i32x16(self, a: i32x16, b: i32x16) -> KMask16 {
+        unsafe { _mm512_cmplt_epi32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm512_cmple_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_i32x16(self, a: i32x16, b: i32x16) -> KMask16 {
+        unsafe { _mm512_cmple_epi32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm512_cmpgt_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i32x16(self, a: i32x16, b: i32x16) -> KMask16 {
+        unsafe { _mm512_cmpgt_epi32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm512_cmpge_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_i32x16(self, a: i32x16, b: i32x16) -> KMask16 {
+        unsafe { _mm512_cmpge_epi32_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm512_cmpeq_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u64x8(self, a: u64x8, b: u64x8) -> KMask8 {
+        unsafe { _mm512_cmpeq_epu64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm512_cmpneq_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_u64x8(self, a: u64x8, b: u64x8) -> KMask8 {
+        unsafe { _mm512_cmpneq_epu64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm512_cmplt_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_u64x8(self, a: u64x8, b: u64x8) -> KMask8 {
+        unsafe { _mm512_cmplt_epu64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm512_cmple_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_u64x8(self, a: u64x8, b: u64x8) -> KMask8 {
+        unsafe { _mm512_cmple_epu64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm512_cmpgt_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_u64x8(self, a: u64x8, b: u64x8) -> KMask8 {
+        unsafe { _mm512_cmpgt_epu64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm512_cmpge_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_u64x8(self, a: u64x8, b: u64x8) -> KMask8 {
+        unsafe { _mm512_cmpge_epu64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm512_cmpeq_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i64x8(self, a: i64x8, b: i64x8) -> KMask8 {
+        unsafe { _mm512_cmpeq_epi64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm512_cmpneq_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_i64x8(self, a: i64x8, b: i64x8) -> KMask8 {
+        unsafe { _mm512_cmpneq_epi64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm512_cmplt_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i64x8(self, a: i64x8, b: i64x8) -> KMask8 {
+        unsafe { _mm512_cmplt_epi64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm512_cmple_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_i64x8(self, a: i64x8, b: i64x8) -> KMask8 {
+        unsafe { _mm512_cmple_epi64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm512_cmpgt_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i64x8(self, a: i64x8, b: i64x8) -> KMask8 {
+        unsafe { _mm512_cmpgt_epi64_mask(a.avx512, b.avx512) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm512_cmpge_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_i64x8(self, a: i64x8, b: i64x8) -> KMask8 {
+        unsafe { _mm512_cmpge_epi64_mask(a.avx512, b.avx512) }.into()
+    }
+}
+
+// Blends, compression, expansion and permutes.
+impl Avx512F {
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_f32x16(self, k: KMask16, a: f32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_blend_ps(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are copied from `src`.
+    #[doc(alias = "_mm512_mask_compress_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_compress_ps(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm512_maskz_compress_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_f32x16_maskz(self, k: KMask16, a: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_compress_ps(k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_expand_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_f32x16_mask(self, src: f32x16, k: KMask16, a: f32x16) -> f32x16 {
+        unsafe { _mm512_mask_expand_ps(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_expand_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_f32x16_maskz(self, k: KMask16, a: f32x16) -> f32x16 {
+        unsafe { _mm512_maskz_expand_ps(k.0, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutexvar_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_f32x16(self, a: f32x16, idx: u32x16) -> f32x16 {
+        unsafe { _mm512_permutexvar_ps(idx.avx512, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across both `a` and `b` using the indices in `idx`.
+    ///
+    /// Indices address the lanes of `a` followed by the lanes of `b`. Only the lowest
+    /// bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutex2var_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes2_f32x16(self, a: f32x16, idx: u32x16, b: f32x16) -> f32x16 {
+        unsafe { _mm512_permutex2var_ps(a.avx512, idx.avx512, b.avx512) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_f64x8(self, k: KMask8, a: f64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_blend_pd(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are copied from `src`.
+    #[doc(alias = "_mm512_mask_compress_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_compress_pd(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm512_maskz_compress_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_f64x8_maskz(self, k: KMask8, a: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_compress_pd(k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_expand_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_f64x8_mask(self, src: f64x8, k: KMask8, a: f64x8) -> f64x8 {
+        unsafe { _mm512_mask_expand_pd(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_expand_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_f64x8_maskz(self, k: KMask8, a: f64x8) -> f64x8 {
+        unsafe { _mm512_maskz_expand_pd(k.0, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutexvar_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_f64x8(self, a: f64x8, idx: u64x8) -> f64x8 {
+        unsafe { _mm512_permutexvar_pd(idx.avx512, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across both `a` and `b` using the indices in `idx`.
+    ///
+    /// Indices address the lanes of `a` followed by the lanes of `b`. Only the lowest
+    /// bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutex2var_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes2_f64x8(self, a: f64x8, idx: u64x8, b: f64x8) -> f64x8 {
+        unsafe { _mm512_permutex2var_pd(a.avx512, idx.avx512, b.avx512) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u32x16(self, k: KMask16, a: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_mask_blend_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are copied from `src`.
+    #[doc(alias = "_mm512_mask_compress_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_u32x16_mask(self, src: u32x16, k: KMask16, a: u32x16) -> u32x16 {
+        unsafe { _mm512_mask_compress_epi32(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm512_maskz_compress_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_u32x16_maskz(self, k: KMask16, a: u32x16) -> u32x16 {
+        unsafe { _mm512_maskz_compress_epi32(k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_expand_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_u32x16_mask(self, src: u32x16, k: KMask16, a: u32x16) -> u32x16 {
+        unsafe { _mm512_mask_expand_epi32(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_expand_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_u32x16_maskz(self, k: KMask16, a: u32x16) -> u32x16 {
+        unsafe { _mm512_maskz_expand_epi32(k.0, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutexvar_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_u32x16(self, a: u32x16, idx: u32x16) -> u32x16 {
+        unsafe { _mm512_permutexvar_epi32(idx.avx512, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across both `a` and `b` using the indices in `idx`.
+    ///
+    /// Indices address the lanes of `a` followed by the lanes of `b`. Only the lowest
+    /// bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutex2var_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes2_u32x16(self, a: u32x16, idx: u32x16, b: u32x16) -> u32x16 {
+        unsafe { _mm512_permutex2var_epi32(a.avx512, idx.avx512, b.avx512) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i32x16(self, k: KMask16, a: i32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_mask_blend_epi32(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are copied from `src`.
+    #[doc(alias = "_mm512_mask_compress_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_i32x16_mask(self, src: i32x16, k: KMask16, a: i32x16) -> i32x16 {
+        unsafe { _mm512_mask_compress_epi32(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm512_maskz_compress_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_i32x16_maskz(self, k: KMask16, a: i32x16) -> i32x16 {
+        unsafe { _mm512_maskz_compress_epi32(k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_expand_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_i32x16_mask(self, src: i32x16, k: KMask16, a: i32x16) -> i32x16 {
+        unsafe { _mm512_mask_expand_epi32(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_expand_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_i32x16_maskz(self, k: KMask16, a: i32x16) -> i32x16 {
+        unsafe { _mm512_maskz_expand_epi32(k.0, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutexvar_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_i32x16(self, a: i32x16, idx: u32x16) -> i32x16 {
+        unsafe { _mm512_permutexvar_epi32(idx.avx512, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across both `a` and `b` using the indices in `idx`.
+    ///
+    /// Indices address the lanes of `a` followed by the lanes of `b`. Only the lowest
+    /// bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutex2var_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes2_i32x16(self, a: i32x16, idx: u32x16, b: i32x16) -> i32x16 {
+        unsafe { _mm512_permutex2var_epi32(a.avx512, idx.avx512, b.avx512) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u64x8(self, k: KMask8, a: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_mask_blend_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are copied from `src`.
+    #[doc(alias = "_mm512_mask_compress_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_u64x8_mask(self, src: u64x8, k: KMask8, a: u64x8) -> u64x8 {
+        unsafe { _mm512_mask_compress_epi64(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm512_maskz_compress_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_u64x8_maskz(self, k: KMask8, a: u64x8) -> u64x8 {
+        unsafe { _mm512_maskz_compress_epi64(k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_expand_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_u64x8_mask(self, src: u64x8, k: KMask8, a: u64x8) -> u64x8 {
+        unsafe { _mm512_mask_expand_epi64(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_expand_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_u64x8_maskz(self, k: KMask8, a: u64x8) -> u64x8 {
+        unsafe { _mm512_maskz_expand_epi64(k.0, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutexvar_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_u64x8(self, a: u64x8, idx: u64x8) -> u64x8 {
+        unsafe { _mm512_permutexvar_epi64(idx.avx512, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across both `a` and `b` using the indices in `idx`.
+    ///
+    /// Indices address the lanes of `a` followed by the lanes of `b`. Only the lowest
+    /// bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutex2var_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes2_u64x8(self, a: u64x8, idx: u64x8, b: u64x8) -> u64x8 {
+        unsafe { _mm512_permutex2var_epi64(a.avx512, idx.avx512, b.avx512) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm512_mask_blend_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i64x8(self, k: KMask8, a: i64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_mask_blend_epi64(k.0, a.avx512, b.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are copied from `src`.
+    #[doc(alias = "_mm512_mask_compress_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_i64x8_mask(self, src: i64x8, k: KMask8, a: i64x8) -> i64x8 {
+        unsafe { _mm512_mask_compress_epi64(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm512_maskz_compress_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_i64x8_maskz(self, k: KMask8, a: i64x8) -> i64x8 {
+        unsafe { _mm512_maskz_compress_epi64(k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are copied from `src`.
+    #[doc(alias = "_mm512_mask_expand_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_i64x8_mask(self, src: i64x8, k: KMask8, a: i64x8) -> i64x8 {
+        unsafe { _mm512_mask_expand_epi64(src.avx512, k.0, a.avx512) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm512_maskz_expand_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_i64x8_maskz(self, k: KMask8, a: i64x8) -> i64x8 {
+        unsafe { _mm512_maskz_expand_epi64(k.0, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutexvar_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes_i64x8(self, a: i64x8, idx: u64x8) -> i64x8 {
+        unsafe { _mm512_permutexvar_epi64(idx.avx512, a.avx512) }.into()
+    }
+
+    /// Shuffle lanes across both `a` and `b` using the indices in `idx`.
+    ///
+    /// Indices address the lanes of `a` followed by the lanes of `b`. Only the lowest
+    /// bits of each index needed to address a lane are used.
+    #[doc(alias = "_mm512_permutex2var_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_lanes2_i64x8(self, a: i64x8, idx: u64x8, b: i64x8) -> i64x8 {
+        unsafe { _mm512_permutex2var_epi64(a.avx512, idx.avx512, b.avx512) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::{f32x16, f64x8, i32x16, i64x8, u32x16, u64x8},
+        util::test::{assert_lanes, Rng},
+    };
+
+    use super::{
+        super::{
+            float::Cmp,
+            test::{
+                blends, compares, compress_expand, masked, predicates, ternary_logic,
+                token_or_skip, ROUNDS,
+            },
+            KMask16, KMask8,
+        },
+        Avx512F,
+    };
+
+    #[test]
+    fn masked_float() {
+        let token = token_or_skip!(Avx512F);
+
+        masked! {
+            token;
+            add_f32x16 / add_f32x16_mask / add_f32x16_maskz (a, b): f32x16, KMask16 => a + b;
+            sub_f32x16 / sub_f32x16_mask / sub_f32x16_maskz (a, b): f32x16, KMask16 => a - b;
+            mul_f32x16 / mul_f32x16_mask / mul_f32x16_maskz (a, b): f32x16, KMask16 => a * b;
+            div_f32x16 / div_f32x16_mask / div_f32x16_maskz (a, b): f32x16, KMask16 => a / b;
+            min_f32x16 / min_f32x16_mask / min_f32x16_maskz (a, b): f32x16, KMask16
+                => if a < b { a } else { b };
+            max_f32x16 / max_f32x16_mask / max_f32x16_maskz (a, b): f32x16, KMask16
+                => if a > b { a } else { b };
+            sqrt_f32x16 / sqrt_f32x16_mask / sqrt_f32x16_maskz (a): f32x16, KMask16 => a.sqrt();
+
+            add_f64x8 / add_f64x8_mask / add_f64x8_maskz (a, b): f64x8, KMask8 => a + b;
+            sub_f64x8 / sub_f64x8_mask / sub_f64x8_maskz (a, b): f64x8, KMask8 => a - b;
+            mul_f64x8 / mul_f64x8_mask / mul_f64x8_maskz (a, b): f64x8, KMask8 => a * b;
+            div_f64x8 / div_f64x8_mask / div_f64x8_maskz (a, b): f64x8, KMask8 => a / b;
+            min_f64x8 / min_f64x8_mask / min_f64x8_maskz (a, b): f64x8, KMask8
+                => if a < b { a } else { b };
+            max_f64x8 / max_f64x8_mask / max_f64x8_maskz (a, b): f64x8, KMask8
+                => if a > b { a } else { b };
+            sqrt_f64x8 / sqrt_f64x8_mask / sqrt_f64x8_maskz (a): f64x8, KMask8 => a.sqrt();
+        }
+    }
+
+    #[test]
+    fn masked_int() {
+        let token = token_or_skip!(Avx512F);
+
+        masked! {
+            token;
+            add_u32x16 / add_u32x16_mask / add_u32x16_maskz (a, b): u32x16, KMask16
+                => a.wrapping_add(b);
+            sub_u32x16 / sub_u32x16_mask / sub_u32x16_maskz (a, b): u32x16, KMask16
+                => a.wrapping_sub(b);
+            mul_u32x16 / mul_u32x16_mask / mul_u32x16_maskz (a, b): u32x16, KMask16
+                => a.wrapping_mul(b);
+            min_u32x16 / min_u32x16_mask / min_u32x16_maskz (a, b): u32x16, KMask16 => a.min(b);
+            max_u32x16 / max_u32x16_mask / max_u32x16_maskz (a, b): u32x16, KMask16 => a.max(b);
+
+            add_i32x16 / add_i32x16_mask / add_i32x16_maskz (a, b): i32x16, KMask16
+                => a.wrapping_add(b);
+            sub_i32x16 / sub_i32x16_mask / sub_i32x16_maskz (a, b): i32x16, KMask16
+                => a.wrapping_sub(b);
+            mul_i32x16 / mul_i32x16_mask / mul_i32x16_maskz (a, b): i32x16, KMask16
+                => a.wrapping_mul(b);
+            min_i32x16 / min_i32x16_mask / min_i32x16_maskz (a, b): i32x16, KMask16 => a.min(b);
+            max_i32x16 / max_i32x16_mask / max_i32x16_maskz (a, b): i32x16, KMask16 => a.max(b);
+
+            add_u64x8 / add_u64x8_mask / add_u64x8_maskz (a, b): u64x8, KMask8 => a.wrapping_add(b);
+            sub_u64x8 / sub_u64x8_mask / sub_u64x8_maskz (a, b): u64x8, KMask8 => a.wrapping_sub(b);
+            min_u64x8 / min_u64x8_mask / min_u64x8_maskz (a, b): u64x8, KMask8 => a.min(b);
+            max_u64x8 / max_u64x8_mask / max_u64x8_maskz (a, b): u64x8, KMask8 => a.max(b);
+
+            add_i64x8 / add_i64x8_mask / add_i64x8_maskz (a, b): i64x8, KMask8 => a.wrapping_add(b);
+            sub_i64x8 / sub_i64x8_mask / sub_i64x8_maskz (a, b): i64x8, KMask8 => a.wrapping_sub(b);
+            min_i64x8 / min_i64x8_mask / min_i64x8_maskz (a, b): i64x8, KMask8 => a.min(b);
+            max_i64x8 / max_i64x8_mask / max_i64x8_maskz (a, b): i64x8, KMask8 => a.max(b);
+        }
+    }
+
+    #[test]
+    fn compress_expand() {
+        let token = token_or_skip!(Avx512F);
+
+        compress_expand! {
+            token;
+            f32x16, KMask16
+                => compress_f32x16_maskz / compress_f32x16_mask,
+                    expand_f32x16_maskz / expand_f32x16_mask;
+            f64x8, KMask8
+                => compress_f64x8_maskz / compress_f64x8_mask,
+                    expand_f64x8_maskz / expand_f64x8_mask;
+            u32x16, KMask16
+                => compress_u32x16_maskz / compress_u32x16_mask,
+                    expand_u32x16_maskz / expand_u32x16_mask;
+            i32x16, KMask16
+                => compress_i32x16_maskz / compress_i32x16_mask,
+                    expand_i32x16_maskz / expand_i32x16_mask;
+            u64x8, KMask8
+                => compress_u64x8_maskz / compress_u64x8_mask,
+                    expand_u64x8_maskz / expand_u64x8_mask;
+            i64x8, KMask8
+                => compress_i64x8_maskz / compress_i64x8_mask,
+                    expand_i64x8_maskz / expand_i64x8_mask;
+        }
+    }
+
+    #[test]
+    fn ternary_logic() {
+        let token = token_or_skip!(Avx512F);
+
+        ternary_logic! {
+            token;
+            ternary_logic_u32x16: u32x16;
+            ternary_logic_i32x16: i32x16;
+            ternary_logic_u64x8: u64x8;
+            ternary_logic_i64x8: i64x8;
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let token = token_or_skip!(Avx512F);
+
+        compares! {
+            token;
+            u32x16 => eq_u32x16, ne_u32x16, lt_u32x16, le_u32x16, gt_u32x16, ge_u32x16;
+            i32x16 => eq_i32x16, ne_i32x16, lt_i32x16, le_i32x16, gt_i32x16, ge_i32x16;
+            u64x8 => eq_u64x8, ne_u64x8, lt_u64x8, le_u64x8, gt_u64x8, ge_u64x8;
+            i64x8 => eq_i64x8, ne_i64x8, lt_i64x8, le_i64x8, gt_i64x8, ge_i64x8;
+        }
+
+        predicates! {
+            token;
+            cmp_f32x16: f32x16 => eval_f32;
+            cmp_f64x8: f64x8 => eval_f64;
+        }
+    }
+
+    #[test]
+    fn compare_masked() {
+        let token = token_or_skip!(Avx512F);
+        let mut rng = Rng::new(1);
+
+        for _ in 0..ROUNDS {
+            let (a, b): ([f32; 16], [f32; 16]) = (rng.array(), rng.array());
+            let (c, d): ([f64; 8], [f64; 8]) = (rng.array(), rng.array());
+            let (k16, k8) = (KMask16::from_bits(rng.gen()), KMask8::from_bits(rng.gen()));
+
+            for cmp in (0..32).filter_map(Cmp::from_i32) {
+                let expected = (0..16)
+                    .filter(|&i| k16.test(i) && cmp.eval_f32(a[i as usize], b[i as usize]))
+                    .fold(0, |bits, i| bits | 1 << i);
+                let actual =
+                    token.cmp_f32x16_mask(k16, f32x16::from_array(a), f32x16::from_array(b), cmp);
+
+                assert_eq!(actual.to_bits(), expected, "{cmp:?} {k16:?} {a:?} {b:?}");
+
+                let expected = (0..8)
+                    .filter(|&i| k8.test(i) && cmp.eval_f64(c[i as usize], d[i as usize]))
+                    .fold(0, |bits, i| bits | 1 << i);
+                let actual =
+                    token.cmp_f64x8_mask(k8, f64x8::from_array(c), f64x8::from_array(d), cmp);
+
+                assert_eq!(actual.to_bits(), expected, "{cmp:?} {k8:?} {c:?} {d:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn blend() {
+        let token = token_or_skip!(Avx512F);
+
+        blends! {
+            token;
+            blend_f32x16: f32x16, KMask16;
+            blend_f64x8: f64x8, KMask8;
+            blend_u32x16: u32x16, KMask16;
+            blend_i32x16: i32x16, KMask16;
+            blend_u64x8: u64x8, KMask8;
+            blend_i64x8: i64x8, KMask8;
+        }
+    }
+}
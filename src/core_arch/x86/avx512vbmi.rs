@@ -0,0 +1,50 @@
+use crate::types::vector::u8x64;
+
+use super::{
+    raw::{_mm512_maskz_permutexvar_epi8, _mm512_permutexvar_epi8},
+    token::token,
+    KMask64,
+};
+
+token! {
+    /// A token proving that AVX-512F, AVX-512BW and AVX-512VBMI are available.
+    pub struct Avx512Vbmi("AVX-512VBMI") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Avx = "avx",
+        Avx2 = "avx2",
+        Fma = "fma",
+        F16c = "f16c",
+        Avx512f = "avx512f",
+        Avx512bw = "avx512bw",
+        Avx512vbmi = "avx512vbmi",
+    }
+}
+
+// Shuffling.
+impl Avx512Vbmi {
+    /// Shuffle bytes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest six bits of each index are used.
+    #[doc(alias = "_mm512_permutexvar_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_bytes_u8x64(self, a: u8x64, idx: u8x64) -> u8x64 {
+        unsafe { _mm512_permutexvar_epi8(idx.avx512, a.avx512) }.into()
+    }
+
+    /// Shuffle bytes across the whole vector using the indices in `idx`.
+    ///
+    /// Only the lowest six bits of each index are used. Lanes whose bit in `k` is not
+    /// set are zeroed.
+    #[doc(alias = "_mm512_maskz_permutexvar_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn permute_bytes_u8x64_maskz(self, k: KMask64, a: u8x64, idx: u8x64) -> u8x64 {
+        unsafe { _mm512_maskz_permutexvar_epi8(k.0, idx.avx512, a.avx512) }.into()
+    }
+}
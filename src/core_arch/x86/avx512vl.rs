@@ -0,0 +1,784 @@
+use crate::types::vector::{
+    f32x4, f32x8, f64x2, f64x4, i32x4, i32x8, i64x2, i64x4, u32x4, u32x8, u64x2, u64x4,
+};
+
+use super::{
+    float::{cmp_imm, Cmp},
+    raw::{
+        _mm256_cmp_pd_mask, _mm256_cmp_ps_mask, _mm256_cmpeq_epi32_mask, _mm256_cmpeq_epi64_mask,
+        _mm256_cmpeq_epu32_mask, _mm256_cmpeq_epu64_mask, _mm256_cmpge_epi32_mask,
+        _mm256_cmpge_epi64_mask, _mm256_cmpge_epu32_mask, _mm256_cmpge_epu64_mask,
+        _mm256_cmpgt_epi32_mask, _mm256_cmpgt_epi64_mask, _mm256_cmpgt_epu32_mask,
+        _mm256_cmpgt_epu64_mask, _mm256_cmple_epi32_mask, _mm256_cmple_epi64_mask,
+        _mm256_cmple_epu32_mask, _mm256_cmple_epu64_mask, _mm256_cmplt_epi32_mask,
+        _mm256_cmplt_epi64_mask, _mm256_cmplt_epu32_mask, _mm256_cmplt_epu64_mask,
+        _mm256_cmpneq_epi32_mask, _mm256_cmpneq_epi64_mask, _mm256_cmpneq_epu32_mask,
+        _mm256_cmpneq_epu64_mask, _mm256_mask_blend_epi32, _mm256_mask_blend_epi64,
+        _mm256_mask_blend_pd, _mm256_mask_blend_ps, _mm256_maskz_compress_epi32,
+        _mm256_maskz_compress_epi64, _mm256_maskz_compress_pd, _mm256_maskz_compress_ps,
+        _mm256_maskz_expand_epi32, _mm256_maskz_expand_epi64, _mm256_maskz_expand_pd,
+        _mm256_maskz_expand_ps, _mm256_ternarylogic_epi32, _mm256_ternarylogic_epi64,
+        _mm_cmp_pd_mask, _mm_cmp_ps_mask, _mm_cmpeq_epi32_mask, _mm_cmpeq_epi64_mask,
+        _mm_cmpeq_epu32_mask, _mm_cmpeq_epu64_mask, _mm_cmpge_epi32_mask, _mm_cmpge_epi64_mask,
+        _mm_cmpge_epu32_mask, _mm_cmpge_epu64_mask, _mm_cmpgt_epi32_mask, _mm_cmpgt_epi64_mask,
+        _mm_cmpgt_epu32_mask, _mm_cmpgt_epu64_mask, _mm_cmple_epi32_mask, _mm_cmple_epi64_mask,
+        _mm_cmple_epu32_mask, _mm_cmple_epu64_mask, _mm_cmplt_epi32_mask, _mm_cmplt_epi64_mask,
+        _mm_cmplt_epu32_mask, _mm_cmplt_epu64_mask, _mm_cmpneq_epi32_mask, _mm_cmpneq_epi64_mask,
+        _mm_cmpneq_epu32_mask, _mm_cmpneq_epu64_mask, _mm_ternarylogic_epi32,
+        _mm_ternarylogic_epi64,
+    },
+    token::token,
+    KMask8,
+};
+
+token! {
+    /// A token proving that AVX-512F and AVX-512VL are available.
+    pub struct Avx512VL("AVX-512VL") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Avx = "avx",
+        Avx2 = "avx2",
+        Fma = "fma",
+        F16c = "f16c",
+        Avx512f = "avx512f",
+        Avx512vl = "avx512vl",
+    }
+}
+
+// Floating point comparisons.
+impl Avx512VL {
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// The bits for lanes past the end of the vector are always cleared.
+    #[doc(alias = "_mm_cmp_ps_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f32x4(self, a: f32x4, b: f32x4, cmp: Cmp) -> KMask8 {
+        unsafe { cmp_imm!(cmp, IMM => _mm_cmp_ps_mask::<IMM>(a.sse, b.sse)) }.into()
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// The bits for lanes past the end of the vector are always cleared.
+    #[doc(alias = "_mm_cmp_pd_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f64x2(self, a: f64x2, b: f64x2, cmp: Cmp) -> KMask8 {
+        unsafe { cmp_imm!(cmp, IMM => _mm_cmp_pd_mask::<IMM>(a.sse, b.sse)) }.into()
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// The bits for lanes past the end of the vector are always cleared.
+    #[doc(alias = "_mm256_cmp_ps_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f32x8(self, a: f32x8, b: f32x8, cmp: Cmp) -> KMask8 {
+        unsafe { cmp_imm!(cmp, IMM => _mm256_cmp_ps_mask::<IMM>(a.avx, b.avx)) }.into()
+    }
+
+    /// Compare every lane of `a` and `b` using the predicate `cmp`.
+    ///
+    /// The bits for lanes past the end of the vector are always cleared.
+    #[doc(alias = "_mm256_cmp_pd_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cmp_f64x4(self, a: f64x4, b: f64x4, cmp: Cmp) -> KMask8 {
+        unsafe { cmp_imm!(cmp, IMM => _mm256_cmp_pd_mask::<IMM>(a.avx, b.avx)) }.into()
+    }
+}
+
+// Integer comparisons.
+impl Avx512VL {
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm_cmpeq_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u32x4(self, a: u32x4, b: u32x4) -> KMask8 {
+        unsafe { _mm_cmpeq_epu32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm_cmpneq_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_u32x4(self, a: u32x4, b: u32x4) -> KMask8 {
+        unsafe { _mm_cmpneq_epu32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm_cmplt_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_u32x4(self, a: u32x4, b: u32x4) -> KMask8 {
+        unsafe { _mm_cmplt_epu32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm_cmple_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_u32x4(self, a: u32x4, b: u32x4) -> KMask8 {
+        unsafe { _mm_cmple_epu32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm_cmpgt_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_u32x4(self, a: u32x4, b: u32x4) -> KMask8 {
+        unsafe { _mm_cmpgt_epu32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm_cmpge_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_u32x4(self, a: u32x4, b: u32x4) -> KMask8 {
+        unsafe { _mm_cmpge_epu32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm_cmpeq_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i32x4(self, a: i32x4, b: i32x4) -> KMask8 {
+        unsafe { _mm_cmpeq_epi32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm_cmpneq_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_i32x4(self, a: i32x4, b: i32x4) -> KMask8 {
+        unsafe { _mm_cmpneq_epi32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm_cmplt_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i32x4(self, a: i32x4, b: i32x4) -> KMask8 {
+        unsafe { _mm_cmplt_epi32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm_cmple_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_i32x4(self, a: i32x4, b: i32x4) -> KMask8 {
+        unsafe { _mm_cmple_epi32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm_cmpgt_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i32x4(self, a: i32x4, b: i32x4) -> KMask8 {
+        unsafe { _mm_cmpgt_epi32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm_cmpge_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_i32x4(self, a: i32x4, b: i32x4) -> KMask8 {
+        unsafe { _mm_cmpge_epi32_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm_cmpeq_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u64x2(self, a: u64x2, b: u64x2) -> KMask8 {
+        unsafe { _mm_cmpeq_epu64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm_cmpneq_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_u64x2(self, a: u64x2, b: u64x2) -> KMask8 {
+        unsafe { _mm_cmpneq_epu64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm_cmplt_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_u64x2(self, a: u64x2, b: u64x2) -> KMask8 {
+        unsafe { _mm_cmplt_epu64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm_cmple_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_u64x2(self, a: u64x2, b: u64x2) -> KMask8 {
+        unsafe { _mm_cmple_epu64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm_cmpgt_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_u64x2(self, a: u64x2, b: u64x2) -> KMask8 {
+        unsafe { _mm_cmpgt_epu64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm_cmpge_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_u64x2(self, a: u64x2, b: u64x2) -> KMask8 {
+        unsafe { _mm_cmpge_epu64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm_cmpeq_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i64x2(self, a: i64x2, b: i64x2) -> KMask8 {
+        unsafe { _mm_cmpeq_epi64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm_cmpneq_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_i64x2(self, a: i64x2, b: i64x2) -> KMask8 {
+        unsafe { _mm_cmpneq_epi64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm_cmplt_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i64x2(self, a: i64x2, b: i64x2) -> KMask8 {
+        unsafe { _mm_cmplt_epi64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm_cmple_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_i64x2(self, a: i64x2, b: i64x2) -> KMask8 {
+        unsafe { _mm_cmple_epi64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm_cmpgt_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i64x2(self, a: i64x2, b: i64x2) -> KMask8 {
+        unsafe { _mm_cmpgt_epi64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm_cmpge_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_i64x2(self, a: i64x2, b: i64x2) -> KMask8 {
+        unsafe { _mm_cmpge_epi64_mask(a.sse, b.sse) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm256_cmpeq_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u32x8(self, a: u32x8, b: u32x8) -> KMask8 {
+        unsafe { _mm256_cmpeq_epu32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm256_cmpneq_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_u32x8(self, a: u32x8, b: u32x8) -> KMask8 {
+        unsafe { _mm256_cmpneq_epu32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm256_cmplt_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_u32x8(self, a: u32x8, b: u32x8) -> KMask8 {
+        unsafe { _mm256_cmplt_epu32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm256_cmple_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_u32x8(self, a: u32x8, b: u32x8) -> KMask8 {
+        unsafe { _mm256_cmple_epu32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm256_cmpgt_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_u32x8(self, a: u32x8, b: u32x8) -> KMask8 {
+        unsafe { _mm256_cmpgt_epu32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm256_cmpge_epu32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_u32x8(self, a: u32x8, b: u32x8) -> KMask8 {
+        unsafe { _mm256_cmpge_epu32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm256_cmpeq_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i32x8(self, a: i32x8, b: i32x8) -> KMask8 {
+        unsafe { _mm256_cmpeq_epi32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm256_cmpneq_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_i32x8(self, a: i32x8, b: i32x8) -> KMask8 {
+        unsafe { _mm256_cmpneq_epi32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm256_cmplt_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i32x8(self, a: i32x8, b: i32x8) -> KMask8 {
+        unsafe { _mm256_cmplt_epi32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm256_cmple_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_i32x8(self, a: i32x8, b: i32x8) -> KMask8 {
+        unsafe { _mm256_cmple_epi32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm256_cmpgt_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i32x8(self, a: i32x8, b: i32x8) -> KMask8 {
+        unsafe { _mm256_cmpgt_epi32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm256_cmpge_epi32_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_i32x8(self, a: i32x8, b: i32x8) -> KMask8 {
+        unsafe { _mm256_cmpge_epi32_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm256_cmpeq_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u64x4(self, a: u64x4, b: u64x4) -> KMask8 {
+        unsafe { _mm256_cmpeq_epu64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm256_cmpneq_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_u64x4(self, a: u64x4, b: u64x4) -> KMask8 {
+        unsafe { _mm256_cmpneq_epu64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm256_cmplt_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_u64x4(self, a: u64x4, b: u64x4) -> KMask8 {
+        unsafe { _mm256_cmplt_epu64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm256_cmple_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_u64x4(self, a: u64x4, b: u64x4) -> KMask8 {
+        unsafe { _mm256_cmple_epu64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm256_cmpgt_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_u64x4(self, a: u64x4, b: u64x4) -> KMask8 {
+        unsafe { _mm256_cmpgt_epu64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm256_cmpge_epu64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_u64x4(self, a: u64x4, b: u64x4) -> KMask8 {
+        unsafe { _mm256_cmpge_epu64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is equal to `b`.
+    #[doc(alias = "_mm256_cmpeq_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i64x4(self, a: i64x4, b: i64x4) -> KMask8 {
+        unsafe { _mm256_cmpeq_epi64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is not equal to `b`.
+    #[doc(alias = "_mm256_cmpneq_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ne_i64x4(self, a: i64x4, b: i64x4) -> KMask8 {
+        unsafe { _mm256_cmpneq_epi64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than `b`.
+    #[doc(alias = "_mm256_cmplt_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i64x4(self, a: i64x4, b: i64x4) -> KMask8 {
+        unsafe { _mm256_cmplt_epi64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is less than or equal to `b`.
+    #[doc(alias = "_mm256_cmple_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_i64x4(self, a: i64x4, b: i64x4) -> KMask8 {
+        unsafe { _mm256_cmple_epi64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than `b`.
+    #[doc(alias = "_mm256_cmpgt_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i64x4(self, a: i64x4, b: i64x4) -> KMask8 {
+        unsafe { _mm256_cmpgt_epi64_mask(a.avx, b.avx) }.into()
+    }
+
+    /// Set the bit for every lane where `a` is greater than or equal to `b`.
+    #[doc(alias = "_mm256_cmpge_epi64_mask")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_i64x4(self, a: i64x4, b: i64x4) -> KMask8 {
+        unsafe { _mm256_cmpge_epi64_mask(a.avx, b.avx) }.into()
+    }
+}
+
+// Blends, compression, expansion and bitwise logic.
+impl Avx512VL {
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm256_mask_blend_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_f32x8(self, k: KMask8, a: f32x8, b: f32x8) -> f32x8 {
+        unsafe { _mm256_mask_blend_ps(k.0, a.avx, b.avx) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm256_maskz_compress_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_f32x8_maskz(self, k: KMask8, a: f32x8) -> f32x8 {
+        unsafe { _mm256_maskz_compress_ps(k.0, a.avx) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm256_maskz_expand_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_f32x8_maskz(self, k: KMask8, a: f32x8) -> f32x8 {
+        unsafe { _mm256_maskz_expand_ps(k.0, a.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm256_mask_blend_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_f64x4(self, k: KMask8, a: f64x4, b: f64x4) -> f64x4 {
+        unsafe { _mm256_mask_blend_pd(k.0, a.avx, b.avx) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm256_maskz_compress_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_f64x4_maskz(self, k: KMask8, a: f64x4) -> f64x4 {
+        unsafe { _mm256_maskz_compress_pd(k.0, a.avx) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm256_maskz_expand_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_f64x4_maskz(self, k: KMask8, a: f64x4) -> f64x4 {
+        unsafe { _mm256_maskz_expand_pd(k.0, a.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm256_mask_blend_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u32x8(self, k: KMask8, a: u32x8, b: u32x8) -> u32x8 {
+        unsafe { _mm256_mask_blend_epi32(k.0, a.avx, b.avx) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm256_maskz_compress_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_u32x8_maskz(self, k: KMask8, a: u32x8) -> u32x8 {
+        unsafe { _mm256_maskz_compress_epi32(k.0, a.avx) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm256_maskz_expand_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_u32x8_maskz(self, k: KMask8, a: u32x8) -> u32x8 {
+        unsafe { _mm256_maskz_expand_epi32(k.0, a.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm256_mask_blend_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i32x8(self, k: KMask8, a: i32x8, b: i32x8) -> i32x8 {
+        unsafe { _mm256_mask_blend_epi32(k.0, a.avx, b.avx) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm256_maskz_compress_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_i32x8_maskz(self, k: KMask8, a: i32x8) -> i32x8 {
+        unsafe { _mm256_maskz_compress_epi32(k.0, a.avx) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm256_maskz_expand_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_i32x8_maskz(self, k: KMask8, a: i32x8) -> i32x8 {
+        unsafe { _mm256_maskz_expand_epi32(k.0, a.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm256_mask_blend_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u64x4(self, k: KMask8, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { _mm256_mask_blend_epi64(k.0, a.avx, b.avx) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm256_maskz_compress_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_u64x4_maskz(self, k: KMask8, a: u64x4) -> u64x4 {
+        unsafe { _mm256_maskz_compress_epi64(k.0, a.avx) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm256_maskz_expand_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_u64x4_maskz(self, k: KMask8, a: u64x4) -> u64x4 {
+        unsafe { _mm256_maskz_expand_epi64(k.0, a.avx) }.into()
+    }
+
+    /// Select lanes from `b` where the bit in `k` is set, and from `a` otherwise.
+    #[doc(alias = "_mm256_mask_blend_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i64x4(self, k: KMask8, a: i64x4, b: i64x4) -> i64x4 {
+        unsafe { _mm256_mask_blend_epi64(k.0, a.avx, b.avx) }.into()
+    }
+
+    /// Pack the lanes of `a` whose bit in `k` is set into the lowest lanes.
+    ///
+    /// The remaining upper lanes are zeroed.
+    #[doc(alias = "_mm256_maskz_compress_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn compress_i64x4_maskz(self, k: KMask8, a: i64x4) -> i64x4 {
+        unsafe { _mm256_maskz_compress_epi64(k.0, a.avx) }.into()
+    }
+
+    /// Spread the lowest lanes of `a` out to the lanes whose bit in `k` is set.
+    ///
+    /// Lanes whose bit in `k` is not set are zeroed.
+    #[doc(alias = "_mm256_maskz_expand_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn expand_i64x4_maskz(self, k: KMask8, a: i64x4) -> i64x4 {
+        unsafe { _mm256_maskz_expand_epi64(k.0, a.avx) }.into()
+    }
+
+    /// Compute an arbitrary bitwise function of `a`, `b` and `c`.
+    ///
+    /// For every bit, the bits of `a`, `b` and `c` form a 3-bit index (with `a` being
+    /// the most significant) into the truth table `IMM8`.
+    #[doc(alias = "_mm_ternarylogic_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ternary_logic_u32x4<const IMM8: i32>(self, a: u32x4, b: u32x4, c: u32x4) -> u32x4 {
+        unsafe { _mm_ternarylogic_epi32::<IMM8>(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute an arbitrary bitwise function of `a`, `b` and `c`.
+    ///
+    /// For every bit, the bits of `a`, `b` and `c` form a 3-bit index (with `a` being
+    /// the most significant) into the truth table `IMM8`.
+    #[doc(alias = "_mm_ternarylogic_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ternary_logic_u64x2<const IMM8: i32>(self, a: u64x2, b: u64x2, c: u64x2) -> u64x2 {
+        unsafe { _mm_ternarylogic_epi64::<IMM8>(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute an arbitrary bitwise function of `a`, `b` and `c`.
+    ///
+    /// For every bit, the bits of `a`, `b` and `c` form a 3-bit index (with `a` being
+    /// the most significant) into the truth table `IMM8`.
+    #[doc(alias = "_mm256_ternarylogic_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ternary_logic_u32x8<const IMM8: i32>(self, a: u32x8, b: u32x8, c: u32x8) -> u32x8 {
+        unsafe { _mm256_ternarylogic_epi32::<IMM8>(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute an arbitrary bitwise function of `a`, `b` and `c`.
+    ///
+    /// For every bit, the bits of `a`, `b` and `c` form a 3-bit index (with `a` being
+    /// the most significant) into the truth table `IMM8`.
+    #[doc(alias = "_mm256_ternarylogic_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ternary_logic_u64x4<const IMM8: i32>(self, a: u64x4, b: u64x4, c: u64x4) -> u64x4 {
+        unsafe { _mm256_ternarylogic_epi64::<IMM8>(a.avx, b.avx, c.avx) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::{
+            f32x4, f32x8, f64x2, f64x4, i32x4, i32x8, i64x2, i64x4, u32x4, u32x8, u64x2, u64x4,
+        },
+        util::test::{assert_lanes, Rng},
+    };
+
+    use super::{
+        super::{
+            float::Cmp,
+            test::{
+                blends, compares, compress_expand, predicates, ternary_logic, token_or_skip, ROUNDS,
+            },
+            KMask8,
+        },
+        Avx512VL,
+    };
+
+    #[test]
+    fn compress_expand() {
+        let token = token_or_skip!(Avx512VL);
+
+        compress_expand! {
+            token;
+            f32x8, KMask8 => compress_f32x8_maskz, expand_f32x8_maskz;
+            f64x4, KMask8 => compress_f64x4_maskz, expand_f64x4_maskz;
+            u32x8, KMask8 => compress_u32x8_maskz, expand_u32x8_maskz;
+            i32x8, KMask8 => compress_i32x8_maskz, expand_i32x8_maskz;
+            u64x4, KMask8 => compress_u64x4_maskz, expand_u64x4_maskz;
+            i64x4, KMask8 => compress_i64x4_maskz, expand_i64x4_maskz;
+        }
+    }
+
+    #[test]
+    fn ternary_logic() {
+        let token = token_or_skip!(Avx512VL);
+
+        ternary_logic! {
+            token;
+            ternary_logic_u32x4: u32x4;
+            ternary_logic_u64x2: u64x2;
+            ternary_logic_u32x8: u32x8;
+            ternary_logic_u64x4: u64x4;
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let token = token_or_skip!(Avx512VL);
+
+        compares! {
+            token;
+            u32x4 => eq_u32x4, ne_u32x4, lt_u32x4, le_u32x4, gt_u32x4, ge_u32x4;
+            i32x4 => eq_i32x4, ne_i32x4, lt_i32x4, le_i32x4, gt_i32x4, ge_i32x4;
+            u64x2 => eq_u64x2, ne_u64x2, lt_u64x2, le_u64x2, gt_u64x2, ge_u64x2;
+            i64x2 => eq_i64x2, ne_i64x2, lt_i64x2, le_i64x2, gt_i64x2, ge_i64x2;
+            u32x8 => eq_u32x8, ne_u32x8, lt_u32x8, le_u32x8, gt_u32x8, ge_u32x8;
+            i32x8 => eq_i32x8, ne_i32x8, lt_i32x8, le_i32x8, gt_i32x8, ge_i32x8;
+            u64x4 => eq_u64x4, ne_u64x4, lt_u64x4, le_u64x4, gt_u64x4, ge_u64x4;
+            i64x4 => eq_i64x4, ne_i64x4, lt_i64x4, le_i64x4, gt_i64x4, ge_i64x4;
+        }
+
+        predicates! {
+            token;
+            cmp_f32x4: f32x4 => eval_f32;
+            cmp_f64x2: f64x2 => eval_f64;
+            cmp_f32x8: f32x8 => eval_f32;
+            cmp_f64x4: f64x4 => eval_f64;
+        }
+    }
+
+    #[test]
+    fn blend() {
+        let token = token_or_skip!(Avx512VL);
+
+        blends! {
+            token;
+            blend_f32x8: f32x8, KMask8;
+            blend_f64x4: f64x4, KMask8;
+            blend_u32x8: u32x8, KMask8;
+            blend_i32x8: i32x8, KMask8;
+            blend_u64x4: u64x4, KMask8;
+            blend_i64x4: i64x4, KMask8;
+        }
+    }
+}
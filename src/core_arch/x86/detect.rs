@@ -0,0 +1,464 @@
+//! Runtime CPU feature detection.
+//!
+//! This is a small `no_std` replacement for `is_x86_feature_detected!`. The
+//! results of querying `cpuid` and `xgetbv` are cached in an atomic, so that
+//! only the first query actually executes them.
+//!
+//! A CPU reporting a feature through `cpuid` is not enough to use it, as the
+//! operating system must also save and restore the registers it uses. That is
+//! what XCR0 tells us, which we read through `xgetbv`.
+//!
+//! Features can also be disabled, to exercise fallback paths on CPUs that would
+//! otherwise never take them. With the `std` feature, the initial set of disabled
+//! features is read from the `FEAT_DISABLE` environment variable.
+
+use core::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use super::{
+    raw::{__cpuid, __cpuid_count, _xgetbv, CpuidResult},
+    Feature, FeatureSet,
+};
+
+/// Set once the cache has been filled in.
+const INITIALIZED: u64 = 1 << 63;
+/// Set when the operating system saves the XMM and YMM registers.
+const OS_AVX: u64 = 1 << 62;
+/// Set when the operating system saves the opmask and ZMM registers.
+const OS_AVX512: u64 = 1 << 61;
+
+const _: () = assert!(
+    Feature::ALL.len() < 61,
+    "the feature cache cannot hold this many features"
+);
+
+/// The XCR0 bits for the SSE and AVX state.
+const XCR0_AVX: u64 = 0b110;
+/// The XCR0 bits for the opmask, upper ZMM and high ZMM state.
+const XCR0_AVX512: u64 = 0b1110_0000;
+
+/// Features that require the operating system to save the YMM registers.
+const AVX_STATE: FeatureSet =
+    FeatureSet::from_features(&[Feature::Avx, Feature::Avx2, Feature::Fma, Feature::F16c])
+        .union(AVX512_STATE);
+
+/// Features that require the operating system to save the opmask and ZMM registers.
+const AVX512_STATE: FeatureSet = FeatureSet::from_features(&[
+    Feature::Avx512f,
+    Feature::Avx512cd,
+    Feature::Avx512bw,
+    Feature::Avx512dq,
+    Feature::Avx512vl,
+    Feature::Avx512vbmi,
+]);
+
+static CACHE: AtomicU64 = AtomicU64::new(0);
+/// The disabled features, with `INITIALIZED` set once the environment was read.
+static DISABLED: AtomicU64 = AtomicU64::new(0);
+
+#[inline(always)]
+const fn bit(value: u32, index: u32) -> bool {
+    (value >> index) & 1 != 0
+}
+
+/// Read the extended control register XCR0.
+///
+/// # Safety
+///
+/// The caller must ensure that the OS has enabled `xgetbv`, which is reported
+/// by the OSXSAVE bit of `cpuid`.
+#[target_feature(enable = "xsave")]
+unsafe fn xcr0() -> u64 {
+    // SAFETY: The caller ensures that `xgetbv` is available.
+    unsafe { _xgetbv(0) }
+}
+
+/// Query `cpuid` and `xgetbv` for every known [`Feature`].
+///
+/// This returns the bits of every feature the CPU reports, alongside the
+/// `OS_AVX` and `OS_AVX512` flags.
+// `__cpuid` is only a safe function on newer versions of Rust.
+#[allow(unused_unsafe)]
+#[cold]
+fn detect() -> u64 {
+    #[cfg(target_arch = "x86")]
+    if !super::raw::has_cpuid() {
+        return 0;
+    }
+
+    let mut os = 0_u64;
+    let mut features = FeatureSet::EMPTY;
+    let mut set = |feature: Feature, enabled: bool| {
+        if enabled {
+            features = features.with(feature);
+        }
+    };
+
+    // SAFETY: We know that `cpuid` is available.
+    let CpuidResult { eax: max_leaf, .. } = unsafe { __cpuid(0) };
+
+    if max_leaf >= 1 {
+        // SAFETY: We know that leaf 1 is supported.
+        let CpuidResult { ecx, edx, .. } = unsafe { __cpuid(1) };
+
+        set(Feature::Sse, bit(edx, 25));
+        set(Feature::Sse2, bit(edx, 26));
+        set(Feature::Sse3, bit(ecx, 0));
+        set(Feature::Ssse3, bit(ecx, 9));
+        set(Feature::Fma, bit(ecx, 12));
+        set(Feature::Cmpxchg16b, bit(ecx, 13));
+        set(Feature::Sse41, bit(ecx, 19));
+        set(Feature::Sse42, bit(ecx, 20));
+        set(Feature::Movbe, bit(ecx, 22));
+        set(Feature::Popcnt, bit(ecx, 23));
+        set(Feature::Xsave, bit(ecx, 26));
+        set(Feature::Avx, bit(ecx, 28));
+        set(Feature::F16c, bit(ecx, 29));
+
+        // OSXSAVE
+        if bit(ecx, 27) {
+            // SAFETY: The OS has enabled `xgetbv`.
+            let xcr0 = unsafe { xcr0() };
+
+            if xcr0 & XCR0_AVX == XCR0_AVX {
+                os |= OS_AVX;
+            }
+
+            if xcr0 & XCR0_AVX512 == XCR0_AVX512 {
+                os |= OS_AVX512;
+            }
+        }
+    }
+
+    if max_leaf >= 7 {
+        // SAFETY: We know that leaf 7 is supported.
+        let CpuidResult { ebx, ecx, .. } = unsafe { __cpuid_count(7, 0) };
+
+        set(Feature::Bmi1, bit(ebx, 3));
+        set(Feature::Avx2, bit(ebx, 5));
+        set(Feature::Bmi2, bit(ebx, 8));
+        set(Feature::Avx512f, bit(ebx, 16));
+        set(Feature::Avx512dq, bit(ebx, 17));
+        set(Feature::Avx512cd, bit(ebx, 28));
+        set(Feature::Avx512bw, bit(ebx, 30));
+        set(Feature::Avx512vl, bit(ebx, 31));
+        set(Feature::Avx512vbmi, bit(ecx, 1));
+    }
+
+    // SAFETY: We know that `cpuid` is available.
+    let CpuidResult {
+        eax: max_extended_leaf,
+        ..
+    } = unsafe { __cpuid(0x8000_0000) };
+
+    if max_extended_leaf >= 0x8000_0001 {
+        // SAFETY: We know that leaf 0x80000001 is supported.
+        let CpuidResult { ecx, .. } = unsafe { __cpuid(0x8000_0001) };
+
+        set(Feature::Lahfsahf, bit(ecx, 0));
+        set(Feature::Lzcnt, bit(ecx, 5));
+    }
+
+    features.bits | os
+}
+
+/// Get the cached result of [`detect`].
+#[inline]
+#[must_use]
+fn cache() -> u64 {
+    let cached = CACHE.load(Ordering::Relaxed);
+
+    if cached & INITIALIZED != 0 {
+        return cached;
+    }
+
+    // Detection always yields the same result, so racing here is fine.
+    let detected = detect() | INITIALIZED;
+    CACHE.store(detected, Ordering::Relaxed);
+
+    detected
+}
+
+/// Get the set of every [`Feature`] the CPU reports, regardless of whether the OS
+/// supports it.
+#[inline]
+#[must_use]
+pub(crate) fn cpu_features() -> FeatureSet {
+    FeatureSet::from_bits(cache())
+}
+
+/// Get the set of every [`Feature`] the OS does not save the registers for.
+#[inline]
+#[must_use]
+pub(crate) fn os_unsupported() -> FeatureSet {
+    let cached = cache();
+    let mut unsupported = FeatureSet::EMPTY;
+
+    if cached & OS_AVX == 0 {
+        unsupported |= AVX_STATE;
+    }
+
+    if cached & OS_AVX512 == 0 {
+        unsupported |= AVX512_STATE;
+    }
+
+    unsupported
+}
+
+/// Read the features disabled by the `FEAT_DISABLE` environment variable.
+///
+/// Entries that fail to parse are skipped, so that a typo doesn't break detection.
+#[cfg(feature = "std")]
+#[cold]
+fn env_disabled() -> FeatureSet {
+    let Some(value) = std::env::var_os("FEAT_DISABLE") else {
+        return FeatureSet::EMPTY;
+    };
+
+    let mut disabled = FeatureSet::EMPTY;
+
+    for entry in value.to_string_lossy().split(',') {
+        if let Ok(set) = disabled.apply(entry) {
+            disabled = set;
+        }
+    }
+
+    disabled
+}
+
+/// Without `std`, there is no environment to read.
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+fn env_disabled() -> FeatureSet {
+    FeatureSet::EMPTY
+}
+
+/// Get the set of every disabled [`Feature`].
+///
+/// With the `std` feature, this is initially read from the `FEAT_DISABLE`
+/// environment variable, a comma separated list of features such as `avx2,fma`.
+/// Unknown features in it are silently ignored, without any diagnostic, while the
+/// rest of the list still applies. Without `std`, it is initially empty.
+#[inline]
+#[must_use]
+pub fn disabled_features() -> FeatureSet {
+    let disabled = DISABLED.load(Ordering::Relaxed);
+
+    if disabled & INITIALIZED != 0 {
+        return FeatureSet::from_bits(disabled);
+    }
+
+    // Don't clobber a call to `override_features` that raced with us.
+    let from_env = env_disabled();
+    match DISABLED.compare_exchange(
+        0,
+        from_env.bits | INITIALIZED,
+        Ordering::Relaxed,
+        Ordering::Relaxed,
+    ) {
+        Ok(_) => from_env,
+        Err(disabled) => FeatureSet::from_bits(disabled),
+    }
+}
+
+/// Disable a set of features, so that detection reports them as absent.
+///
+/// This replaces every previously disabled feature, including those read from
+/// `FEAT_DISABLE`, and an empty set enables every feature again.
+///
+/// This is meant for testing fallback paths. Tokens that were already created
+/// remain valid, and functions from [`dispatch!`](crate::dispatch) that were
+/// already called keep using the copy they selected, so this should be called
+/// before either happens.
+#[inline]
+pub fn override_features(disabled: FeatureSet) {
+    DISABLED.store(disabled.bits | INITIALIZED, Ordering::Relaxed);
+}
+
+/// Get the set of every [`Feature`] that is usable on the current CPU and OS, and
+/// that is not disabled.
+#[inline]
+#[must_use]
+pub(crate) fn features() -> FeatureSet {
+    cpu_features() - os_unsupported() - disabled_features()
+}
+
+/// Check whether every feature in `required` is usable.
+#[inline]
+pub(crate) fn check(required: FeatureSet) -> Result<(), UnsupportedError> {
+    let cpu = required - cpu_features();
+    let disabled = (required - cpu) & disabled_features();
+    let os = (required - cpu - disabled) & os_unsupported();
+
+    if cpu.is_empty() && os.is_empty() && disabled.is_empty() {
+        Ok(())
+    } else {
+        Err(UnsupportedError { cpu, os, disabled })
+    }
+}
+
+/// The error returned when the features a token requires are not usable.
+///
+/// This tells apart features that the CPU lacks, features that the CPU supports
+/// but the operating system has not enabled the register state for, and features
+/// that were [disabled](disabled_features).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnsupportedError {
+    cpu: FeatureSet,
+    os: FeatureSet,
+    disabled: FeatureSet,
+}
+
+impl UnsupportedError {
+    /// Get the required features that the CPU does not support.
+    #[inline]
+    #[must_use]
+    pub const fn missing_cpu_features(&self) -> FeatureSet {
+        self.cpu
+    }
+
+    /// Get the required features that the CPU supports, but that the operating
+    /// system does not save the registers for.
+    #[inline]
+    #[must_use]
+    pub const fn missing_os_features(&self) -> FeatureSet {
+        self.os
+    }
+
+    /// Get the required features that are supported, but were
+    /// [disabled](disabled_features).
+    #[inline]
+    #[must_use]
+    pub const fn disabled_features(&self) -> FeatureSet {
+        self.disabled
+    }
+
+    /// Get every required feature that is not usable.
+    #[inline]
+    #[must_use]
+    pub const fn missing_features(&self) -> FeatureSet {
+        self.cpu.union(self.os).union(self.disabled)
+    }
+
+    /// Check whether the CPU is missing support for any of the required features.
+    #[inline]
+    #[must_use]
+    pub const fn is_cpu_unsupported(&self) -> bool {
+        !self.cpu.is_empty()
+    }
+
+    /// Check whether the operating system is missing support for any of the
+    /// required features.
+    #[inline]
+    #[must_use]
+    pub const fn is_os_unsupported(&self) -> bool {
+        !self.os.is_empty()
+    }
+
+    /// Check whether any of the required features were [disabled](disabled_features).
+    #[inline]
+    #[must_use]
+    pub const fn is_disabled(&self) -> bool {
+        !self.disabled.is_empty()
+    }
+}
+
+impl fmt::Display for UnsupportedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reasons: [(FeatureSet, &str); 3] = [
+            (self.cpu, "the CPU does not support"),
+            (
+                self.os,
+                "the operating system does not save the registers used by",
+            ),
+            (self.disabled, "detection was disabled for"),
+        ];
+
+        let mut first = true;
+        for (features, reason) in reasons {
+            if features.is_empty() {
+                continue;
+            }
+
+            if !first {
+                f.write_str(", and ")?;
+            }
+
+            write!(f, "{reason} `{features}`")?;
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::error::Error for UnsupportedError {}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process::Command};
+
+    use super::{
+        super::{Feature, FeatureSet, Sse2},
+        cpu_features, override_features,
+    };
+
+    /// Run a test again in a child process with `FEAT_DISABLE` set, as the disabled
+    /// features are global.
+    ///
+    /// Returns whether this is the child process, which should run the test.
+    fn in_child(test: &str, disable: &str) -> bool {
+        if env::var_os("FEAT_TEST_CHILD").is_some() {
+            return true;
+        }
+
+        let (_, module) = module_path!().split_once("::").unwrap();
+        let output = Command::new(env::current_exe().unwrap())
+            .args([&format!("{module}::{test}"), "--exact"])
+            .env("FEAT_TEST_CHILD", "1")
+            .env("FEAT_DISABLE", disable)
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success(),
+            "`{test}` failed in a child process:\n{}",
+            String::from_utf8_lossy(&output.stdout),
+        );
+        false
+    }
+
+    #[test]
+    fn override_features_disables() {
+        if !in_child("override_features_disables", "") || !cpu_features().contains(Feature::Sse2) {
+            return;
+        }
+
+        assert!(Sse2::try_new().is_some());
+
+        override_features(Feature::Sse2.into());
+
+        assert!(Sse2::try_new().is_none());
+        assert!(Sse2::detect().is_err_and(|error| error.is_disabled()));
+
+        override_features(FeatureSet::EMPTY);
+
+        assert!(Sse2::try_new().is_some());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn env_disables() {
+        use super::{super::Sse, disabled_features};
+
+        if !in_child("env_disables", "sse2, bogus,") || !cpu_features().contains(Feature::Sse2) {
+            return;
+        }
+
+        assert_eq!(disabled_features(), Feature::Sse2.into());
+        assert!(Sse2::try_new().is_none());
+        assert!(Sse::try_new().is_some());
+    }
+}
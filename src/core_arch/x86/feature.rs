@@ -0,0 +1,587 @@
+//! CPU features and sets of them.
+
+use core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign},
+};
+
+use crate::util::string::{is_eq, split, strip_prefix};
+
+macro_rules! define {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[doc = $doc:tt])*
+                $feature:ident = $target_feature:literal
+            ),*
+            $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(u8)]
+        $vis enum $name {
+            $(
+                $(#[doc = $doc])*
+                #[doc = ""]
+                #[doc = ::core::concat!(
+                    "Maps to the `", $target_feature, "` target feature."
+                )]
+                $feature,
+            )*
+        }
+
+        impl $name {
+            /// All features, in the order of their bit indices.
+            pub const ALL: &'static [$name] = &[$($name::$feature),*];
+
+            /// Get the name of the target feature this feature maps to.
+            #[inline]
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(
+                        $name::$feature => $target_feature,
+                    )*
+                }
+            }
+
+            /// Get the bit used to represent this feature.
+            #[inline]
+            #[must_use]
+            pub(crate) const fn bit(self) -> u64 {
+                1 << (self as u8)
+            }
+
+            /// Look up a feature by the name of its target feature.
+            #[inline]
+            #[must_use]
+            pub const fn from_name(name: &str) -> Option<$name> {
+                $(
+                    if is_eq(name, $target_feature) {
+                        return Some($name::$feature);
+                    }
+                )*
+
+                None
+            }
+
+            /// Check whether this feature is enabled at compile time.
+            #[inline]
+            #[must_use]
+            pub const fn is_enabled(self) -> bool {
+                match self {
+                    $(
+                        $name::$feature => cfg!(target_feature = $target_feature),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+define! {
+    /// An x86 CPU feature.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum Feature {
+        /// Streaming SIMD Extensions.
+        Sse = "sse",
+        /// Streaming SIMD Extensions 2.
+        Sse2 = "sse2",
+        /// Streaming SIMD Extensions 3.
+        Sse3 = "sse3",
+        /// Supplemental Streaming SIMD Extensions 3.
+        Ssse3 = "ssse3",
+        /// Streaming SIMD Extensions 4.1.
+        Sse41 = "sse4.1",
+        /// Streaming SIMD Extensions 4.2.
+        Sse42 = "sse4.2",
+        /// Population count.
+        Popcnt = "popcnt",
+        /// 16-byte compare and exchange.
+        Cmpxchg16b = "cmpxchg16b",
+        /// Advanced Vector Extensions.
+        Avx = "avx",
+        /// Advanced Vector Extensions 2.
+        Avx2 = "avx2",
+        /// Fused multiply-add.
+        Fma = "fma",
+        /// Half precision float conversions.
+        F16c = "f16c",
+        /// Bit manipulation instruction set 1.
+        Bmi1 = "bmi1",
+        /// Bit manipulation instruction set 2.
+        Bmi2 = "bmi2",
+        /// Leading zero count.
+        Lzcnt = "lzcnt",
+        /// Byte swapping moves.
+        Movbe = "movbe",
+        /// `lahf` and `sahf` in 64-bit mode.
+        Lahfsahf = "lahfsahf",
+        /// Extended processor state saving.
+        Xsave = "xsave",
+        /// AVX-512 foundation.
+        Avx512f = "avx512f",
+        /// AVX-512 conflict detection.
+        Avx512cd = "avx512cd",
+        /// AVX-512 byte and word instructions.
+        Avx512bw = "avx512bw",
+        /// AVX-512 doubleword and quadword instructions.
+        Avx512dq = "avx512dq",
+        /// AVX-512 vector length extensions.
+        Avx512vl = "avx512vl",
+        /// AVX-512 vector byte manipulation instructions.
+        Avx512vbmi = "avx512vbmi",
+    }
+}
+
+/// A set of [`Feature`]s.
+///
+/// This can describe the features detected on the current CPU, the features enabled
+/// at compile time, or a set of features parsed from a target feature string such as
+/// `"+sse4.2,+avx2,-fma"`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct FeatureSet {
+    pub(crate) bits: u64,
+}
+
+impl FeatureSet {
+    /// The set containing no features.
+    pub const EMPTY: FeatureSet = FeatureSet { bits: 0 };
+
+    /// The set containing every feature.
+    pub const ALL: FeatureSet = {
+        let mut set = FeatureSet::EMPTY;
+        let mut features = Feature::ALL;
+
+        while let [feature, rest @ ..] = features {
+            set = set.with(*feature);
+            features = rest;
+        }
+
+        set
+    };
+
+    /// Create a set from raw bits, dropping any bits that do not map to a feature.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn from_bits(bits: u64) -> FeatureSet {
+        FeatureSet {
+            bits: bits & FeatureSet::ALL.bits,
+        }
+    }
+
+    /// Create a set containing a single feature.
+    #[inline]
+    #[must_use]
+    pub const fn from_feature(feature: Feature) -> FeatureSet {
+        FeatureSet {
+            bits: feature.bit(),
+        }
+    }
+
+    /// Create a set from a slice of features.
+    #[inline]
+    #[must_use]
+    pub const fn from_features(mut features: &[Feature]) -> FeatureSet {
+        let mut set = FeatureSet::EMPTY;
+
+        while let [feature, rest @ ..] = features {
+            set = set.with(*feature);
+            features = rest;
+        }
+
+        set
+    }
+
+    /// Get the set of features that are enabled at compile time.
+    #[inline]
+    #[must_use]
+    pub const fn enabled() -> FeatureSet {
+        const ENABLED: FeatureSet = {
+            let mut set = FeatureSet::EMPTY;
+            let mut features = Feature::ALL;
+
+            while let [feature, rest @ ..] = features {
+                if feature.is_enabled() {
+                    set = set.with(*feature);
+                }
+
+                features = rest;
+            }
+
+            set
+        };
+
+        ENABLED
+    }
+
+    /// Get the set of features that are available on the current CPU and operating
+    /// system, and that were not [disabled](crate::detect::disabled_features).
+    #[inline]
+    #[must_use]
+    pub fn detected() -> FeatureSet {
+        super::detect::features()
+    }
+
+    /// Get a copy of this set with a feature added.
+    #[inline]
+    #[must_use]
+    pub const fn with(self, feature: Feature) -> FeatureSet {
+        FeatureSet {
+            bits: self.bits | feature.bit(),
+        }
+    }
+
+    /// Get a copy of this set with a feature removed.
+    #[inline]
+    #[must_use]
+    pub const fn without(self, feature: Feature) -> FeatureSet {
+        FeatureSet {
+            bits: self.bits & !feature.bit(),
+        }
+    }
+
+    /// Check whether this set contains a feature.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, feature: Feature) -> bool {
+        self.bits & feature.bit() != 0
+    }
+
+    /// Check whether this set contains no features.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Get the number of features in this set.
+    #[inline]
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Get the features in either set.
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: FeatureSet) -> FeatureSet {
+        FeatureSet {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Get the features in both sets.
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: FeatureSet) -> FeatureSet {
+        FeatureSet {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Get the features in this set that are not in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: FeatureSet) -> FeatureSet {
+        FeatureSet {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Get the features in exactly one of the sets.
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: FeatureSet) -> FeatureSet {
+        FeatureSet {
+            bits: self.bits ^ other.bits,
+        }
+    }
+
+    /// Get every feature that is not in this set.
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> FeatureSet {
+        FeatureSet::ALL.difference(self)
+    }
+
+    /// Check whether every feature of this set is in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn is_subset(self, other: FeatureSet) -> bool {
+        self.bits & other.bits == self.bits
+    }
+
+    /// Check whether every feature of `other` is in this set.
+    #[inline]
+    #[must_use]
+    pub const fn is_superset(self, other: FeatureSet) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Check whether the sets have no features in common.
+    #[inline]
+    #[must_use]
+    pub const fn is_disjoint(self, other: FeatureSet) -> bool {
+        self.bits & other.bits == 0
+    }
+
+    /// Get an iterator over the features in this set.
+    #[inline]
+    #[must_use]
+    pub const fn iter(self) -> Iter {
+        Iter { bits: self.bits }
+    }
+
+    /// Parse a comma separated list of target features.
+    ///
+    /// This is equivalent to applying the list to [`FeatureSet::EMPTY`] with
+    /// [`FeatureSet::apply`].
+    #[inline]
+    pub const fn parse(s: &str) -> Result<FeatureSet, ParseFeatureError<'_>> {
+        FeatureSet::EMPTY.apply(s)
+    }
+
+    /// Apply a comma separated list of target features to this set.
+    ///
+    /// Features prefixed with `-` are removed from the set, while features
+    /// prefixed with `+` or without a prefix are added to it. The list is
+    /// applied from left to right, and empty entries are ignored.
+    pub const fn apply(self, s: &str) -> Result<FeatureSet, ParseFeatureError<'_>> {
+        let mut set = self;
+        let mut rest = s;
+
+        loop {
+            let (entry, next) = match split(",", rest) {
+                Some((entry, next)) => (entry, Some(next)),
+                None => (rest, None),
+            };
+
+            let entry = entry.trim_ascii();
+
+            let (name, enable) = match (strip_prefix("+", entry), strip_prefix("-", entry)) {
+                (Some(name), _) => (name, true),
+                (_, Some(name)) => (name, false),
+                (None, None) => (entry, true),
+            };
+
+            if !entry.is_empty() {
+                match Feature::from_name(name) {
+                    Some(feature) if enable => set = set.with(feature),
+                    Some(feature) => set = set.without(feature),
+                    None => return Err(ParseFeatureError { name }),
+                }
+            }
+
+            match next {
+                Some(next) => rest = next,
+                None => return Ok(set),
+            }
+        }
+    }
+}
+
+impl From<Feature> for FeatureSet {
+    #[inline]
+    fn from(feature: Feature) -> FeatureSet {
+        FeatureSet::from_feature(feature)
+    }
+}
+
+impl BitOr for FeatureSet {
+    type Output = FeatureSet;
+
+    #[inline]
+    fn bitor(self, rhs: FeatureSet) -> FeatureSet {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for FeatureSet {
+    type Output = FeatureSet;
+
+    #[inline]
+    fn bitand(self, rhs: FeatureSet) -> FeatureSet {
+        self.intersection(rhs)
+    }
+}
+
+impl BitXor for FeatureSet {
+    type Output = FeatureSet;
+
+    #[inline]
+    fn bitxor(self, rhs: FeatureSet) -> FeatureSet {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl Sub for FeatureSet {
+    type Output = FeatureSet;
+
+    #[inline]
+    fn sub(self, rhs: FeatureSet) -> FeatureSet {
+        self.difference(rhs)
+    }
+}
+
+impl Not for FeatureSet {
+    type Output = FeatureSet;
+
+    #[inline]
+    fn not(self) -> FeatureSet {
+        self.complement()
+    }
+}
+
+impl BitOrAssign for FeatureSet {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: FeatureSet) {
+        *self = *self | rhs;
+    }
+}
+
+impl BitAndAssign for FeatureSet {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: FeatureSet) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitXorAssign for FeatureSet {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: FeatureSet) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl SubAssign for FeatureSet {
+    #[inline]
+    fn sub_assign(&mut self, rhs: FeatureSet) {
+        *self = *self - rhs;
+    }
+}
+
+impl FromIterator<Feature> for FeatureSet {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Feature>>(iter: I) -> FeatureSet {
+        let mut set = FeatureSet::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Feature> for FeatureSet {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Feature>>(&mut self, iter: I) {
+        for feature in iter {
+            *self = self.with(feature);
+        }
+    }
+}
+
+impl IntoIterator for FeatureSet {
+    type Item = Feature;
+    type IntoIter = Iter;
+
+    #[inline]
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+/// Formats the set as a target feature string, such as `+sse,+sse2`.
+impl fmt::Display for FeatureSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, feature) in self.iter().enumerate() {
+            if index != 0 {
+                f.write_str(",")?;
+            }
+
+            write!(f, "+{}", feature.name())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FeatureSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl fmt::Display for Feature {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An iterator over the features in a [`FeatureSet`].
+#[derive(Debug, Clone)]
+pub struct Iter {
+    bits: u64,
+}
+
+impl Iterator for Iter {
+    type Item = Feature;
+
+    #[inline]
+    fn next(&mut self) -> Option<Feature> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+
+        Some(Feature::ALL[index])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl FusedIterator for Iter {}
+
+/// The error returned when parsing a target feature string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseFeatureError<'a> {
+    name: &'a str,
+}
+
+impl<'a> ParseFeatureError<'a> {
+    /// Get the name of the unknown feature.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+}
+
+impl fmt::Display for ParseFeatureError<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown target feature `{}`", self.name)
+    }
+}
+
+impl core::error::Error for ParseFeatureError<'_> {}
+
+const _: () = assert!(matches!(
+    FeatureSet::parse("+sse4.2,+avx2,-fma"),
+    Ok(set) if set.contains(Feature::Sse42) && set.contains(Feature::Avx2) && set.len() == 2
+));
+const _: () = assert!(matches!(
+    FeatureSet::parse("+sse,nope"),
+    Err(ParseFeatureError { name }) if is_eq(name, "nope")
+));
@@ -125,6 +125,7 @@ macro_rules! define {
     };
 }
 
+const RELATION_BITS: i32 = 0x03;
 const RESULT_BIT: i32 = 0x04;
 const ORDER_BIT: i32 = 0x08;
 const SIGNAL_BIT: i32 = 0x10;
@@ -428,6 +429,191 @@ impl Cmp {
     }
 }
 
+impl Cmp {
+    /// Evaluate this predicate on a pair of [`f32`]s, with the same result as the
+    /// comparison instructions.
+    #[inline]
+    #[must_use]
+    pub const fn eval_f32(self, a: f32, b: f32) -> bool {
+        self.eval(a.is_nan() || b.is_nan(), a == b, a < b, a <= b)
+    }
+
+    /// Evaluate this predicate on a pair of [`f64`]s, with the same result as the
+    /// comparison instructions.
+    #[inline]
+    #[must_use]
+    pub const fn eval_f64(self, a: f64, b: f64) -> bool {
+        self.eval(a.is_nan() || b.is_nan(), a == b, a < b, a <= b)
+    }
+
+    /// Evaluate this predicate from the relations between two scalars.
+    ///
+    /// The two lowest bits select the relation (equal, less-than, less-than-or-equal
+    /// or unordered), which the result bit negates. Unordered scalars only satisfy
+    /// the unordered relation, which the order bit negates as well.
+    #[inline(always)]
+    const fn eval(self, unordered: bool, eq: bool, lt: bool, le: bool) -> bool {
+        let predicate = self as i32;
+        let relation = predicate & RELATION_BITS;
+
+        let result = if unordered {
+            (relation == RELATION_BITS) ^ (predicate & ORDER_BIT != 0)
+        } else {
+            match relation {
+                0 => eq,
+                1 => lt,
+                2 => le,
+                _ => false,
+            }
+        };
+
+        result ^ (predicate & RESULT_BIT != 0)
+    }
+}
+
+/// Evaluate `$body` with `$imm` bound to a constant holding the immediate of a
+/// given [`Cmp`].
+///
+/// This lets a [`Cmp`] be passed to intrinsics that take the predicate as a const
+/// generic. As long as the predicate is known at compile time, the match folds away
+/// and only the instruction using the immediate remains.
+macro_rules! cmp_imm {
+    ($cmp:expr, $imm:ident => $body:expr) => {
+        match $cmp {
+            $crate::core_arch::x86::float::Cmp::EQ => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::EQ as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::EQ_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::EQ_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::EQ_UNORD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::EQ_UNORD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::EQ_UNORD_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::EQ_UNORD_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NEQ => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NEQ as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NEQ_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NEQ_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NEQ_UNORD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NEQ_UNORD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NEQ_UNORD_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NEQ_UNORD_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::GE => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::GE as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::GE_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::GE_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NGE => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NGE as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NGE_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NGE_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::GT => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::GT as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::GT_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::GT_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NGT => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NGT as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NGT_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NGT_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::LE => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::LE as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::LE_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::LE_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NLE => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NLE as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NLE_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NLE_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::LT => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::LT as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::LT_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::LT_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NLT => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NLT as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::NLT_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::NLT_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::TRUE => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::TRUE as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::TRUE_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::TRUE_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::FALSE => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::FALSE as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::FALSE_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::FALSE_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::ORD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::ORD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::ORD_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::ORD_LOUD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::UNORD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::UNORD as i32;
+                $body
+            }
+            $crate::core_arch::x86::float::Cmp::UNORD_LOUD => {
+                const $imm: i32 = $crate::core_arch::x86::float::Cmp::UNORD_LOUD as i32;
+                $body
+            }
+        }
+    };
+}
+
+pub(crate) use cmp_imm;
+
 impl fmt::Display for Cmp {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -488,3 +674,53 @@ impl Noise {
 }
 
 const _X: &str = Cmp::EQ.name();
+
+#[cfg(test)]
+mod tests {
+    use crate::types::vector::{f32x4, f64x2};
+
+    use super::{
+        super::{test::token_or_skip, Avx},
+        Cmp,
+    };
+
+    #[test]
+    fn eval_matches_instructions() {
+        let token = token_or_skip!(Avx);
+
+        let f32s = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -f32::NAN,
+        ];
+        let f64s = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -f64::NAN,
+        ];
+
+        for cmp in (0..32).filter_map(Cmp::from_i32) {
+            for (a, b) in f32s.into_iter().flat_map(|a| f32s.map(|b| (a, b))) {
+                let mask = token.cmp_f32x4(f32x4::splat(a), f32x4::splat(b), cmp);
+
+                assert_eq!(mask.test(0), cmp.eval_f32(a, b), "{cmp:?} {a:?} {b:?}");
+            }
+
+            for (a, b) in f64s.into_iter().flat_map(|a| f64s.map(|b| (a, b))) {
+                let mask = token.cmp_f64x2(f64x2::splat(a), f64x2::splat(b), cmp);
+
+                assert_eq!(mask.test(0), cmp.eval_f64(a, b), "{cmp:?} {a:?} {b:?}");
+            }
+        }
+    }
+}
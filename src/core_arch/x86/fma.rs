@@ -0,0 +1,406 @@
+use crate::types::vector::{f32x4, f32x8, f64x2, f64x4};
+
+use super::{
+    raw::{
+        _mm256_fmadd_pd, _mm256_fmadd_ps, _mm256_fmaddsub_pd, _mm256_fmaddsub_ps, _mm256_fmsub_pd,
+        _mm256_fmsub_ps, _mm256_fmsubadd_pd, _mm256_fmsubadd_ps, _mm256_fnmadd_pd,
+        _mm256_fnmadd_ps, _mm256_fnmsub_pd, _mm256_fnmsub_ps, _mm_fmadd_pd, _mm_fmadd_ps,
+        _mm_fmadd_sd, _mm_fmadd_ss, _mm_fmaddsub_pd, _mm_fmaddsub_ps, _mm_fmsub_pd, _mm_fmsub_ps,
+        _mm_fmsub_sd, _mm_fmsub_ss, _mm_fmsubadd_pd, _mm_fmsubadd_ps, _mm_fnmadd_pd, _mm_fnmadd_ps,
+        _mm_fnmadd_sd, _mm_fnmadd_ss, _mm_fnmsub_pd, _mm_fnmsub_ps, _mm_fnmsub_sd, _mm_fnmsub_ss,
+    },
+    token::token,
+};
+
+token! {
+    /// A token proving that FMA is available.
+    pub struct Fma("FMA") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Avx = "avx",
+        Fma = "fma",
+    }
+}
+
+// Packed fused multiply-add.
+impl Fma {
+    /// Compute `(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm_fmadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_f32x4(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fmadd_ps(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm_fmsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_sub_f32x4(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fmsub_ps(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `-(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm_fnmadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_add_f32x4(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fnmadd_ps(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `-(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm_fnmsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_sub_f32x4(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fnmsub_ps(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) - c` for the even lanes and `(a * b) + c` for the odd lanes,
+    /// rounding only once.
+    #[doc(alias = "_mm_fmaddsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_addsub_f32x4(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fmaddsub_ps(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) + c` for the even lanes and `(a * b) - c` for the odd lanes,
+    /// rounding only once.
+    #[doc(alias = "_mm_fmsubadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_subadd_f32x4(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fmsubadd_ps(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm256_fmadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_f32x8(self, a: f32x8, b: f32x8, c: f32x8) -> f32x8 {
+        unsafe { _mm256_fmadd_ps(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm256_fmsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_sub_f32x8(self, a: f32x8, b: f32x8, c: f32x8) -> f32x8 {
+        unsafe { _mm256_fmsub_ps(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `-(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm256_fnmadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_add_f32x8(self, a: f32x8, b: f32x8, c: f32x8) -> f32x8 {
+        unsafe { _mm256_fnmadd_ps(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `-(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm256_fnmsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_sub_f32x8(self, a: f32x8, b: f32x8, c: f32x8) -> f32x8 {
+        unsafe { _mm256_fnmsub_ps(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `(a * b) - c` for the even lanes and `(a * b) + c` for the odd lanes,
+    /// rounding only once.
+    #[doc(alias = "_mm256_fmaddsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_addsub_f32x8(self, a: f32x8, b: f32x8, c: f32x8) -> f32x8 {
+        unsafe { _mm256_fmaddsub_ps(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `(a * b) + c` for the even lanes and `(a * b) - c` for the odd lanes,
+    /// rounding only once.
+    #[doc(alias = "_mm256_fmsubadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_subadd_f32x8(self, a: f32x8, b: f32x8, c: f32x8) -> f32x8 {
+        unsafe { _mm256_fmsubadd_ps(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm_fmadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_f64x2(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fmadd_pd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm_fmsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_sub_f64x2(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fmsub_pd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `-(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm_fnmadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_add_f64x2(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fnmadd_pd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `-(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm_fnmsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_sub_f64x2(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fnmsub_pd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) - c` for the even lanes and `(a * b) + c` for the odd lanes,
+    /// rounding only once.
+    #[doc(alias = "_mm_fmaddsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_addsub_f64x2(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fmaddsub_pd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) + c` for the even lanes and `(a * b) - c` for the odd lanes,
+    /// rounding only once.
+    #[doc(alias = "_mm_fmsubadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_subadd_f64x2(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fmsubadd_pd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm256_fmadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_f64x4(self, a: f64x4, b: f64x4, c: f64x4) -> f64x4 {
+        unsafe { _mm256_fmadd_pd(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm256_fmsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_sub_f64x4(self, a: f64x4, b: f64x4, c: f64x4) -> f64x4 {
+        unsafe { _mm256_fmsub_pd(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `-(a * b) + c` for every lane, rounding only once.
+    #[doc(alias = "_mm256_fnmadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_add_f64x4(self, a: f64x4, b: f64x4, c: f64x4) -> f64x4 {
+        unsafe { _mm256_fnmadd_pd(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `-(a * b) - c` for every lane, rounding only once.
+    #[doc(alias = "_mm256_fnmsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_sub_f64x4(self, a: f64x4, b: f64x4, c: f64x4) -> f64x4 {
+        unsafe { _mm256_fnmsub_pd(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `(a * b) - c` for the even lanes and `(a * b) + c` for the odd lanes,
+    /// rounding only once.
+    #[doc(alias = "_mm256_fmaddsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_addsub_f64x4(self, a: f64x4, b: f64x4, c: f64x4) -> f64x4 {
+        unsafe { _mm256_fmaddsub_pd(a.avx, b.avx, c.avx) }.into()
+    }
+
+    /// Compute `(a * b) + c` for the even lanes and `(a * b) - c` for the odd lanes,
+    /// rounding only once.
+    #[doc(alias = "_mm256_fmsubadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_subadd_f64x4(self, a: f64x4, b: f64x4, c: f64x4) -> f64x4 {
+        unsafe { _mm256_fmsubadd_pd(a.avx, b.avx, c.avx) }.into()
+    }
+}
+
+// Scalar fused multiply-add.
+//
+// These only operate on the lowest lane, and copy the upper lanes from `a`.
+impl Fma {
+    /// Compute `(a * b) + c` for the lowest lane, rounding only once.
+    #[doc(alias = "_mm_fmadd_ss")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_f32x4_s(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fmadd_ss(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) - c` for the lowest lane, rounding only once.
+    #[doc(alias = "_mm_fmsub_ss")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_sub_f32x4_s(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fmsub_ss(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `-(a * b) + c` for the lowest lane, rounding only once.
+    #[doc(alias = "_mm_fnmadd_ss")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_add_f32x4_s(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fnmadd_ss(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `-(a * b) - c` for the lowest lane, rounding only once.
+    #[doc(alias = "_mm_fnmsub_ss")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_sub_f32x4_s(self, a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        unsafe { _mm_fnmsub_ss(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) + c` for the lowest lane, rounding only once.
+    #[doc(alias = "_mm_fmadd_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_f64x2_s(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fmadd_sd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `(a * b) - c` for the lowest lane, rounding only once.
+    #[doc(alias = "_mm_fmsub_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_sub_f64x2_s(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fmsub_sd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `-(a * b) + c` for the lowest lane, rounding only once.
+    #[doc(alias = "_mm_fnmadd_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_add_f64x2_s(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fnmadd_sd(a.sse, b.sse, c.sse) }.into()
+    }
+
+    /// Compute `-(a * b) - c` for the lowest lane, rounding only once.
+    #[doc(alias = "_mm_fnmsub_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neg_mul_sub_f64x2_s(self, a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        unsafe { _mm_fnmsub_sd(a.sse, b.sse, c.sse) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::{f32x4, f32x8, f64x2, f64x4},
+        util::test::{assert_lanes, Rng},
+    };
+
+    use super::{
+        super::test::{lane_wise, lowest_lane, token_or_skip, ROUNDS},
+        Fma,
+    };
+
+    #[test]
+    fn packed() {
+        let token = token_or_skip!(Fma);
+
+        lane_wise! {
+            token;
+            mul_add_f32x4 (a, b, c): f32x4 => a.mul_add(b, c);
+            mul_sub_f32x4 (a, b, c): f32x4 => a.mul_add(b, -c);
+            neg_mul_add_f32x4 (a, b, c): f32x4 => (-a).mul_add(b, c);
+            neg_mul_sub_f32x4 (a, b, c): f32x4 => (-a).mul_add(b, -c);
+            mul_add_f32x8 (a, b, c): f32x8 => a.mul_add(b, c);
+            mul_sub_f32x8 (a, b, c): f32x8 => a.mul_add(b, -c);
+            neg_mul_add_f32x8 (a, b, c): f32x8 => (-a).mul_add(b, c);
+            neg_mul_sub_f32x8 (a, b, c): f32x8 => (-a).mul_add(b, -c);
+
+            mul_add_f64x2 (a, b, c): f64x2 => a.mul_add(b, c);
+            mul_sub_f64x2 (a, b, c): f64x2 => a.mul_add(b, -c);
+            neg_mul_add_f64x2 (a, b, c): f64x2 => (-a).mul_add(b, c);
+            neg_mul_sub_f64x2 (a, b, c): f64x2 => (-a).mul_add(b, -c);
+            mul_add_f64x4 (a, b, c): f64x4 => a.mul_add(b, c);
+            mul_sub_f64x4 (a, b, c): f64x4 => a.mul_add(b, -c);
+            neg_mul_add_f64x4 (a, b, c): f64x4 => (-a).mul_add(b, c);
+            neg_mul_sub_f64x4 (a, b, c): f64x4 => (-a).mul_add(b, -c);
+        }
+    }
+
+    #[test]
+    fn alternating() {
+        let token = token_or_skip!(Fma);
+        let mut rng = Rng::new(1);
+
+        // `mul_addsub` subtracts in the even lanes and adds in the odd ones, and
+        // `mul_subadd` does the opposite.
+        macro_rules! check {
+            ($($vector:ident: $addsub:ident, $subadd:ident;)*) => {$(
+                for _ in 0..ROUNDS {
+                    let a = $vector::from_array(rng.array()).to_array();
+                    let b = $vector::from_array(rng.array()).to_array();
+                    let c = $vector::from_array(rng.array()).to_array();
+                    let sub: Vec<_> = (0..a.len()).map(|i| a[i].mul_add(b[i], -c[i])).collect();
+                    let add: Vec<_> = (0..a.len()).map(|i| a[i].mul_add(b[i], c[i])).collect();
+                    let addsub: Vec<_> =
+                        (0..a.len()).map(|i| if i % 2 == 0 { sub[i] } else { add[i] }).collect();
+                    let subadd: Vec<_> =
+                        (0..a.len()).map(|i| if i % 2 == 0 { add[i] } else { sub[i] }).collect();
+                    let (va, vb, vc) =
+                        ($vector::from_array(a), $vector::from_array(b), $vector::from_array(c));
+
+                    assert_lanes(&token.$addsub(va, vb, vc).to_array(), &addsub, (a, b, c));
+                    assert_lanes(&token.$subadd(va, vb, vc).to_array(), &subadd, (a, b, c));
+                }
+            )*};
+        }
+
+        check! {
+            f32x4: mul_addsub_f32x4, mul_subadd_f32x4;
+            f32x8: mul_addsub_f32x8, mul_subadd_f32x8;
+            f64x2: mul_addsub_f64x2, mul_subadd_f64x2;
+            f64x4: mul_addsub_f64x4, mul_subadd_f64x4;
+        }
+    }
+
+    #[test]
+    fn scalar() {
+        let token = token_or_skip!(Fma);
+
+        lowest_lane! {
+            token;
+            mul_add_f32x4_s (a, b, c): f32x4 => a.mul_add(b, c);
+            mul_sub_f32x4_s (a, b, c): f32x4 => a.mul_add(b, -c);
+            neg_mul_add_f32x4_s (a, b, c): f32x4 => (-a).mul_add(b, c);
+            neg_mul_sub_f32x4_s (a, b, c): f32x4 => (-a).mul_add(b, -c);
+            mul_add_f64x2_s (a, b, c): f64x2 => a.mul_add(b, c);
+            mul_sub_f64x2_s (a, b, c): f64x2 => a.mul_add(b, -c);
+            neg_mul_add_f64x2_s (a, b, c): f64x2 => (-a).mul_add(b, c);
+            neg_mul_sub_f64x2_s (a, b, c): f64x2 => (-a).mul_add(b, -c);
+        }
+    }
+
+    #[test]
+    fn single_rounding() {
+        let token = token_or_skip!(Fma);
+
+        // The product is `1 - 2^-46`, which rounds to one before the subtraction unless the
+        // operation is fused.
+        let a = f32x4::splat(1.0 + f32::EPSILON);
+        let b = f32x4::splat(1.0 - f32::EPSILON);
+        let c = f32x4::splat(1.0);
+        let residual = -(f32::EPSILON * f32::EPSILON);
+
+        assert_eq!(token.mul_sub_f32x4(a, b, c).to_array(), [residual; 4]);
+        assert_eq!(token.neg_mul_add_f32x4(a, b, c).to_array(), [-residual; 4]);
+    }
+}
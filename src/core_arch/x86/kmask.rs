@@ -0,0 +1,254 @@
+use core::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+use super::raw::{__mmask16, __mmask32, __mmask64, __mmask8};
+
+macro_rules! kmask {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($raw:ident);
+    )*) => {$(
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #[repr(transparent)]
+        $vis struct $name(pub(crate) $raw);
+
+        impl $name {
+            /// The number of bits in this mask.
+            pub const BITS: u32 = $raw::BITS;
+
+            /// A mask with every bit set.
+            pub const ALL: $name = $name($raw::MAX);
+
+            /// A mask with no bits set.
+            pub const NONE: $name = $name(0);
+
+            /// Create a mask from its raw bits.
+            #[inline]
+            #[must_use]
+            pub const fn from_bits(bits: $raw) -> $name {
+                $name(bits)
+            }
+
+            /// Get the raw bits of this mask.
+            #[inline]
+            #[must_use]
+            pub const fn to_bits(self) -> $raw {
+                self.0
+            }
+
+            /// Check whether the bit for a given lane is set.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lane` is out of bounds.
+            #[inline]
+            #[must_use]
+            pub const fn test(self, lane: u32) -> bool {
+                assert!(lane < $name::BITS, "`lane` is out of bounds");
+
+                (self.0 >> lane) & 1 != 0
+            }
+
+            /// Get a copy of this mask with the bit for a given lane set to `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lane` is out of bounds.
+            #[inline]
+            #[must_use]
+            pub const fn with(self, lane: u32, value: bool) -> $name {
+                assert!(lane < $name::BITS, "`lane` is out of bounds");
+
+                $name((self.0 & !(1 << lane)) | ((value as $raw) << lane))
+            }
+
+            /// Count the number of set bits.
+            #[inline]
+            #[must_use]
+            pub const fn count(self) -> u32 {
+                self.0.count_ones()
+            }
+
+            /// Check whether any bit is set.
+            #[inline]
+            #[must_use]
+            pub const fn any(self) -> bool {
+                self.0 != 0
+            }
+
+            /// Check whether no bit is set.
+            #[inline]
+            #[must_use]
+            pub const fn none(self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl From<$raw> for $name {
+            #[inline]
+            fn from(bits: $raw) -> $name {
+                $name(bits)
+            }
+        }
+
+        impl From<$name> for $raw {
+            #[inline]
+            fn from(mask: $name) -> $raw {
+                mask.0
+            }
+        }
+
+        impl BitAnd for $name {
+            type Output = $name;
+
+            #[inline]
+            fn bitand(self, rhs: $name) -> $name {
+                $name(self.0 & rhs.0)
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = $name;
+
+            #[inline]
+            fn bitor(self, rhs: $name) -> $name {
+                $name(self.0 | rhs.0)
+            }
+        }
+
+        impl BitXor for $name {
+            type Output = $name;
+
+            #[inline]
+            fn bitxor(self, rhs: $name) -> $name {
+                $name(self.0 ^ rhs.0)
+            }
+        }
+
+        impl Not for $name {
+            type Output = $name;
+
+            #[inline]
+            fn not(self) -> $name {
+                $name(!self.0)
+            }
+        }
+
+        impl BitAndAssign for $name {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: $name) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        impl BitOrAssign for $name {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: $name) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl BitXorAssign for $name {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: $name) {
+                self.0 ^= rhs.0;
+            }
+        }
+
+        impl fmt::Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    f,
+                    "{}({:#0width$b})",
+                    ::core::stringify!($name),
+                    self.0,
+                    width = $name::BITS as usize + 2,
+                )
+            }
+        }
+    )*};
+}
+
+kmask! {
+    /// An AVX-512 mask with one bit per lane, held in a `k` register.
+    ///
+    /// Bit `i` corresponds to lane `i`.
+    pub struct KMask8(__mmask8);
+
+    /// An AVX-512 mask with one bit per lane, held in a `k` register.
+    ///
+    /// Bit `i` corresponds to lane `i`.
+    pub struct KMask16(__mmask16);
+
+    /// An AVX-512 mask with one bit per lane, held in a `k` register.
+    ///
+    /// Bit `i` corresponds to lane `i`.
+    pub struct KMask32(__mmask32);
+
+    /// An AVX-512 mask with one bit per lane, held in a `k` register.
+    ///
+    /// Bit `i` corresponds to lane `i`.
+    pub struct KMask64(__mmask64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KMask16, KMask64, KMask8};
+
+    #[test]
+    fn bits() {
+        let k = KMask16::from_bits(0b1000_0000_0100_0001);
+
+        assert!(k.test(0) && k.test(6) && k.test(15));
+        assert!(!k.test(1) && !k.test(14));
+        assert_eq!(k.count(), 3);
+        assert_eq!(
+            k.with(0, false).with(1, true).to_bits(),
+            0b1000_0000_0100_0010
+        );
+        assert_eq!(k.with(6, true), k);
+
+        assert!(KMask64::ALL.test(63));
+        assert_eq!(KMask64::ALL.count(), 64);
+        assert!(KMask8::NONE.none() && !KMask8::NONE.any());
+        assert!(KMask8::ALL.any() && !KMask8::ALL.none());
+    }
+
+    #[test]
+    fn ops() {
+        let (a, b) = (
+            KMask8::from_bits(0b1100_1010),
+            KMask8::from_bits(0b1010_0110),
+        );
+
+        assert_eq!((a & b).to_bits(), 0b1000_0010);
+        assert_eq!((a | b).to_bits(), 0b1110_1110);
+        assert_eq!((a ^ b).to_bits(), 0b0110_1100);
+        assert_eq!((!a).to_bits(), 0b0011_0101);
+
+        let mut c = a;
+        c ^= b;
+        c |= KMask8::from_bits(1);
+        c &= !KMask8::from_bits(0b0100_0000);
+
+        assert_eq!(c.to_bits(), 0b0010_1101);
+    }
+
+    #[test]
+    #[should_panic = "`lane` is out of bounds"]
+    fn test_out_of_bounds() {
+        let _ = KMask8::ALL.test(8);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", KMask8::from_bits(0b101)),
+            "KMask8(0b00000101)"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Tokens for the x86-64 microarchitecture levels defined by the x86-64 psABI.
+
+use super::{token::token, FeatureSet};
+
+token! {
+    /// A token proving that the x86-64-v2 microarchitecture level is available.
+    ///
+    /// This bundles CMPXCHG16B, LAHF/SAHF, POPCNT, SSE3, SSE4.1, SSE4.2 and SSSE3 on
+    /// top of the x86-64 baseline.
+    ///
+    /// LAHF/SAHF is checked when detecting this level at runtime, but cannot be
+    /// enabled with `#[target_feature]`.
+    pub struct X86V2("x86-64-v2") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Popcnt = "popcnt",
+        Cmpxchg16b = "cmpxchg16b",
+        Lahfsahf,
+    }
+}
+
+token! {
+    /// A token proving that the x86-64-v3 microarchitecture level is available.
+    ///
+    /// This bundles AVX, AVX2, BMI1, BMI2, F16C, FMA, LZCNT, MOVBE and XSAVE on top
+    /// of x86-64-v2.
+    pub struct X86V3("x86-64-v3") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Popcnt = "popcnt",
+        Cmpxchg16b = "cmpxchg16b",
+        Lahfsahf,
+        Avx = "avx",
+        Avx2 = "avx2",
+        Bmi1 = "bmi1",
+        Bmi2 = "bmi2",
+        F16c = "f16c",
+        Fma = "fma",
+        Lzcnt = "lzcnt",
+        Movbe = "movbe",
+        Xsave = "xsave",
+    }
+}
+
+token! {
+    /// A token proving that the x86-64-v4 microarchitecture level is available.
+    ///
+    /// This bundles AVX-512F, AVX-512BW, AVX-512CD, AVX-512DQ and AVX-512VL on top of
+    /// x86-64-v3.
+    pub struct X86V4("x86-64-v4") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+        Popcnt = "popcnt",
+        Cmpxchg16b = "cmpxchg16b",
+        Lahfsahf,
+        Avx = "avx",
+        Avx2 = "avx2",
+        Bmi1 = "bmi1",
+        Bmi2 = "bmi2",
+        F16c = "f16c",
+        Fma = "fma",
+        Lzcnt = "lzcnt",
+        Movbe = "movbe",
+        Xsave = "xsave",
+        Avx512f = "avx512f",
+        Avx512bw = "avx512bw",
+        Avx512cd = "avx512cd",
+        Avx512dq = "avx512dq",
+        Avx512vl = "avx512vl",
+    }
+}
+
+// Every level has exactly the features listed for it by the psABI, along with SSE and
+// SSE2 from the baseline.
+const _: () = assert!(matches!(
+    FeatureSet::parse("+sse,+sse2,+cmpxchg16b,+lahfsahf,+popcnt,+sse3,+sse4.1,+sse4.2,+ssse3"),
+    Ok(set) if set.bits == X86V2::FEATURE_SET.bits
+));
+const _: () = assert!(matches!(
+    X86V2::FEATURE_SET.apply("+avx,+avx2,+bmi1,+bmi2,+f16c,+fma,+lzcnt,+movbe,+xsave"),
+    Ok(set) if set.bits == X86V3::FEATURE_SET.bits
+));
+const _: () = assert!(matches!(
+    X86V3::FEATURE_SET.apply("+avx512f,+avx512bw,+avx512cd,+avx512dq,+avx512vl"),
+    Ok(set) if set.bits == X86V4::FEATURE_SET.bits
+));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_are_nested() {
+        if X86V4::try_new().is_some() {
+            assert!(X86V3::try_new().is_some());
+        }
+
+        if X86V3::try_new().is_some() {
+            assert!(X86V2::try_new().is_some());
+        }
+    }
+}
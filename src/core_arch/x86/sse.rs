@@ -1,40 +1,21 @@
-use crate::{types::vector::f32x4, Func};
+use core::mem::transmute;
 
-use super::raw::{
-    _mm_add_ps, _mm_add_ss, _mm_div_ps, _mm_div_ss, _mm_mul_ps, _mm_mul_ss, _mm_sub_ps, _mm_sub_ss,
-};
-
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(transparent)]
-pub struct Sse {
-    __private: (),
-}
-
-impl Sse {
-    #[inline(always)]
-    #[must_use]
-    pub const unsafe fn new_unchecked() -> Sse {
-        Sse { __private: () }
-    }
+use crate::types::vector::{f32x4, i32x4};
 
-    /// Call a given closure with SSE enabled.
-    ///
-    /// Despite being marked as unsafe, this is actually safe as
-    /// [`Sse`] proves that sse is available.
-    ///
-    /// This however has to be marked as one cannot use the `target_feature`
-    /// attribute without it being an unsafe method.
-    #[inline]
-    #[target_feature(enable = "sse")]
-    pub unsafe fn execute<F: Func>(self, f: F) -> F::Output {
-        f.call()
-    }
+use super::{
+    raw::{
+        __m128, __m128i, _mm_add_ps, _mm_add_ss, _mm_cmpeq_ps, _mm_cmpge_ps, _mm_cmpgt_ps,
+        _mm_cmple_ps, _mm_cmplt_ps, _mm_cmpneq_ps, _mm_cmpord_ps, _mm_cmpunord_ps, _mm_div_ps,
+        _mm_div_ss, _mm_max_ps, _mm_min_ps, _mm_movemask_ps, _mm_mul_ps, _mm_mul_ss, _mm_sub_ps,
+        _mm_sub_ss,
+    },
+    token::token,
+};
 
-    /// Call a given closure with SSE enabled.
-    #[inline(always)]
-    pub fn run<F: Func>(self, f: F) -> F::Output {
-        // SAFETY: We know that SSE is enabled.
-        unsafe { self.execute(f) }
+token! {
+    /// A token proving that SSE is available.
+    pub struct Sse("SSE") {
+        Sse = "sse",
     }
 }
 
@@ -66,6 +47,20 @@ impl Sse {
     pub fn div_f32x4(self, a: f32x4, b: f32x4) -> f32x4 {
         unsafe { _mm_div_ps(a.sse, b.sse) }.into()
     }
+
+    #[doc(alias = "_mm_min_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f32x4(self, a: f32x4, b: f32x4) -> f32x4 {
+        unsafe { _mm_min_ps(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f32x4(self, a: f32x4, b: f32x4) -> f32x4 {
+        unsafe { _mm_max_ps(a.sse, b.sse) }.into()
+    }
 }
 
 impl Sse {
@@ -97,3 +92,77 @@ impl Sse {
         unsafe { _mm_div_ss(a.sse, b.sse) }.into()
     }
 }
+
+// Single precision comparisons.
+//
+// Every lane of the result is either all ones or all zeros. The results are
+// reinterpreted with `transmute`, as `_mm_castps_si128` requires SSE2.
+impl Sse {
+    #[doc(alias = "_mm_cmpeq_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_f32x4(self, a: f32x4, b: f32x4) -> i32x4 {
+        unsafe { transmute::<__m128, __m128i>(_mm_cmpeq_ps(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpneq_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neq_f32x4(self, a: f32x4, b: f32x4) -> i32x4 {
+        unsafe { transmute::<__m128, __m128i>(_mm_cmpneq_ps(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmplt_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_f32x4(self, a: f32x4, b: f32x4) -> i32x4 {
+        unsafe { transmute::<__m128, __m128i>(_mm_cmplt_ps(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmple_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_f32x4(self, a: f32x4, b: f32x4) -> i32x4 {
+        unsafe { transmute::<__m128, __m128i>(_mm_cmple_ps(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpgt_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_f32x4(self, a: f32x4, b: f32x4) -> i32x4 {
+        unsafe { transmute::<__m128, __m128i>(_mm_cmpgt_ps(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpge_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_f32x4(self, a: f32x4, b: f32x4) -> i32x4 {
+        unsafe { transmute::<__m128, __m128i>(_mm_cmpge_ps(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpord_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ord_f32x4(self, a: f32x4, b: f32x4) -> i32x4 {
+        unsafe { transmute::<__m128, __m128i>(_mm_cmpord_ps(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpunord_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unord_f32x4(self, a: f32x4, b: f32x4) -> i32x4 {
+        unsafe { transmute::<__m128, __m128i>(_mm_cmpunord_ps(a.sse, b.sse)) }.into()
+    }
+}
+
+// Sign bit extraction.
+//
+// Bit `i` of the result is the highest bit of lane `i`.
+impl Sse {
+    #[doc(alias = "_mm_movemask_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn movemask_f32x4(self, a: f32x4) -> u8 {
+        unsafe { _mm_movemask_ps(a.sse) as u8 }
+    }
+}
@@ -0,0 +1,1469 @@
+use crate::types::vector::{f64x2, i16x8, i32x4, i64x2, i8x16, u16x8, u32x4, u64x2, u8x16};
+
+use super::{
+    raw::{
+        _mm_add_epi16, _mm_add_epi32, _mm_add_epi64, _mm_add_epi8, _mm_add_pd, _mm_add_sd,
+        _mm_adds_epi16, _mm_adds_epi8, _mm_adds_epu16, _mm_adds_epu8, _mm_and_si128,
+        _mm_andnot_si128, _mm_avg_epu16, _mm_avg_epu8, _mm_castpd_si128, _mm_cmpeq_epi16,
+        _mm_cmpeq_epi32, _mm_cmpeq_epi8, _mm_cmpeq_pd, _mm_cmpge_pd, _mm_cmpgt_epi16,
+        _mm_cmpgt_epi32, _mm_cmpgt_epi8, _mm_cmpgt_pd, _mm_cmple_pd, _mm_cmplt_epi16,
+        _mm_cmplt_epi32, _mm_cmplt_epi8, _mm_cmplt_pd, _mm_cmpneq_pd, _mm_cmpord_pd,
+        _mm_cmpunord_pd, _mm_div_pd, _mm_div_sd, _mm_madd_epi16, _mm_max_epi16, _mm_max_epu8,
+        _mm_max_pd, _mm_max_sd, _mm_min_epi16, _mm_min_epu8, _mm_min_pd, _mm_min_sd,
+        _mm_movemask_epi8, _mm_movemask_pd, _mm_mul_epu32, _mm_mul_pd, _mm_mul_sd, _mm_mulhi_epi16,
+        _mm_mulhi_epu16, _mm_mullo_epi16, _mm_or_si128, _mm_packs_epi16, _mm_packs_epi32,
+        _mm_packus_epi16, _mm_sad_epu8, _mm_sll_epi16, _mm_sll_epi32, _mm_sll_epi64,
+        _mm_slli_epi16, _mm_slli_epi32, _mm_slli_epi64, _mm_slli_si128, _mm_sqrt_pd, _mm_sqrt_sd,
+        _mm_sra_epi16, _mm_sra_epi32, _mm_srai_epi16, _mm_srai_epi32, _mm_srl_epi16, _mm_srl_epi32,
+        _mm_srl_epi64, _mm_srli_epi16, _mm_srli_epi32, _mm_srli_epi64, _mm_srli_si128,
+        _mm_sub_epi16, _mm_sub_epi32, _mm_sub_epi64, _mm_sub_epi8, _mm_sub_pd, _mm_sub_sd,
+        _mm_subs_epi16, _mm_subs_epi8, _mm_subs_epu16, _mm_subs_epu8, _mm_unpackhi_epi16,
+        _mm_unpackhi_epi32, _mm_unpackhi_epi64, _mm_unpackhi_epi8, _mm_unpackhi_pd,
+        _mm_unpacklo_epi16, _mm_unpacklo_epi32, _mm_unpacklo_epi64, _mm_unpacklo_epi8,
+        _mm_unpacklo_pd, _mm_xor_si128,
+    },
+    token::token,
+};
+
+token! {
+    /// A token proving that SSE2 is available.
+    pub struct Sse2("SSE2") {
+        Sse = "sse",
+        Sse2 = "sse2",
+    }
+}
+
+// Wrapping integer arithmetic.
+impl Sse2 {
+    #[doc(alias = "_mm_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_add_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_add_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_add_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_add_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_add_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_add_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_add_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_add_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_add_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_add_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_add_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_add_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_sub_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_sub_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_sub_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_sub_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_sub_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_sub_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_sub_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_sub_epi64(a.sse, b.sse) }.into()
+    }
+}
+
+// Saturating integer arithmetic.
+impl Sse2 {
+    #[doc(alias = "_mm_adds_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_adds_epu8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_adds_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_adds_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_adds_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_adds_epu16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_adds_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_add_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_adds_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_subs_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_subs_epu8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_subs_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_subs_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_subs_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_subs_epu16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_subs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn saturating_sub_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_subs_epi16(a.sse, b.sse) }.into()
+    }
+}
+
+// Integer multiplication, averages and sums of absolute differences.
+impl Sse2 {
+    #[doc(alias = "_mm_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_mullo_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mullo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_mullo_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mulhi_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_high_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_mulhi_epu16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mulhi_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_high_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_mulhi_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mul_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_even_u32x4(self, a: u32x4, b: u32x4) -> u64x2 {
+        unsafe { _mm_mul_epu32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_madd_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_pairs_i16x8(self, a: i16x8, b: i16x8) -> i32x4 {
+        unsafe { _mm_madd_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_avg_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn avg_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_avg_epu8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_avg_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn avg_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_avg_epu16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sad_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sum_abs_diff_u8x16(self, a: u8x16, b: u8x16) -> u64x2 {
+        unsafe { _mm_sad_epu8(a.sse, b.sse) }.into()
+    }
+}
+
+// Integer minimum and maximum.
+impl Sse2 {
+    #[doc(alias = "_mm_min_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_min_epu8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_epu8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_max_epu8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_min_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_min_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_max_epi16(a.sse, b.sse) }.into()
+    }
+}
+
+// Integer comparisons.
+//
+// Every lane of the result is either all ones or all zeros.
+impl Sse2 {
+    #[doc(alias = "_mm_cmpeq_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_cmpeq_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpeq_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_cmpeq_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpeq_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_cmpeq_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpeq_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_cmpeq_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpeq_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_cmpeq_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpeq_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_cmpeq_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpgt_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_cmpgt_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpgt_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_cmpgt_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpgt_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_cmpgt_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmplt_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_cmplt_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmplt_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_cmplt_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmplt_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_cmplt_epi32(a.sse, b.sse) }.into()
+    }
+}
+
+// Sign bit extraction.
+//
+// Bit `i` of the result is the highest bit of lane `i`.
+impl Sse2 {
+    #[doc(alias = "_mm_movemask_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn movemask_u8x16(self, a: u8x16) -> u16 {
+        unsafe { _mm_movemask_epi8(a.sse) as u16 }
+    }
+
+    #[doc(alias = "_mm_movemask_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn movemask_i8x16(self, a: i8x16) -> u16 {
+        unsafe { _mm_movemask_epi8(a.sse) as u16 }
+    }
+
+    #[doc(alias = "_mm_movemask_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn movemask_f64x2(self, a: f64x2) -> u8 {
+        unsafe { _mm_movemask_pd(a.sse) as u8 }
+    }
+}
+
+// Integer bitwise logic.
+impl Sse2 {
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm_and_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_and_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm_or_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_or_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm_xor_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_xor_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm_andnot_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_andnot_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm_and_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_and_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm_or_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_or_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm_xor_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_xor_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm_andnot_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_andnot_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm_and_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_and_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm_or_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_or_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm_xor_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_xor_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm_andnot_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_andnot_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm_and_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_and_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm_or_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_or_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm_xor_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_xor_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm_andnot_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_andnot_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm_and_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_and_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm_or_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_or_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm_xor_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_xor_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm_andnot_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_andnot_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm_and_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_and_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm_or_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_or_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm_xor_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_xor_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm_andnot_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_andnot_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm_and_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_and_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm_or_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_or_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm_xor_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_xor_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm_andnot_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_andnot_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `a` and `b`.
+    #[doc(alias = "_mm_and_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn and_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_and_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise OR of `a` and `b`.
+    #[doc(alias = "_mm_or_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn or_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_or_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise XOR of `a` and `b`.
+    #[doc(alias = "_mm_xor_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn xor_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_xor_si128(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the bitwise AND of `b` and the complement of `a`.
+    #[doc(alias = "_mm_andnot_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_andnot_si128(a.sse, b.sse) }.into()
+    }
+}
+
+// Shifts.
+//
+// Shifting by at least the bit width of a lane results in zero, or in the
+// case of arithmetic shifts, every bit being a copy of the sign bit.
+impl Sse2 {
+    #[doc(alias = "_mm_slli_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_u16x8<const IMM8: i32>(self, a: u16x8) -> u16x8 {
+        unsafe { _mm_slli_epi16::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_slli_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_i16x8<const IMM8: i32>(self, a: i16x8) -> i16x8 {
+        unsafe { _mm_slli_epi16::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_slli_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_u32x4<const IMM8: i32>(self, a: u32x4) -> u32x4 {
+        unsafe { _mm_slli_epi32::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_slli_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_i32x4<const IMM8: i32>(self, a: i32x4) -> i32x4 {
+        unsafe { _mm_slli_epi32::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_slli_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_u64x2<const IMM8: i32>(self, a: u64x2) -> u64x2 {
+        unsafe { _mm_slli_epi64::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_slli_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_i64x2<const IMM8: i32>(self, a: i64x2) -> i64x2 {
+        unsafe { _mm_slli_epi64::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srli_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_u16x8<const IMM8: i32>(self, a: u16x8) -> u16x8 {
+        unsafe { _mm_srli_epi16::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srai_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_i16x8<const IMM8: i32>(self, a: i16x8) -> i16x8 {
+        unsafe { _mm_srai_epi16::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srli_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_u32x4<const IMM8: i32>(self, a: u32x4) -> u32x4 {
+        unsafe { _mm_srli_epi32::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srai_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_i32x4<const IMM8: i32>(self, a: i32x4) -> i32x4 {
+        unsafe { _mm_srai_epi32::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srli_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_u64x2<const IMM8: i32>(self, a: u64x2) -> u64x2 {
+        unsafe { _mm_srli_epi64::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sll_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_u16x8(self, a: u16x8, count: u64x2) -> u16x8 {
+        unsafe { _mm_sll_epi16(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sll_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_i16x8(self, a: i16x8, count: u64x2) -> i16x8 {
+        unsafe { _mm_sll_epi16(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sll_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_u32x4(self, a: u32x4, count: u64x2) -> u32x4 {
+        unsafe { _mm_sll_epi32(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sll_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_i32x4(self, a: i32x4, count: u64x2) -> i32x4 {
+        unsafe { _mm_sll_epi32(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sll_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_u64x2(self, a: u64x2, count: u64x2) -> u64x2 {
+        unsafe { _mm_sll_epi64(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sll_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_by_i64x2(self, a: i64x2, count: u64x2) -> i64x2 {
+        unsafe { _mm_sll_epi64(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srl_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_u16x8(self, a: u16x8, count: u64x2) -> u16x8 {
+        unsafe { _mm_srl_epi16(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sra_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_i16x8(self, a: i16x8, count: u64x2) -> i16x8 {
+        unsafe { _mm_sra_epi16(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srl_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_u32x4(self, a: u32x4, count: u64x2) -> u32x4 {
+        unsafe { _mm_srl_epi32(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sra_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_i32x4(self, a: i32x4, count: u64x2) -> i32x4 {
+        unsafe { _mm_sra_epi32(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srl_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_by_u64x2(self, a: u64x2, count: u64x2) -> u64x2 {
+        unsafe { _mm_srl_epi64(a.sse, count.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_slli_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shl_bytes_u8x16<const IMM8: i32>(self, a: u8x16) -> u8x16 {
+        unsafe { _mm_slli_si128::<IMM8>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_srli_si128")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_bytes_u8x16<const IMM8: i32>(self, a: u8x16) -> u8x16 {
+        unsafe { _mm_srli_si128::<IMM8>(a.sse) }.into()
+    }
+}
+
+// Packing and unpacking.
+impl Sse2 {
+    #[doc(alias = "_mm_packs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn pack_saturate_i16x8(self, a: i16x8, b: i16x8) -> i8x16 {
+        unsafe { _mm_packs_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_packus_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn pack_saturate_unsigned_i16x8(self, a: i16x8, b: i16x8) -> u8x16 {
+        unsafe { _mm_packus_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_packs_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn pack_saturate_i32x4(self, a: i32x4, b: i32x4) -> i16x8 {
+        unsafe { _mm_packs_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_unpacklo_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_unpacklo_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_unpacklo_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_unpacklo_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_unpacklo_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_unpacklo_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_unpacklo_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_unpacklo_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpacklo_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_lo_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_unpacklo_pd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_u8x16(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_unpackhi_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_unpackhi_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_unpackhi_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_unpackhi_epi16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_unpackhi_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_unpackhi_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_unpackhi_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_unpackhi_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_unpackhi_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unpack_hi_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_unpackhi_pd(a.sse, b.sse) }.into()
+    }
+}
+
+// Double precision arithmetic.
+impl Sse2 {
+    #[doc(alias = "_mm_add_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_add_pd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_sub_pd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mul_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_mul_pd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_div_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_div_pd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_min_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_min_pd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_max_pd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sqrt_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f64x2(self, a: f64x2) -> f64x2 {
+        unsafe { _mm_sqrt_pd(a.sse) }.into()
+    }
+}
+
+// Double precision comparisons.
+//
+// Every lane of the result is either all ones or all zeros.
+impl Sse2 {
+    #[doc(alias = "_mm_cmpeq_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_f64x2(self, a: f64x2, b: f64x2) -> i64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmpeq_pd(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpneq_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn neq_f64x2(self, a: f64x2, b: f64x2) -> i64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmpneq_pd(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmplt_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_f64x2(self, a: f64x2, b: f64x2) -> i64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmplt_pd(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmple_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn le_f64x2(self, a: f64x2, b: f64x2) -> i64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmple_pd(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpgt_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_f64x2(self, a: f64x2, b: f64x2) -> i64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmpgt_pd(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpge_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ge_f64x2(self, a: f64x2, b: f64x2) -> i64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmpge_pd(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpord_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ord_f64x2(self, a: f64x2, b: f64x2) -> i64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmpord_pd(a.sse, b.sse)) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpunord_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn unord_f64x2(self, a: f64x2, b: f64x2) -> i64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmpunord_pd(a.sse, b.sse)) }.into()
+    }
+}
+
+// Double precision scalar arithmetic.
+//
+// These only operate on the lowest lane, and copy the upper lane from `a`.
+impl Sse2 {
+    #[doc(alias = "_mm_add_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn add_f64x2_s(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_add_sd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sub_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sub_f64x2_s(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_sub_sd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mul_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_f64x2_s(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_mul_sd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_div_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn div_f64x2_s(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_div_sd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_min_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_f64x2_s(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_min_sd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_f64x2_s(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_max_sd(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_sqrt_sd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt_f64x2_s(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_sqrt_sd(a.sse, b.sse) }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::{f64x2, i16x8, i32x4, i64x2, i8x16, u16x8, u32x4, u64x2, u8x16},
+        util::test::{assert_lanes, Rng},
+    };
+
+    use super::{
+        super::test::{
+            groups, lane_wise, lowest_lane, packs, shift_count, shifts, token_or_skip, unpacks,
+            ROUNDS,
+        },
+        Sse2,
+    };
+
+    #[test]
+    fn arith() {
+        let token = token_or_skip!(Sse2);
+
+        lane_wise! {
+            token;
+            add_u8x16 (a, b): u8x16 => a.wrapping_add(b);
+            add_i8x16 (a, b): i8x16 => a.wrapping_add(b);
+            add_u16x8 (a, b): u16x8 => a.wrapping_add(b);
+            add_i16x8 (a, b): i16x8 => a.wrapping_add(b);
+            add_u32x4 (a, b): u32x4 => a.wrapping_add(b);
+            add_i32x4 (a, b): i32x4 => a.wrapping_add(b);
+            add_u64x2 (a, b): u64x2 => a.wrapping_add(b);
+            add_i64x2 (a, b): i64x2 => a.wrapping_add(b);
+
+            sub_u8x16 (a, b): u8x16 => a.wrapping_sub(b);
+            sub_i8x16 (a, b): i8x16 => a.wrapping_sub(b);
+            sub_u16x8 (a, b): u16x8 => a.wrapping_sub(b);
+            sub_i16x8 (a, b): i16x8 => a.wrapping_sub(b);
+            sub_u32x4 (a, b): u32x4 => a.wrapping_sub(b);
+            sub_i32x4 (a, b): i32x4 => a.wrapping_sub(b);
+            sub_u64x2 (a, b): u64x2 => a.wrapping_sub(b);
+            sub_i64x2 (a, b): i64x2 => a.wrapping_sub(b);
+
+            saturating_add_u8x16 (a, b): u8x16 => a.saturating_add(b);
+            saturating_add_i8x16 (a, b): i8x16 => a.saturating_add(b);
+            saturating_add_u16x8 (a, b): u16x8 => a.saturating_add(b);
+            saturating_add_i16x8 (a, b): i16x8 => a.saturating_add(b);
+            saturating_sub_u8x16 (a, b): u8x16 => a.saturating_sub(b);
+            saturating_sub_i8x16 (a, b): i8x16 => a.saturating_sub(b);
+            saturating_sub_u16x8 (a, b): u16x8 => a.saturating_sub(b);
+            saturating_sub_i16x8 (a, b): i16x8 => a.saturating_sub(b);
+
+            mul_u16x8 (a, b): u16x8 => a.wrapping_mul(b);
+            mul_i16x8 (a, b): i16x8 => a.wrapping_mul(b);
+            mul_high_u16x8 (a, b): u16x8 => ((a as u32 * b as u32) >> 16) as u16;
+            mul_high_i16x8 (a, b): i16x8 => ((a as i32 * b as i32) >> 16) as i16;
+            avg_u8x16 (a, b): u8x16 => ((a as u16 + b as u16 + 1) >> 1) as u8;
+            avg_u16x8 (a, b): u16x8 => ((a as u32 + b as u32 + 1) >> 1) as u16;
+
+            min_u8x16 (a, b): u8x16 => a.min(b);
+            max_u8x16 (a, b): u8x16 => a.max(b);
+            min_i16x8 (a, b): i16x8 => a.min(b);
+            max_i16x8 (a, b): i16x8 => a.max(b);
+        }
+
+        groups! {
+            token;
+            mul_even_u32x4 (a, b): u32x4 => a[0] as u64 * b[0] as u64;
+            mul_add_pairs_i16x8 (a, b): i16x8
+                => (a[0] as i32 * b[0] as i32).wrapping_add(a[1] as i32 * b[1] as i32);
+            sum_abs_diff_u8x16 (a, b): u8x16
+                => a.iter().zip(b).map(|(&a, &b)| u64::from(a.abs_diff(b))).sum::<u64>();
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let token = token_or_skip!(Sse2);
+
+        // Only the signed lanes can be ordered, so unsigned lanes that differ in their
+        // highest bit must compare the other way around.
+        lane_wise! {
+            token;
+            eq_u8x16 (a, b): u8x16 => if a == b { !0 } else { 0 };
+            eq_i8x16 (a, b): i8x16 => if a == b { !0 } else { 0 };
+            eq_u16x8 (a, b): u16x8 => if a == b { !0 } else { 0 };
+            eq_i16x8 (a, b): i16x8 => if a == b { !0 } else { 0 };
+            eq_u32x4 (a, b): u32x4 => if a == b { !0 } else { 0 };
+            eq_i32x4 (a, b): i32x4 => if a == b { !0 } else { 0 };
+            gt_i8x16 (a, b): i8x16 => if a > b { !0 } else { 0 };
+            gt_i16x8 (a, b): i16x8 => if a > b { !0 } else { 0 };
+            gt_i32x4 (a, b): i32x4 => if a > b { !0 } else { 0 };
+            lt_i8x16 (a, b): i8x16 => if a < b { !0 } else { 0 };
+            lt_i16x8 (a, b): i16x8 => if a < b { !0 } else { 0 };
+            lt_i32x4 (a, b): i32x4 => if a < b { !0 } else { 0 };
+
+            eq_f64x2 (a, b): f64x2 => if a == b { !0 } else { 0 };
+            neq_f64x2 (a, b): f64x2 => if a != b { !0 } else { 0 };
+            lt_f64x2 (a, b): f64x2 => if a < b { !0 } else { 0 };
+            le_f64x2 (a, b): f64x2 => if a <= b { !0 } else { 0 };
+            gt_f64x2 (a, b): f64x2 => if a > b { !0 } else { 0 };
+            ge_f64x2 (a, b): f64x2 => if a >= b { !0 } else { 0 };
+            ord_f64x2 (a, b): f64x2 => if a.is_nan() || b.is_nan() { 0 } else { !0 };
+            unord_f64x2 (a, b): f64x2 => if a.is_nan() || b.is_nan() { !0 } else { 0 };
+        }
+
+        assert_eq!(
+            token
+                .eq_u8x16(u8x16::splat(0x80), u8x16::splat(0x80))
+                .to_array(),
+            [0xff; 16]
+        );
+        assert_eq!(
+            token
+                .gt_i8x16(i8x16::splat(0), i8x16::splat(-128))
+                .to_array(),
+            [-1; 16]
+        );
+    }
+
+    #[test]
+    fn movemask() {
+        let token = token_or_skip!(Sse2);
+        let mut rng = Rng::new(1);
+
+        for _ in 0..ROUNDS {
+            let a: [u8; 16] = rng.array();
+            let expected = (0..16).fold(0, |bits, i| bits | ((a[i] >> 7) as u16) << i);
+
+            assert_eq!(
+                token.movemask_u8x16(u8x16::from_array(a)),
+                expected,
+                "{a:?}"
+            );
+            assert_eq!(
+                token.movemask_i8x16(i8x16::from_array(a.map(|a| a as i8))),
+                expected,
+                "{a:?}"
+            );
+
+            let a: [f64; 2] = rng.array();
+            let expected = (0..2).fold(0, |bits, i| bits | (a[i].is_sign_negative() as u8) << i);
+
+            assert_eq!(
+                token.movemask_f64x2(f64x2::from_array(a)),
+                expected,
+                "{a:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise() {
+        let token = token_or_skip!(Sse2);
+
+        lane_wise! {
+            token;
+            and_u8x16 (a, b): u8x16 => a & b;
+            or_u8x16 (a, b): u8x16 => a | b;
+            xor_u8x16 (a, b): u8x16 => a ^ b;
+            andnot_u8x16 (a, b): u8x16 => !a & b;
+            and_i8x16 (a, b): i8x16 => a & b;
+            or_i8x16 (a, b): i8x16 => a | b;
+            xor_i8x16 (a, b): i8x16 => a ^ b;
+            andnot_i8x16 (a, b): i8x16 => !a & b;
+            and_u16x8 (a, b): u16x8 => a & b;
+            or_u16x8 (a, b): u16x8 => a | b;
+            xor_u16x8 (a, b): u16x8 => a ^ b;
+            andnot_u16x8 (a, b): u16x8 => !a & b;
+            and_i16x8 (a, b): i16x8 => a & b;
+            or_i16x8 (a, b): i16x8 => a | b;
+            xor_i16x8 (a, b): i16x8 => a ^ b;
+            andnot_i16x8 (a, b): i16x8 => !a & b;
+            and_u32x4 (a, b): u32x4 => a & b;
+            or_u32x4 (a, b): u32x4 => a | b;
+            xor_u32x4 (a, b): u32x4 => a ^ b;
+            andnot_u32x4 (a, b): u32x4 => !a & b;
+            and_i32x4 (a, b): i32x4 => a & b;
+            or_i32x4 (a, b): i32x4 => a | b;
+            xor_i32x4 (a, b): i32x4 => a ^ b;
+            andnot_i32x4 (a, b): i32x4 => !a & b;
+            and_u64x2 (a, b): u64x2 => a & b;
+            or_u64x2 (a, b): u64x2 => a | b;
+            xor_u64x2 (a, b): u64x2 => a ^ b;
+            andnot_u64x2 (a, b): u64x2 => !a & b;
+            and_i64x2 (a, b): i64x2 => a & b;
+            or_i64x2 (a, b): i64x2 => a | b;
+            xor_i64x2 (a, b): i64x2 => a ^ b;
+            andnot_i64x2 (a, b): i64x2 => !a & b;
+        }
+    }
+
+    #[test]
+    fn shift() {
+        let token = token_or_skip!(Sse2);
+
+        shifts! {
+            token;
+            imm shl_u16x8: u16x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_i16x8: i16x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_u32x4: u32x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_i32x4: i32x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_u64x2: u64x2 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shl_i64x2: i64x2 => |a, count| a.checked_shl(count).unwrap_or(0);
+            imm shr_u16x8: u16x8 => |a, count| a.checked_shr(count).unwrap_or(0);
+            imm shr_i16x8: i16x8 => |a, count| a >> count.min(15);
+            imm shr_u32x4: u32x4 => |a, count| a.checked_shr(count).unwrap_or(0);
+            imm shr_i32x4: i32x4 => |a, count| a >> count.min(31);
+            imm shr_u64x2: u64x2 => |a, count| a.checked_shr(count).unwrap_or(0);
+
+            by shl_by_u16x8: u16x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_i16x8: i16x8 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_u32x4: u32x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_i32x4: i32x4 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_u64x2: u64x2 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shl_by_i64x2: i64x2 => |a, count| a.checked_shl(count).unwrap_or(0);
+            by shr_by_u16x8: u16x8 => |a, count| a.checked_shr(count).unwrap_or(0);
+            by shr_by_i16x8: i16x8 => |a, count| a >> count.min(15);
+            by shr_by_u32x4: u32x4 => |a, count| a.checked_shr(count).unwrap_or(0);
+            by shr_by_i32x4: i32x4 => |a, count| a >> count.min(31);
+            by shr_by_u64x2: u64x2 => |a, count| a.checked_shr(count).unwrap_or(0);
+        }
+    }
+
+    #[test]
+    fn shift_bytes() {
+        let token = token_or_skip!(Sse2);
+        let lanes: [u8; 16] = core::array::from_fn(|i| i as u8 + 1);
+        let a = u8x16::from_array(lanes);
+
+        macro_rules! check {
+            ($($imm:literal),*) => {$(
+                assert_eq!(
+                    token.shl_bytes_u8x16::<$imm>(a).to_array(),
+                    core::array::from_fn(|i| i.checked_sub($imm).map_or(0, |i| lanes[i])),
+                    "{}",
+                    $imm,
+                );
+                assert_eq!(
+                    token.shr_bytes_u8x16::<$imm>(a).to_array(),
+                    core::array::from_fn(|i| lanes.get(i + $imm).copied().unwrap_or(0)),
+                    "{}",
+                    $imm,
+                );
+            )*};
+        }
+
+        check!(0, 1, 5, 15, 16, 255);
+    }
+
+    #[test]
+    fn pack_unpack() {
+        let token = token_or_skip!(Sse2);
+
+        packs! {
+            token;
+            pack_saturate_i16x8: i16x8 => i8;
+            pack_saturate_unsigned_i16x8: i16x8 => u8;
+            pack_saturate_i32x4: i32x4 => i16;
+        }
+
+        unpacks! {
+            token;
+            unpack_lo_u8x16 / unpack_hi_u8x16: u8x16;
+            unpack_lo_i8x16 / unpack_hi_i8x16: i8x16;
+            unpack_lo_u16x8 / unpack_hi_u16x8: u16x8;
+            unpack_lo_i16x8 / unpack_hi_i16x8: i16x8;
+            unpack_lo_u32x4 / unpack_hi_u32x4: u32x4;
+            unpack_lo_i32x4 / unpack_hi_i32x4: i32x4;
+            unpack_lo_u64x2 / unpack_hi_u64x2: u64x2;
+            unpack_lo_i64x2 / unpack_hi_i64x2: i64x2;
+            unpack_lo_f64x2 / unpack_hi_f64x2: f64x2;
+        }
+    }
+
+    #[test]
+    fn float() {
+        let token = token_or_skip!(Sse2);
+
+        lane_wise! {
+            token;
+            add_f64x2 (a, b): f64x2 => a + b;
+            sub_f64x2 (a, b): f64x2 => a - b;
+            mul_f64x2 (a, b): f64x2 => a * b;
+            div_f64x2 (a, b): f64x2 => a / b;
+            min_f64x2 (a, b): f64x2 => if a < b { a } else { b };
+            max_f64x2 (a, b): f64x2 => if a > b { a } else { b };
+            sqrt_f64x2 (a): f64x2 => a.sqrt();
+        }
+
+        lowest_lane! {
+            token;
+            add_f64x2_s (a, b): f64x2 => a + b;
+            sub_f64x2_s (a, b): f64x2 => a - b;
+            mul_f64x2_s (a, b): f64x2 => a * b;
+            div_f64x2_s (a, b): f64x2 => a / b;
+            min_f64x2_s (a, b): f64x2 => if a < b { a } else { b };
+            max_f64x2_s (a, b): f64x2 => if a > b { a } else { b };
+            sqrt_f64x2_s (a, b): f64x2 => b.sqrt();
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::types::vector::{f32x4, f64x2};
+
+use super::{
+    raw::{
+        _mm_addsub_pd, _mm_addsub_ps, _mm_hadd_pd, _mm_hadd_ps, _mm_hsub_pd, _mm_hsub_ps,
+        _mm_movedup_pd, _mm_movehdup_ps, _mm_moveldup_ps,
+    },
+    token::token,
+};
+
+token! {
+    /// A token proving that SSE3 is available.
+    pub struct Sse3("SSE3") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+    }
+}
+
+// Horizontal and alternating arithmetic.
+impl Sse3 {
+    /// Subtract the even lanes and add the odd lanes.
+    #[doc(alias = "_mm_addsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn addsub_f32x4(self, a: f32x4, b: f32x4) -> f32x4 {
+        unsafe { _mm_addsub_ps(a.sse, b.sse) }.into()
+    }
+
+    /// Horizontally add adjacent pairs of lanes of `a` followed by `b`.
+    #[doc(alias = "_mm_hadd_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hadd_f32x4(self, a: f32x4, b: f32x4) -> f32x4 {
+        unsafe { _mm_hadd_ps(a.sse, b.sse) }.into()
+    }
+
+    /// Horizontally subtract adjacent pairs of lanes of `a` followed by `b`.
+    #[doc(alias = "_mm_hsub_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hsub_f32x4(self, a: f32x4, b: f32x4) -> f32x4 {
+        unsafe { _mm_hsub_ps(a.sse, b.sse) }.into()
+    }
+
+    /// Subtract the even lanes and add the odd lanes.
+    #[doc(alias = "_mm_addsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn addsub_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_addsub_pd(a.sse, b.sse) }.into()
+    }
+
+    /// Horizontally add adjacent pairs of lanes of `a` followed by `b`.
+    #[doc(alias = "_mm_hadd_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hadd_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_hadd_pd(a.sse, b.sse) }.into()
+    }
+
+    /// Horizontally subtract adjacent pairs of lanes of `a` followed by `b`.
+    #[doc(alias = "_mm_hsub_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hsub_f64x2(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_hsub_pd(a.sse, b.sse) }.into()
+    }
+}
+
+// Duplication.
+impl Sse3 {
+    /// Copy every even lane into the odd lane after it.
+    #[doc(alias = "_mm_moveldup_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_even_f32x4(self, a: f32x4) -> f32x4 {
+        unsafe { _mm_moveldup_ps(a.sse) }.into()
+    }
+
+    /// Copy every odd lane into the even lane before it.
+    #[doc(alias = "_mm_movehdup_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_odd_f32x4(self, a: f32x4) -> f32x4 {
+        unsafe { _mm_movehdup_ps(a.sse) }.into()
+    }
+
+    /// Copy the lowest lane into both lanes.
+    #[doc(alias = "_mm_movedup_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_low_f64x2(self, a: f64x2) -> f64x2 {
+        unsafe { _mm_movedup_pd(a.sse) }.into()
+    }
+}
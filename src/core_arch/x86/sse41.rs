@@ -0,0 +1,248 @@
+use crate::types::vector::{f32x4, f64x2, i16x8, i32x4, i64x2, i8x16, u16x8, u32x4, u64x2, u8x16};
+
+use super::{
+    raw::{
+        _mm_blend_epi16, _mm_blend_pd, _mm_blend_ps, _mm_blendv_epi8, _mm_blendv_pd, _mm_blendv_ps,
+        _mm_castsi128_pd, _mm_castsi128_ps, _mm_ceil_pd, _mm_ceil_ps, _mm_cmpeq_epi64,
+        _mm_floor_pd, _mm_floor_ps, _mm_max_epi32, _mm_max_epi8, _mm_max_epu16, _mm_max_epu32,
+        _mm_min_epi32, _mm_min_epi8, _mm_min_epu16, _mm_min_epu32, _mm_mul_epi32, _mm_mullo_epi32,
+        _mm_packus_epi32, _mm_round_pd, _mm_round_ps,
+    },
+    token::token,
+};
+
+token! {
+    /// A token proving that SSE4.1 is available.
+    pub struct Sse41("SSE4.1") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+    }
+}
+
+// Integer arithmetic, comparisons and packing.
+impl Sse41 {
+    #[doc(alias = "_mm_min_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_min_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_max_epi8(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_min_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_min_epu16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_epu16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u16x8(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_max_epu16(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_min_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_min_epu32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_epu32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_max_epu32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_min_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn min_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_min_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_max_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn max_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_max_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_u32x4(self, a: u32x4, b: u32x4) -> u32x4 {
+        unsafe { _mm_mullo_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mullo_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_mullo_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_mul_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_even_i32x4(self, a: i32x4, b: i32x4) -> i64x2 {
+        unsafe { _mm_mul_epi32(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpeq_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_u64x2(self, a: u64x2, b: u64x2) -> u64x2 {
+        unsafe { _mm_cmpeq_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpeq_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_cmpeq_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_packus_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn pack_saturate_unsigned_i32x4(self, a: i32x4, b: i32x4) -> u16x8 {
+        unsafe { _mm_packus_epi32(a.sse, b.sse) }.into()
+    }
+}
+
+// Rounding.
+impl Sse41 {
+    #[doc(alias = "_mm_floor_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn floor_f32x4(self, a: f32x4) -> f32x4 {
+        unsafe { _mm_floor_ps(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_ceil_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ceil_f32x4(self, a: f32x4) -> f32x4 {
+        unsafe { _mm_ceil_ps(a.sse) }.into()
+    }
+
+    /// Round every lane according to `ROUNDING`, which is made up of the
+    /// `_MM_FROUND_*` constants.
+    #[doc(alias = "_mm_round_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn round_f32x4<const ROUNDING: i32>(self, a: f32x4) -> f32x4 {
+        unsafe { _mm_round_ps::<ROUNDING>(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_floor_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn floor_f64x2(self, a: f64x2) -> f64x2 {
+        unsafe { _mm_floor_pd(a.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_ceil_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn ceil_f64x2(self, a: f64x2) -> f64x2 {
+        unsafe { _mm_ceil_pd(a.sse) }.into()
+    }
+
+    /// Round every lane according to `ROUNDING`, which is made up of the
+    /// `_MM_FROUND_*` constants.
+    #[doc(alias = "_mm_round_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn round_f64x2<const ROUNDING: i32>(self, a: f64x2) -> f64x2 {
+        unsafe { _mm_round_pd::<ROUNDING>(a.sse) }.into()
+    }
+}
+
+// Blends.
+impl Sse41 {
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm_blend_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_u16x8<const IMM8: i32>(self, a: u16x8, b: u16x8) -> u16x8 {
+        unsafe { _mm_blend_epi16::<IMM8>(a.sse, b.sse) }.into()
+    }
+
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm_blend_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_i16x8<const IMM8: i32>(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_blend_epi16::<IMM8>(a.sse, b.sse) }.into()
+    }
+
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm_blend_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_f32x4<const IMM8: i32>(self, a: f32x4, b: f32x4) -> f32x4 {
+        unsafe { _mm_blend_ps::<IMM8>(a.sse, b.sse) }.into()
+    }
+
+    /// Select lanes from `b` where the corresponding bit in `IMM8` is set, and from `a`
+    /// otherwise.
+    #[doc(alias = "_mm_blend_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blend_f64x2<const IMM8: i32>(self, a: f64x2, b: f64x2) -> f64x2 {
+        unsafe { _mm_blend_pd::<IMM8>(a.sse, b.sse) }.into()
+    }
+
+    /// Select bytes from `b` where the highest bit of the corresponding byte in `mask`
+    /// is set, and from `a` otherwise.
+    #[doc(alias = "_mm_blendv_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blendv_u8x16(self, a: u8x16, b: u8x16, mask: u8x16) -> u8x16 {
+        unsafe { _mm_blendv_epi8(a.sse, b.sse, mask.sse) }.into()
+    }
+
+    /// Select bytes from `b` where the highest bit of the corresponding byte in `mask`
+    /// is set, and from `a` otherwise.
+    #[doc(alias = "_mm_blendv_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blendv_i8x16(self, a: i8x16, b: i8x16, mask: i8x16) -> i8x16 {
+        unsafe { _mm_blendv_epi8(a.sse, b.sse, mask.sse) }.into()
+    }
+
+    /// Select lanes from `b` where the sign bit of the corresponding lane in `mask` is
+    /// set, and from `a` otherwise.
+    #[doc(alias = "_mm_blendv_ps")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blendv_f32x4(self, a: f32x4, b: f32x4, mask: i32x4) -> f32x4 {
+        unsafe { _mm_blendv_ps(a.sse, b.sse, _mm_castsi128_ps(mask.sse)) }.into()
+    }
+
+    /// Select lanes from `b` where the sign bit of the corresponding lane in `mask` is
+    /// set, and from `a` otherwise.
+    #[doc(alias = "_mm_blendv_pd")]
+    #[inline(always)]
+    #[must_use]
+    pub fn blendv_f64x2(self, a: f64x2, b: f64x2, mask: i64x2) -> f64x2 {
+        unsafe { _mm_blendv_pd(a.sse, b.sse, _mm_castsi128_pd(mask.sse)) }.into()
+    }
+}
@@ -0,0 +1,64 @@
+use crate::types::vector::i64x2;
+
+use super::{
+    raw::{_mm_cmpgt_epi64, _mm_crc32_u16, _mm_crc32_u32, _mm_crc32_u8},
+    token::token,
+};
+
+token! {
+    /// A token proving that SSE4.2 is available.
+    pub struct Sse42("SSE4.2") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+        Sse41 = "sse4.1",
+        Sse42 = "sse4.2",
+    }
+}
+
+// Integer comparisons.
+//
+// Every lane of the result is either all ones or all zeros.
+impl Sse42 {
+    #[doc(alias = "_mm_cmpgt_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn gt_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_cmpgt_epi64(a.sse, b.sse) }.into()
+    }
+
+    #[doc(alias = "_mm_cmpgt_epi64")]
+    #[inline(always)]
+    #[must_use]
+    pub fn lt_i64x2(self, a: i64x2, b: i64x2) -> i64x2 {
+        unsafe { _mm_cmpgt_epi64(b.sse, a.sse) }.into()
+    }
+}
+
+// CRC-32C.
+impl Sse42 {
+    /// Accumulate `value` into a CRC-32C checksum.
+    #[doc(alias = "_mm_crc32_u8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn crc32_u8(self, crc: u32, value: u8) -> u32 {
+        unsafe { _mm_crc32_u8(crc, value) }
+    }
+
+    /// Accumulate `value` into a CRC-32C checksum.
+    #[doc(alias = "_mm_crc32_u16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn crc32_u16(self, crc: u32, value: u16) -> u32 {
+        unsafe { _mm_crc32_u16(crc, value) }
+    }
+
+    /// Accumulate `value` into a CRC-32C checksum.
+    #[doc(alias = "_mm_crc32_u32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn crc32_u32(self, crc: u32, value: u32) -> u32 {
+        unsafe { _mm_crc32_u32(crc, value) }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::types::vector::{i16x8, i32x4, i8x16, u8x16};
+
+use super::{
+    raw::{
+        _mm_abs_epi16, _mm_abs_epi32, _mm_abs_epi8, _mm_alignr_epi8, _mm_hadd_epi16,
+        _mm_hadd_epi32, _mm_hsub_epi16, _mm_hsub_epi32, _mm_mulhrs_epi16, _mm_shuffle_epi8,
+        _mm_sign_epi16, _mm_sign_epi32, _mm_sign_epi8,
+    },
+    token::token,
+};
+
+token! {
+    /// A token proving that SSSE3 is available.
+    pub struct Ssse3("SSSE3") {
+        Sse = "sse",
+        Sse2 = "sse2",
+        Sse3 = "sse3",
+        Ssse3 = "ssse3",
+    }
+}
+
+// Integer arithmetic.
+impl Ssse3 {
+    /// Compute the absolute value of every lane, wrapping on overflow.
+    #[doc(alias = "_mm_abs_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn abs_i8x16(self, a: i8x16) -> i8x16 {
+        unsafe { _mm_abs_epi8(a.sse) }.into()
+    }
+
+    /// Negate every lane of `a` where `b` is negative, and zero it where `b` is zero.
+    #[doc(alias = "_mm_sign_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sign_i8x16(self, a: i8x16, b: i8x16) -> i8x16 {
+        unsafe { _mm_sign_epi8(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the absolute value of every lane, wrapping on overflow.
+    #[doc(alias = "_mm_abs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn abs_i16x8(self, a: i16x8) -> i16x8 {
+        unsafe { _mm_abs_epi16(a.sse) }.into()
+    }
+
+    /// Negate every lane of `a` where `b` is negative, and zero it where `b` is zero.
+    #[doc(alias = "_mm_sign_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sign_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_sign_epi16(a.sse, b.sse) }.into()
+    }
+
+    /// Compute the absolute value of every lane, wrapping on overflow.
+    #[doc(alias = "_mm_abs_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn abs_i32x4(self, a: i32x4) -> i32x4 {
+        unsafe { _mm_abs_epi32(a.sse) }.into()
+    }
+
+    /// Negate every lane of `a` where `b` is negative, and zero it where `b` is zero.
+    #[doc(alias = "_mm_sign_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn sign_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_sign_epi32(a.sse, b.sse) }.into()
+    }
+
+    /// Horizontally add adjacent pairs of lanes of `a` followed by `b`, wrapping on
+    /// overflow.
+    #[doc(alias = "_mm_hadd_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hadd_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_hadd_epi16(a.sse, b.sse) }.into()
+    }
+
+    /// Horizontally subtract adjacent pairs of lanes of `a` followed by `b`, wrapping
+    /// on overflow.
+    #[doc(alias = "_mm_hsub_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hsub_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_hsub_epi16(a.sse, b.sse) }.into()
+    }
+
+    /// Horizontally add adjacent pairs of lanes of `a` followed by `b`, wrapping on
+    /// overflow.
+    #[doc(alias = "_mm_hadd_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hadd_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_hadd_epi32(a.sse, b.sse) }.into()
+    }
+
+    /// Horizontally subtract adjacent pairs of lanes of `a` followed by `b`, wrapping
+    /// on overflow.
+    #[doc(alias = "_mm_hsub_epi32")]
+    #[inline(always)]
+    #[must_use]
+    pub fn hsub_i32x4(self, a: i32x4, b: i32x4) -> i32x4 {
+        unsafe { _mm_hsub_epi32(a.sse, b.sse) }.into()
+    }
+
+    /// Multiply every lane of `a` and `b` into a 32-bit product, and keep bits 15 to 30
+    /// of the product rounded to nearest.
+    #[doc(alias = "_mm_mulhrs_epi16")]
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_high_round_i16x8(self, a: i16x8, b: i16x8) -> i16x8 {
+        unsafe { _mm_mulhrs_epi16(a.sse, b.sse) }.into()
+    }
+}
+
+// Byte shuffles.
+impl Ssse3 {
+    /// Shuffle the bytes of `a` using the indices in `idx`.
+    ///
+    /// Only the lowest four bits of each index are used. Any index with its highest bit
+    /// set results in zero.
+    #[doc(alias = "_mm_shuffle_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle_bytes_u8x16(self, a: u8x16, idx: u8x16) -> u8x16 {
+        unsafe { _mm_shuffle_epi8(a.sse, idx.sse) }.into()
+    }
+
+    /// Concatenate `a` and `b` (with `b` in the lower half), shift the result right by
+    /// `IMM8` bytes and keep the lower 16 bytes.
+    #[doc(alias = "_mm_alignr_epi8")]
+    #[inline(always)]
+    #[must_use]
+    pub fn align_right_u8x16<const IMM8: i32>(self, a: u8x16, b: u8x16) -> u8x16 {
+        unsafe { _mm_alignr_epi8::<IMM8>(a.sse, b.sse) }.into()
+    }
+}
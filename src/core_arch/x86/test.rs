@@ -0,0 +1,471 @@
+//! Macros for checking the methods of the tokens against scalar models.
+
+pub(crate) use crate::util::test::ROUNDS;
+
+use crate::util::test::Rng;
+
+/// Get a token, or return early if the current CPU doesn't support it.
+macro_rules! token_or_skip {
+    ($token:ident) => {
+        match $token::try_new() {
+            Some(token) => token,
+            None => {
+                std::eprintln!("skipped, as {} is not available", stringify!($token));
+                return;
+            }
+        }
+    };
+}
+
+/// Check lane-wise operations, along with their merge-masked and zero-masked forms,
+/// against a model of a single lane.
+macro_rules! masked {
+    ($token:ident; $(
+        $op:ident / $mask:ident / $maskz:ident ($($arg:ident),+): $vector:ident, $kmask:ident
+        => $model:expr;
+    )*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            $(let $arg = $vector::from_array(rng.array()).to_array();)+
+            let src = $vector::from_array(rng.array()).to_array();
+            let k = $kmask::from_bits(rng.gen());
+
+            let expected: [_; $vector::LANES] = core::array::from_fn(|i| {
+                $(let $arg = $arg[i];)+
+                $model
+            });
+            let merged: [_; $vector::LANES] = core::array::from_fn(|i| {
+                if k.test(i as u32) { expected[i] } else { src[i] }
+            });
+            let zeroed: [_; $vector::LANES] = core::array::from_fn(|i| {
+                if k.test(i as u32) { expected[i] } else { Default::default() }
+            });
+
+            let context = (stringify!($op), k, $($arg),+);
+
+            assert_lanes(
+                &$token.$op($($vector::from_array($arg)),+).to_array(),
+                &expected,
+                context,
+            );
+            assert_lanes(
+                &$token.$mask($vector::from_array(src), k, $($vector::from_array($arg)),+)
+                    .to_array(),
+                &merged,
+                context,
+            );
+            assert_lanes(
+                &$token.$maskz(k, $($vector::from_array($arg)),+).to_array(),
+                &zeroed,
+                context,
+            );
+        }
+    })*};
+}
+
+/// Check lane-wise operations against a model of a single lane.
+macro_rules! lane_wise {
+    ($token:ident; $(
+        $op:ident ($($arg:ident),+): $vector:ident => $model:expr;
+    )*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            $(let $arg = $vector::from_array(rng.array()).to_array();)+
+            let actual = $token.$op($($vector::from_array($arg)),+).to_array();
+            let expected: Vec<_> = (0..actual.len())
+                .map(|i| {
+                    $(let $arg = $arg[i];)+
+                    $model
+                })
+                .collect();
+
+            assert_lanes(&actual, &expected, (stringify!($op), $($arg),+));
+        }
+    })*};
+}
+
+/// Check operations where every lane of the result is computed from a group of
+/// neighbouring lanes of the arguments, against a model of a single group.
+macro_rules! groups {
+    ($token:ident; $(
+        $op:ident ($($arg:ident),+): $vector:ident => $model:expr;
+    )*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            $(let $arg = $vector::from_array(rng.array()).to_array();)+
+            let actual = $token.$op($($vector::from_array($arg)),+).to_array();
+            let size = $vector::LANES / actual.len();
+            let expected: Vec<_> = (0..actual.len())
+                .map(|i| {
+                    $(let $arg = &$arg[i * size..][..size];)+
+                    $model
+                })
+                .collect();
+
+            assert_lanes(&actual, &expected, (stringify!($op), $($arg),+));
+        }
+    })*};
+}
+
+/// Check operations on the lowest lane, which copy the other lanes from the first
+/// argument, against a model of the lowest lane.
+macro_rules! lowest_lane {
+    ($token:ident; $(
+        $op:ident ($first:ident $(, $arg:ident)*): $vector:ident => $model:expr;
+    )*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let $first = $vector::from_array(rng.array()).to_array();
+            $(let $arg = $vector::from_array(rng.array()).to_array();)*
+            let actual = $token
+                .$op($vector::from_array($first) $(, $vector::from_array($arg))*)
+                .to_array();
+
+            let mut expected = $first;
+            #[allow(unused_variables)]
+            let lowest = {
+                let $first = $first[0];
+                $(let $arg = $arg[0];)*
+                $model
+            };
+            expected[0] = lowest;
+
+            assert_lanes(&actual, &expected, (stringify!($op), $first $(, $arg)*));
+        }
+    })*};
+}
+
+/// Shift counts around the bit widths of the lanes, and some beyond all of them.
+const SHIFT_COUNTS: [u64; 14] = [
+    0,
+    1,
+    7,
+    8,
+    15,
+    16,
+    31,
+    32,
+    63,
+    64,
+    255,
+    256,
+    1 << 32,
+    u64::MAX,
+];
+
+/// Get a random shift count, which is often around the bit width of a lane.
+pub(crate) fn shift_count(rng: &mut Rng) -> u64 {
+    let bits = rng.next_u64();
+
+    match bits % 2 {
+        0 => SHIFT_COUNTS[(bits >> 8) as usize % SHIFT_COUNTS.len()],
+        _ => (bits >> 8) % 64,
+    }
+}
+
+/// Check shifts by an immediate, by the lowest 64 bits of a vector, or by every lane of
+/// a vector, against a model of a single lane. The model gets the count limited to 255.
+macro_rules! shifts {
+    ($token:ident; $(
+        $kind:ident $method:ident: $vector:ident $(, $counts:ident)?
+        => |$a:ident, $count:ident| $model:expr;
+    )*) => {$(
+        shifts!(@shift $kind $token, $method, $vector $(, $counts)?, |$a, $count| $model);
+    )*};
+
+    (@shift imm $token:ident, $method:ident, $vector:ident, |$a:ident, $count:ident| $model:expr) => {
+        shifts!(
+            @imm $token, $method, $vector, |$a, $count| $model,
+            [0, 1, 3, 7, 8, 15, 16, 31, 32, 63, 64, 255]
+        );
+    };
+
+    (
+        @imm $token:ident, $method:ident, $vector:ident, |$a:ident, $count:ident| $model:expr,
+        [$($imm:literal),*]
+    ) => {$({
+        let mut rng = Rng::new(line!() as u64 ^ $imm);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let expected: [_; $vector::LANES] = core::array::from_fn(|i| {
+                let ($a, $count): (_, u32) = (a[i], $imm);
+                $model
+            });
+            let actual = $token.$method::<$imm>($vector::from_array(a));
+
+            assert_lanes(&actual.to_array(), &expected, (stringify!($method), $imm, a));
+        }
+    })*};
+
+    (@shift by $token:ident, $method:ident, $vector:ident, |$a:ident, $count:ident| $model:expr) => {{
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let count = shift_count(&mut rng);
+            let expected: [_; $vector::LANES] = core::array::from_fn(|i| {
+                let ($a, $count) = (a[i], count.min(255) as u32);
+                $model
+            });
+
+            // Only the lowest 64 bits are used as the count.
+            let counts = $crate::types::vector::u64x2::from_array([count, rng.gen()]);
+            let actual = $token.$method($vector::from_array(a), counts);
+
+            assert_lanes(&actual.to_array(), &expected, (stringify!($method), count, a));
+        }
+    }};
+
+    (
+        @shift var $token:ident, $method:ident, $vector:ident, $counts:ident,
+        |$a:ident, $count:ident| $model:expr
+    ) => {{
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let counts: [_; $counts::LANES] =
+                core::array::from_fn(|_| shift_count(&mut rng) as _);
+            let expected: [_; $vector::LANES] = core::array::from_fn(|i| {
+                let ($a, $count) = (a[i], u64::from(counts[i]).min(255) as u32);
+                $model
+            });
+            let actual = $token.$method($vector::from_array(a), $counts::from_array(counts));
+
+            assert_lanes(&actual.to_array(), &expected, (stringify!($method), counts, a));
+        }
+    }};
+}
+
+/// Check packing the lanes of two vectors into narrower lanes with saturation, which
+/// takes the lanes of `a` and then those of `b` within every 128-bit lane.
+macro_rules! packs {
+    ($token:ident; $($method:ident: $vector:ident => $scalar:ident;)*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let b = $vector::from_array(rng.array()).to_array();
+            let size = 16 / core::mem::size_of_val(&a[0]);
+            let expected: Vec<$scalar> = a
+                .chunks(size)
+                .zip(b.chunks(size))
+                .flat_map(|(a, b)| a.iter().chain(b))
+                .map(|&lane| lane.clamp($scalar::MIN as _, $scalar::MAX as _) as $scalar)
+                .collect();
+            let actual = $token.$method($vector::from_array(a), $vector::from_array(b));
+
+            assert_lanes(&actual.to_array(), &expected, (stringify!($method), a, b));
+        }
+    })*};
+}
+
+/// Check interleaving the lower or upper halves of every 128-bit lane of two vectors.
+macro_rules! unpacks {
+    ($token:ident; $($lo:ident / $hi:ident: $vector:ident;)*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let b = $vector::from_array(rng.array()).to_array();
+            let size = 16 / core::mem::size_of_val(&a[0]);
+            let unpack = |offset: usize| -> Vec<_> {
+                a.chunks(size)
+                    .zip(b.chunks(size))
+                    .flat_map(|(a, b)| (offset..offset + size / 2).flat_map(|i| [a[i], b[i]]))
+                    .collect()
+            };
+
+            assert_lanes(
+                &$token.$lo($vector::from_array(a), $vector::from_array(b)).to_array(),
+                &unpack(0),
+                (stringify!($lo), a, b),
+            );
+            assert_lanes(
+                &$token.$hi($vector::from_array(a), $vector::from_array(b)).to_array(),
+                &unpack(size / 2),
+                (stringify!($hi), a, b),
+            );
+        }
+    })*};
+}
+
+/// Check integer comparisons into a k-mask, including that the bits past the last lane
+/// are cleared.
+macro_rules! compares {
+    ($token:ident; $(
+        $vector:ident => $eq:ident, $ne:ident, $lt:ident, $le:ident, $gt:ident, $ge:ident;
+    )*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let mut b = $vector::from_array(rng.array()).to_array();
+
+            // Make sure that some of the lanes are equal.
+            for (a, b) in a.iter().zip(&mut b) {
+                if rng.gen() {
+                    *b = *a;
+                }
+            }
+
+            let (va, vb) = ($vector::from_array(a), $vector::from_array(b));
+            let bits = |f: fn(&_, &_) -> bool| -> u64 {
+                (0..$vector::LANES).map(|i| (f(&a[i], &b[i]) as u64) << i).sum()
+            };
+
+            assert_eq!($token.$eq(va, vb).to_bits() as u64, bits(PartialEq::eq), "{a:?} {b:?}");
+            assert_eq!($token.$ne(va, vb).to_bits() as u64, bits(PartialEq::ne), "{a:?} {b:?}");
+            assert_eq!($token.$lt(va, vb).to_bits() as u64, bits(PartialOrd::lt), "{a:?} {b:?}");
+            assert_eq!($token.$le(va, vb).to_bits() as u64, bits(PartialOrd::le), "{a:?} {b:?}");
+            assert_eq!($token.$gt(va, vb).to_bits() as u64, bits(PartialOrd::gt), "{a:?} {b:?}");
+            assert_eq!($token.$ge(va, vb).to_bits() as u64, bits(PartialOrd::ge), "{a:?} {b:?}");
+        }
+    })*};
+}
+
+/// Check float comparisons into a k-mask for every predicate, using the portable
+/// evaluation of the predicate as the model.
+macro_rules! predicates {
+    ($token:ident; $($method:ident: $vector:ident => $eval:ident;)*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let b = $vector::from_array(rng.array()).to_array();
+
+            for cmp in (0..32).filter_map(Cmp::from_i32) {
+                let expected: u64 =
+                    (0..$vector::LANES).map(|i| (cmp.$eval(a[i], b[i]) as u64) << i).sum();
+                let actual = $token.$method($vector::from_array(a), $vector::from_array(b), cmp);
+
+                assert_eq!(actual.to_bits() as u64, expected, "{cmp:?} {a:?} {b:?}");
+            }
+        }
+    })*};
+}
+
+/// Check compressing and expanding the lanes selected by a k-mask, with the
+/// zero-masked form and optionally the merge-masked form.
+macro_rules! compress_expand {
+    ($token:ident; $(
+        $vector:ident, $kmask:ident
+        => $compress:ident $(/ $compress_mask:ident)?, $expand:ident $(/ $expand_mask:ident)?;
+    )*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let src = $vector::from_array(rng.array()).to_array();
+            let k = $kmask::from_bits(rng.gen());
+            let selected = (0..$vector::LANES).filter(|&i| k.test(i as u32));
+
+            let mut compressed = (src, [Default::default(); $vector::LANES]);
+            for (lane, i) in selected.clone().enumerate() {
+                compressed.0[lane] = a[i];
+                compressed.1[lane] = a[i];
+            }
+
+            let mut expanded = (src, [Default::default(); $vector::LANES]);
+            for (lane, i) in selected.enumerate() {
+                expanded.0[i] = a[lane];
+                expanded.1[i] = a[lane];
+            }
+
+            assert_lanes(
+                &$token.$compress(k, $vector::from_array(a)).to_array(),
+                &compressed.1,
+                (stringify!($compress), k, a),
+            );
+            assert_lanes(
+                &$token.$expand(k, $vector::from_array(a)).to_array(),
+                &expanded.1,
+                (stringify!($expand), k, a),
+            );
+            $(
+                assert_lanes(
+                    &$token
+                        .$compress_mask($vector::from_array(src), k, $vector::from_array(a))
+                        .to_array(),
+                    &compressed.0,
+                    (stringify!($compress_mask), k, a),
+                );
+            )?
+            $(
+                assert_lanes(
+                    &$token
+                        .$expand_mask($vector::from_array(src), k, $vector::from_array(a))
+                        .to_array(),
+                    &expanded.0,
+                    (stringify!($expand_mask), k, a),
+                );
+            )?
+        }
+    })*};
+}
+
+/// Check a ternary logic instruction with a selection of truth tables, against a model
+/// which looks up every bit in the table.
+macro_rules! ternary_logic {
+    ($token:ident; $($method:ident: $vector:ident;)*) => {$(
+        ternary_logic!(
+            @tables $token, $method, $vector,
+            [0x00, 0xff, 0x96, 0xe8, 0xca, 0xf0, 0xcc, 0xaa, 0x1e, 0x80, 0x01, 0x6b]
+        );
+    )*};
+
+    (@tables $token:ident, $method:ident, $vector:ident, [$($table:literal),*]) => {$({
+        let mut rng = Rng::new(line!() as u64 ^ $table);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let b = $vector::from_array(rng.array()).to_array();
+            let c = $vector::from_array(rng.array()).to_array();
+
+            let expected: [_; $vector::LANES] = core::array::from_fn(|i| {
+                let select = |bit: u64, x: u64| if bit != 0 { x } else { !x };
+                let (a, b, c) = (a[i] as u64, b[i] as u64, c[i] as u64);
+
+                (0..8)
+                    .filter(|index| ($table >> index) & 1 != 0)
+                    .map(|index| select(index & 4, a) & select(index & 2, b) & select(index & 1, c))
+                    .fold(0, |x, y| x | y) as _
+            });
+            let actual = $token.$method::<$table>(
+                $vector::from_array(a),
+                $vector::from_array(b),
+                $vector::from_array(c),
+            );
+
+            assert_lanes(&actual.to_array(), &expected, (stringify!($method), $table, a, b, c));
+        }
+    })*};
+}
+
+/// Check blending two vectors with a k-mask.
+macro_rules! blends {
+    ($token:ident; $($method:ident: $vector:ident, $kmask:ident;)*) => {$({
+        let mut rng = Rng::new(line!() as u64);
+
+        for _ in 0..ROUNDS {
+            let a = $vector::from_array(rng.array()).to_array();
+            let b = $vector::from_array(rng.array()).to_array();
+            let k = $kmask::from_bits(rng.gen());
+            let expected: [_; $vector::LANES] =
+                core::array::from_fn(|i| if k.test(i as u32) { b[i] } else { a[i] });
+            let actual = $token.$method(k, $vector::from_array(a), $vector::from_array(b));
+
+            assert_lanes(&actual.to_array(), &expected, (stringify!($method), k, a, b));
+        }
+    })*};
+}
+
+pub(crate) use {
+    blends, compares, compress_expand, groups, lane_wise, lowest_lane, masked, packs, predicates,
+    shifts, ternary_logic, token_or_skip, unpacks,
+};
@@ -0,0 +1,298 @@
+use crate::Func;
+
+use super::{
+    Avx, Avx2, Avx512BW, Avx512DQ, Avx512F, Avx512VL, Avx512Vbmi, FeatureSet, Fma, Sse, Sse2, Sse3,
+    Sse41, Sse42, Ssse3, UnsupportedError, X86V2, X86V3, X86V4,
+};
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// A zero-sized proof that a set of CPU features is available.
+///
+/// This trait is sealed, and is only implemented by the tokens in this module.
+pub trait Token: Copy + Send + Sync + 'static + sealed::Sealed {
+    /// The target features this token enables, as used by `#[target_feature]`.
+    const FEATURES: &'static [&'static str];
+
+    /// Every feature this token requires, including those that cannot be enabled
+    /// with `#[target_feature]`.
+    const FEATURE_SET: FeatureSet;
+
+    /// Try to create a new token.
+    ///
+    /// Returns [`None`] if the required features are not available on the current CPU.
+    #[must_use]
+    fn try_new() -> Option<Self>;
+
+    /// Try to create a new token, explaining why if that is not possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnsupportedError`] if the required features are not supported
+    /// by either the current CPU or the operating system.
+    fn detect() -> Result<Self, UnsupportedError>;
+
+    /// Create a new token.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the required features are available.
+    #[must_use]
+    unsafe fn new_unchecked() -> Self;
+
+    /// Call a given closure with the required features enabled.
+    fn run<F: Func>(self, f: F) -> F::Output;
+}
+
+/// Implemented by tokens whose features imply the availability of `T`.
+///
+/// Every token implies itself, so generic code can take a `T: Has<Sse2>` bound
+/// and be handed an [`Sse2`](super::Sse2), an [`Avx2`](super::Avx2), or anything
+/// in between.
+pub trait Has<T: Token>: Token {
+    /// Get the token for a subset of this token's features.
+    #[inline(always)]
+    #[must_use]
+    fn downgrade(self) -> T {
+        // SAFETY: The features of `T` are implied by `Self`, which proves that
+        //         they're available.
+        unsafe { T::new_unchecked() }
+    }
+}
+
+impl<T: Token> Has<T> for T {
+    #[inline(always)]
+    fn downgrade(self) -> T {
+        self
+    }
+}
+
+/// Define a token proving that a set of features is available.
+///
+/// Features without a target feature name are only checked at runtime, as Rust
+/// cannot enable them.
+macro_rules! token {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($display:literal) {
+            $($feature:ident $(= $target_feature:literal)?),+
+            $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        $vis struct $name {
+            __private: (),
+        }
+
+        impl $name {
+            /// Every feature this token requires.
+            const FEATURE_SET: $crate::core_arch::x86::FeatureSet =
+                $crate::core_arch::x86::FeatureSet::from_features(&[
+                    $($crate::core_arch::x86::Feature::$feature),+
+                ]);
+
+            #[doc = ::core::concat!("Create a new [`", ::core::stringify!($name), "`] token.")]
+            ///
+            /// This is only available when all of the required target features
+            /// are enabled at compile time.
+            #[cfg(all($($(target_feature = $target_feature,)?)+))]
+            #[inline(always)]
+            #[must_use]
+            pub const fn new() -> $name {
+                // SAFETY: The required target features are enabled at compile time.
+                unsafe { $name::new_unchecked() }
+            }
+
+            #[doc = ::core::concat!("Create a new [`", ::core::stringify!($name), "`] token.")]
+            ///
+            /// # Safety
+            ///
+            #[doc = ::core::concat!("The caller must ensure that ", $display, " is available.")]
+            #[inline(always)]
+            #[must_use]
+            pub const unsafe fn new_unchecked() -> $name {
+                $name { __private: () }
+            }
+
+            #[doc = ::core::concat!("Try to create a new [`", ::core::stringify!($name), "`] token.")]
+            ///
+            #[doc = ::core::concat!(
+                "Returns [`None`] if ", $display, " is not available on the current CPU."
+            )]
+            #[inline]
+            #[must_use]
+            pub fn try_new() -> Option<$name> {
+                $name::detect().ok()
+            }
+
+            #[doc = ::core::concat!(
+                "Try to create a new [`", ::core::stringify!($name), "`] token, ",
+                "explaining why if that is not possible."
+            )]
+            ///
+            /// # Errors
+            ///
+            #[doc = ::core::concat!(
+                "Returns an [`UnsupportedError`](super::UnsupportedError) if ",
+                $display, " is not supported by either the current CPU or the operating system."
+            )]
+            #[inline]
+            pub fn detect() -> Result<$name, $crate::core_arch::x86::UnsupportedError> {
+                $crate::core_arch::x86::detect::check($name::FEATURE_SET)?;
+
+                // SAFETY: We just detected that the required features are available.
+                Ok(unsafe { $name::new_unchecked() })
+            }
+
+            #[doc = ::core::concat!("Call a given closure with ", $display, " enabled.")]
+            ///
+            /// # Safety
+            ///
+            /// Despite being marked as unsafe, this is actually safe as
+            #[doc = ::core::concat!(
+                "[`", ::core::stringify!($name), "`] proves that ", $display, " is available."
+            )]
+            ///
+            /// This however has to be marked as one cannot use the `target_feature`
+            /// attribute without it being an unsafe method.
+            #[inline]
+            $($(#[target_feature(enable = $target_feature)])?)+
+            pub unsafe fn execute<F: $crate::Func>(self, f: F) -> F::Output {
+                f.call()
+            }
+
+            #[doc = ::core::concat!("Call a given closure with ", $display, " enabled.")]
+            #[inline(always)]
+            pub fn run<F: $crate::Func>(self, f: F) -> F::Output {
+                // SAFETY: The token proves that the required features are available.
+                unsafe { self.execute(f) }
+            }
+        }
+
+        impl $crate::core_arch::x86::token::sealed::Sealed for $name {}
+
+        impl $crate::core_arch::x86::token::Token for $name {
+            const FEATURES: &'static [&'static str] = &[$($($target_feature,)?)+];
+
+            const FEATURE_SET: $crate::core_arch::x86::FeatureSet = $name::FEATURE_SET;
+
+            #[inline]
+            fn try_new() -> Option<$name> {
+                $name::try_new()
+            }
+
+            #[inline]
+            fn detect() -> Result<$name, $crate::core_arch::x86::UnsupportedError> {
+                $name::detect()
+            }
+
+            #[inline(always)]
+            unsafe fn new_unchecked() -> $name {
+                // SAFETY: The caller ensures that the required features are available.
+                unsafe { $name::new_unchecked() }
+            }
+
+            #[inline(always)]
+            fn run<F: $crate::Func>(self, f: F) -> F::Output {
+                $name::run(self, f)
+            }
+        }
+
+        #[cfg(all($($(target_feature = $target_feature,)?)+))]
+        impl ::core::default::Default for $name {
+            #[inline(always)]
+            fn default() -> $name {
+                $name::new()
+            }
+        }
+    };
+}
+
+pub(crate) use token;
+
+/// Declare that the features of each token on the left imply the features of
+/// every token on the right.
+///
+/// This fails to compile if the features of a token on the left don't include every
+/// feature of a token on the right.
+macro_rules! implies {
+    ($($name:ident => [$($lower:ident),+ $(,)?];)*) => {$($(
+        const _: () = assert!(
+            <$name as Token>::FEATURE_SET.is_superset(<$lower as Token>::FEATURE_SET),
+            concat!(stringify!($name), " doesn't have every feature of ", stringify!($lower)),
+        );
+
+        impl Has<$lower> for $name {}
+
+        impl From<$name> for $lower {
+            #[inline(always)]
+            fn from(token: $name) -> $lower {
+                Has::<$lower>::downgrade(token)
+            }
+        }
+    )+)*};
+}
+
+// The x86-64 microarchitecture levels imply the features they bundle.
+implies! {
+    X86V2 => [Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    X86V3 => [X86V2, Avx2, Fma, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    X86V4 => [
+        X86V3, X86V2, Avx512BW, Avx512DQ, Avx512VL, Avx512F, Avx2, Fma, Avx, Sse42, Sse41, Ssse3,
+        Sse3, Sse2, Sse,
+    ];
+}
+
+// SSE → SSE2 → SSE3 → SSSE3 → SSE4.1 → SSE4.2 → AVX → AVX2, with FMA branching
+// off of AVX, and every AVX-512 extension implying AVX-512F, AVX2 and FMA.
+implies! {
+    Sse2 => [Sse];
+    Sse3 => [Sse2, Sse];
+    Ssse3 => [Sse3, Sse2, Sse];
+    Sse41 => [Ssse3, Sse3, Sse2, Sse];
+    Sse42 => [Sse41, Ssse3, Sse3, Sse2, Sse];
+    Avx => [Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    Avx2 => [Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    Fma => [Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    Avx512F => [Avx2, Fma, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    Avx512BW => [Avx512F, Avx2, Fma, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    Avx512DQ => [Avx512F, Avx2, Fma, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    Avx512VL => [Avx512F, Avx2, Fma, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    Avx512Vbmi => [Avx512BW, Avx512F, Avx2, Fma, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that detecting `T` also detects `L`, which it claims to imply.
+    fn check<T: Has<L>, L: Token>() {
+        if let Some(token) = T::try_new() {
+            assert!(L::try_new().is_some());
+
+            let _: L = token.downgrade();
+        }
+    }
+
+    #[test]
+    fn detection_follows_implications() {
+        check::<Sse2, Sse>();
+        check::<Sse3, Sse2>();
+        check::<Ssse3, Sse3>();
+        check::<Sse41, Ssse3>();
+        check::<Sse42, Sse41>();
+        check::<Avx, Sse42>();
+        check::<Avx2, Avx>();
+        check::<Fma, Avx>();
+        check::<Avx512F, Avx2>();
+        check::<Avx512F, Fma>();
+        check::<Avx512BW, Avx512F>();
+        check::<Avx512DQ, Avx512F>();
+        check::<Avx512VL, Avx512F>();
+        check::<Avx512Vbmi, Avx512BW>();
+    }
+}
@@ -0,0 +1,5 @@
+//! Control over runtime feature detection.
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[doc(inline)]
+pub use crate::core_arch::x86::detect::{disabled_features, override_features};
@@ -0,0 +1,312 @@
+//! Runtime dispatch between copies of a function compiled for different tokens.
+
+/// Define functions that are compiled once per token, and dispatch to the best
+/// available copy at runtime.
+///
+/// Every function takes a `#[targets(...)]` attribute listing tokens, from the most
+/// to the least preferred. A copy of the body is compiled with the target features
+/// of each listed token, alongside a fallback copy with no extra features.
+///
+/// The first call detects which tokens are available, and stores a pointer to the
+/// selected copy in an atomic. Later calls are then a single indirect call, without
+/// any detection. This does not require `std`.
+///
+/// On architectures without tokens, only the fallback is compiled, and it is called
+/// directly.
+///
+/// # Limitations
+///
+/// The functions cannot be generic, take `self`, or use patterns for their
+/// arguments. The copies are nested in the generated function, so they cannot see
+/// its arguments except those that are passed to them.
+///
+/// # Examples
+///
+/// ```
+/// feat::dispatch! {
+///     /// Sum every value in a slice.
+///     #[targets(Avx512F, Avx2, Sse2)]
+///     pub fn sum(values: &[f32]) -> f32 {
+///         values.iter().sum()
+///     }
+/// }
+///
+/// assert_eq!(sum(&[1.0, 2.0, 3.0]), 6.0);
+/// ```
+#[macro_export]
+macro_rules! dispatch {
+    ($($rest:tt)*) => {
+        $crate::__dispatch_parse! { [] [] $($rest)* }
+    };
+}
+
+/// Split the `#[targets(...)]` attribute from the other attributes of a function.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dispatch_parse {
+    ([] []) => {};
+
+    (
+        [$($attr:tt)*] [$($tokens:tt)*]
+        #[targets($($token:ident),* $(,)?)]
+        $($rest:tt)*
+    ) => {
+        $crate::__dispatch_parse! { [$($attr)*] [$($tokens)* $($token)*] $($rest)* }
+    };
+
+    (
+        [$($attr:tt)*] [$($tokens:tt)*]
+        #[$meta:meta]
+        $($rest:tt)*
+    ) => {
+        $crate::__dispatch_parse! { [$($attr)* #[$meta]] [$($tokens)*] $($rest)* }
+    };
+
+    (
+        [$(#[$attr:meta])*] [$($token:ident)*]
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block
+        $($rest:tt)*
+    ) => {
+        $crate::__dispatch_fn! {
+            $(#[$attr])*
+            [$($token)*]
+            $vis fn $name($($arg: $ty),*) $(-> $ret)? $body
+        }
+
+        $crate::__dispatch_parse! { [] [] $($rest)* }
+    };
+}
+
+/// Generate a dispatching function.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dispatch_fn {
+    (
+        $(#[$attr:meta])*
+        [$($token:ident)*]
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)? $body:block
+    ) => {
+        $(#[$attr])*
+        #[inline]
+        $vis fn $name($($arg: $ty),*) $(-> $ret)? {
+            type __Fn = unsafe fn($($ty),*) $(-> $ret)?;
+
+            /// The selected copy, which is [`__resolve`] until the first call.
+            static __SELECTED: ::core::sync::atomic::AtomicPtr<()> =
+                ::core::sync::atomic::AtomicPtr::new(__resolve as __Fn as *mut ());
+
+            /// Select a copy, store it and call it.
+            ///
+            /// # Safety
+            ///
+            /// This is always safe to call.
+            unsafe fn __resolve($($arg: $ty),*) $(-> $ret)? {
+                let selected = __select();
+                __SELECTED.store(selected as *mut (), ::core::sync::atomic::Ordering::Relaxed);
+
+                // SAFETY: `__select` only returns copies whose features are available.
+                unsafe { selected($($arg),*) }
+            }
+
+            /// Select the first copy whose token is available.
+            #[cold]
+            fn __select() -> __Fn {
+                $crate::__dispatch_select! {
+                    [$($token)*]
+                    fn ($($arg: $ty),*) [$(-> $ret)?] $body
+                }
+            }
+
+            // SAFETY: `__SELECTED` only ever holds a `__Fn`.
+            let selected = unsafe {
+                ::core::mem::transmute::<*mut (), __Fn>(
+                    __SELECTED.load(::core::sync::atomic::Ordering::Relaxed),
+                )
+            };
+
+            // SAFETY: `__SELECTED` only ever holds `__resolve`, or a copy whose
+            //         features are available.
+            unsafe { selected($($arg),*) }
+        }
+    };
+}
+
+/// Generate the body of `__select`, trying each token in order.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dispatch_select {
+    ([] fn $args:tt [$($ret:tt)*] $body:block) => {{
+        fn __fallback $args $($ret)* $body
+
+        __fallback
+    }};
+
+    ([$token:ident $($rest:ident)*] fn $args:tt [$($ret:tt)*] $body:block) => {
+        if $crate::__private::x86::$token::try_new().is_some() {
+            $crate::__dispatch_target_feature! {
+                $token
+                unsafe fn __version $args $($ret)* $body
+            }
+
+            __version
+        } else {
+            $crate::__dispatch_select! { [$($rest)*] fn $args [$($ret)*] $body }
+        }
+    };
+}
+
+/// Generate a function that only has a fallback.
+#[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dispatch_fn {
+    (
+        $(#[$attr:meta])*
+        [$($token:ident)*]
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)? $body:block
+    ) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) $(-> $ret)? $body
+    };
+}
+
+/// Enable the target features of a token on a function.
+///
+/// These must match the `FEATURES` of each token, which is checked below.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dispatch_target_feature {
+    (@ $features:literal check) => {
+        $features
+    };
+    (@ $features:literal $item:item) => {
+        #[target_feature(enable = $features)]
+        $item
+    };
+
+    (Sse $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! { @ "sse" $($rest)* }
+    };
+    (Sse2 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! { @ "sse,sse2" $($rest)* }
+    };
+    (Sse3 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! { @ "sse,sse2,sse3" $($rest)* }
+    };
+    (Ssse3 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! { @ "sse,sse2,sse3,ssse3" $($rest)* }
+    };
+    (Sse41 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! { @ "sse,sse2,sse3,ssse3,sse4.1" $($rest)* }
+    };
+    (Sse42 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! { @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2" $($rest)* }
+    };
+    (Avx $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! { @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx" $($rest)* }
+    };
+    (Avx2 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,avx2" $($rest)*
+        }
+    };
+    (Fma $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,fma" $($rest)*
+        }
+    };
+    (Avx512F $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,avx2,fma,f16c,avx512f" $($rest)*
+        }
+    };
+    (Avx512BW $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,avx2,fma,f16c,avx512f,avx512bw" $($rest)*
+        }
+    };
+    (Avx512DQ $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,avx2,fma,f16c,avx512f,avx512dq" $($rest)*
+        }
+    };
+    (Avx512VL $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,avx2,fma,f16c,avx512f,avx512vl" $($rest)*
+        }
+    };
+    (Avx512Vbmi $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,avx2,fma,f16c,avx512f,avx512bw,avx512vbmi"
+            $($rest)*
+        }
+    };
+    (X86V2 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,popcnt,cmpxchg16b" $($rest)*
+        }
+    };
+    (X86V3 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,popcnt,cmpxchg16b,avx,avx2,bmi1,bmi2,f16c,fma,\
+               lzcnt,movbe,xsave" $($rest)*
+        }
+    };
+    (X86V4 $($rest:tt)*) => {
+        $crate::__dispatch_target_feature! {
+            @ "sse,sse2,sse3,ssse3,sse4.1,sse4.2,popcnt,cmpxchg16b,avx,avx2,bmi1,bmi2,f16c,fma,\
+               lzcnt,movbe,xsave,avx512f,avx512bw,avx512cd,avx512dq,avx512vl" $($rest)*
+        }
+    };
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod check {
+    use crate::{
+        core_arch::x86::{
+            Avx, Avx2, Avx512BW, Avx512DQ, Avx512F, Avx512VL, Avx512Vbmi, Fma, Sse, Sse2, Sse3,
+            Sse41, Sse42, Ssse3, Token, X86V2, X86V3, X86V4,
+        },
+        util::string,
+    };
+
+    /// Check whether `list` is `features` joined by commas.
+    const fn matches(mut features: &[&str], mut list: &str) -> bool {
+        while let [feature, rest @ ..] = features {
+            let (head, tail) = match string::split(",", list) {
+                Some(split) => split,
+                None => (list, ""),
+            };
+
+            if string::is_ne(feature, head) {
+                return false;
+            }
+
+            features = rest;
+            list = tail;
+        }
+
+        list.is_empty()
+    }
+
+    macro_rules! check {
+        ($($token:ident),+ $(,)?) => {$(
+            const _: () = assert!(
+                matches($token::FEATURES, crate::__dispatch_target_feature!($token check)),
+                ::core::concat!(
+                    "the dispatch target features of `",
+                    ::core::stringify!($token),
+                    "` are out of date"
+                ),
+            );
+        )+};
+    }
+
+    check! {
+        Sse, Sse2, Sse3, Ssse3, Sse41, Sse42, Avx, Avx2, Fma, Avx512F, Avx512BW, Avx512DQ,
+        Avx512VL, Avx512Vbmi, X86V2, X86V3, X86V4,
+    }
+}
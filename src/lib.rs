@@ -1,14 +1,159 @@
 #![cfg_attr(not(test), no_std)]
 #![allow(non_camel_case_types)]
 
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
 /// -Module providing vendor-ish specific intrinsics.
 mod core_arch;
+mod dispatch;
 mod macros;
 mod util;
 
 pub mod arch;
+pub mod detect;
 pub mod types;
 
+/// Compile copies of a function for several sets of target features, and call the
+/// best one available at runtime.
+///
+/// The arguments are the targets to specialize for, from the most to the least
+/// preferred:
+///
+/// - `sse`, `sse2`, `sse3`, `ssse3`, `sse41`, `sse42`, `avx`, `avx2`, `fma`,
+///   `avx512f`, `avx512bw`, `avx512dq`, `avx512vl` and `avx512vbmi`, for the token of the
+///   same name.
+/// - `x86_64_v2`, `x86_64_v3` and `x86_64_v4`, for the microarchitecture levels.
+/// - `fallback`, for a copy without any extra features, which must come last.
+///
+/// Each copy has the token of its target injected as a parameter named `token`.
+/// The fallback is given `()` instead. If no target is available and there is no
+/// fallback, calling the function panics.
+///
+/// This works on free functions and on inherent methods. The copies of methods
+/// taking `self` are hidden methods next to it, while the copies of other functions
+/// are nested within them, so those cannot mention `Self`.
+///
+/// The expansion refers to `feat` as `::feat`. If it is renamed or re-exported,
+/// give the path to it with an extra `crate = path` argument, such as
+/// `#[specialize(crate = my_feat, avx2, fallback)]`.
+///
+/// # Examples
+///
+/// ```
+/// #[feat::specialize(avx2, sse2, fallback)]
+/// fn sum(values: &[f32]) -> f32 {
+///     values.iter().sum()
+/// }
+///
+/// assert_eq!(sum(&[1.0, 2.0, 3.0]), 6.0);
+/// ```
+///
+/// Methods get the token after their receiver:
+///
+/// ```
+/// use core::any::type_name_of_val;
+///
+/// struct Counter(u32);
+///
+/// impl Counter {
+///     /// Add `n` to the counter, and get the name of the token that was used.
+///     #[feat::specialize(avx2, sse2, fallback)]
+///     fn add(&mut self, n: u32) -> &'static str {
+///         self.0 += n;
+///         type_name_of_val(&token)
+///     }
+/// }
+///
+/// let mut counter = Counter(1);
+/// let name = counter.add(2);
+///
+/// assert_eq!(counter.0, 3);
+///
+/// #[cfg(target_arch = "x86_64")]
+/// {
+///     use feat::arch::x86_64::{Avx2, Sse2};
+///
+///     let expected = if Avx2::try_new().is_some() {
+///         "Avx2"
+///     } else if Sse2::try_new().is_some() {
+///         "Sse2"
+///     } else {
+///         "()"
+///     };
+///
+///     assert!(name.ends_with(expected), "{name}");
+/// }
+/// ```
+///
+/// An `unsafe fn` stays unsafe to call, and its copies keep relying on the contract of
+/// its caller:
+///
+/// ```
+/// /// Read a value without checking the pointer.
+/// ///
+/// /// # Safety
+/// ///
+/// /// `ptr` must be valid for reads.
+/// #[feat::specialize(avx2, fallback)]
+/// unsafe fn read(ptr: *const u32) -> u32 {
+///     unsafe { ptr.read() }
+/// }
+///
+/// let value = 7;
+///
+/// // SAFETY: The pointer comes from a reference.
+/// assert_eq!(unsafe { read(&value) }, 7);
+/// ```
+///
+/// ```compile_fail
+/// #[feat::specialize(avx2, fallback)]
+/// unsafe fn read(ptr: *const u32) -> u32 {
+///     unsafe { ptr.read() }
+/// }
+///
+/// let value = 7;
+/// read(&value);
+/// ```
+///
+/// Through a re-export of `feat`:
+///
+/// ```
+/// mod reexport {
+///     pub(crate) use feat as inner;
+/// }
+///
+/// #[feat::specialize(crate = reexport::inner, avx2, fallback)]
+/// fn double(x: u32) -> u32 {
+///     x * 2
+/// }
+///
+/// assert_eq!(double(21), 42);
+/// ```
+///
+/// Unknown targets are rejected:
+///
+/// ```compile_fail
+/// #[feat::specialize(avx3, fallback)]
+/// fn f() {}
+/// ```
+///
+/// And so is a fallback that isn't last:
+///
+/// ```compile_fail
+/// #[feat::specialize(fallback, avx2)]
+/// fn f() {}
+/// ```
+#[cfg(feature = "macros")]
+pub use feat_macros::specialize;
+
+/// Items used by the exported macros, which are not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    pub use crate::core_arch::x86;
+}
+
 pub trait Func {
     type Output;
 
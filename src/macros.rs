@@ -62,6 +62,44 @@ macro_rules! vector_base {
         };
 
         impl $name {
+            /// The number of lanes in this vector.
+            pub const LANES: usize = $lanes;
+
+            /// The size of this vector in bits.
+            pub const BITS: usize = $bits;
+
+            /// A vector with every lane set to zero.
+            pub const ZERO: $name = $name::splat(0 as $scalar);
+
+            /// A vector with every lane set to one.
+            pub const ONE: $name = $name::splat(1 as $scalar);
+
+            /// A vector with every lane set to the smallest value of the scalar.
+            pub const MIN: $name = $name::splat($scalar::MIN);
+
+            /// A vector with every lane set to the largest value of the scalar.
+            pub const MAX: $name = $name::splat($scalar::MAX);
+
+            /// Create a new vector with every lane set to `value`.
+            ///
+            /// As this is a `const fn`, it doesn't go through a backend, and choosing
+            /// the instructions is left to the compiler.
+            #[inline]
+            #[must_use]
+            pub const fn splat(value: $scalar) -> $name {
+                $name::from_array([value; $lanes])
+            }
+
+            /// Create a new vector by calling `f` with the index of every lane.
+            #[inline]
+            #[must_use]
+            pub fn from_fn<F>(f: F) -> $name
+            where
+                F: FnMut(usize) -> $scalar,
+            {
+                $name::from_array(::core::array::from_fn(f))
+            }
+
             /// Create a new vector from an array of scalars.
             #[inline]
             #[must_use]
@@ -181,6 +219,58 @@ macro_rules! vector_base {
             pub const fn as_slice_mut(&mut self) -> &mut [$scalar] {
                 self.as_array_mut()
             }
+
+            /// Get the lane at index `I`.
+            ///
+            /// This fails to compile if `I` is out of bounds.
+            #[inline]
+            #[must_use]
+            pub const fn extract<const I: usize>(self) -> $scalar {
+                const {
+                    assert!(I < $lanes, "lane index is out of bounds");
+                }
+
+                self.as_array()[I]
+            }
+
+            /// Get a copy of this vector with the lane at index `I` set to `value`.
+            ///
+            /// This fails to compile if `I` is out of bounds.
+            #[inline]
+            #[must_use]
+            pub const fn replace<const I: usize>(self, value: $scalar) -> $name {
+                const {
+                    assert!(I < $lanes, "lane index is out of bounds");
+                }
+
+                let mut array = self.to_array();
+                array[I] = value;
+
+                $name::from_array(array)
+            }
+
+            /// Get the lane at `index`, or `None` if it is out of bounds.
+            #[inline]
+            #[must_use]
+            pub const fn get(self, index: usize) -> Option<$scalar> {
+                if index < $lanes {
+                    Some(self.as_array()[index])
+                } else {
+                    None
+                }
+            }
+
+            /// Set the lane at `index` to `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            pub const fn set(&mut self, index: usize, value: $scalar) {
+                assert!(index < $lanes, "lane index is out of bounds");
+
+                self.as_array_mut()[index] = value;
+            }
         }
 
         impl ::core::borrow::Borrow<[$scalar; $lanes]> for $name {
@@ -334,6 +424,13 @@ macro_rules! vector_base {
             }
         }
 
+        impl Default for $name {
+            #[inline]
+            fn default() -> $name {
+                $name::ZERO
+            }
+        }
+
         impl ::core::fmt::Debug for $name {
             #[inline]
             fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -470,6 +567,100 @@ macro_rules! vectors {
 
 pub(crate) use vectors;
 
+/// Implement [`Vector`](crate::types::vector::Vector) for a chain of vectors of the
+/// same scalar, each twice the length of the previous one, and map them from
+/// [`Simd`](crate::types::vector::Simd).
+macro_rules! vector_impls {
+    ($scalar:ident: $first:ident $(, $rest:ident)* $(,)?) => {
+        impl $crate::types::vector::sealed::SealedElement for $scalar {}
+
+        $crate::macros::vector_impls!(@impl $scalar; []; $first $(, $rest)*);
+    };
+
+    (@impl $scalar:ident; [$($half:ident)?]; $name:ident, $double:ident $(, $rest:ident)*) => {
+        $crate::macros::vector_impls!(@trait $scalar; [$($half)?]; $name; $double);
+        $crate::macros::vector_impls!(@impl $scalar; [$name]; $double $(, $rest)*);
+    };
+
+    (@impl $scalar:ident; [$($half:ident)?]; $name:ident) => {
+        $crate::macros::vector_impls!(@trait $scalar; [$($half)?]; $name; ());
+    };
+
+    (@half) => { () };
+    (@half $half:ident) => { $half };
+
+    // A single lane has no halves, and `()` is not a vector, so these can't be called.
+    (@halves $name:ident;) => {
+        #[inline(always)]
+        fn from_halves(_: (), _: ()) -> $name {
+            unreachable!("a single lane has no halves")
+        }
+
+        #[inline(always)]
+        fn to_halves(self) -> [(); 2] {
+            unreachable!("a single lane has no halves")
+        }
+    };
+    (@halves $name:ident; $half:ident) => {
+        #[inline(always)]
+        fn from_halves(a: $half, b: $half) -> $name {
+            $name::from_halves(a, b)
+        }
+
+        #[inline(always)]
+        fn to_halves(self) -> [$half; 2] {
+            $name::to_halves(self)
+        }
+    };
+
+    (@trait $scalar:ident; [$($half:ident)?]; $name:ident; $double:ty) => {
+        impl $crate::types::vector::sealed::Sealed for $name {}
+
+        impl $crate::types::vector::Vector for $name {
+            type Scalar = $scalar;
+            type Array = [$scalar; $name::LANES];
+            type Half = $crate::macros::vector_impls!(@half $($half)?);
+            type Double = $double;
+
+            const LANES: usize = $name::LANES;
+            const BITS: usize = $name::BITS;
+
+            #[inline(always)]
+            fn from_array(array: Self::Array) -> $name {
+                $name::from_array(array)
+            }
+
+            #[inline(always)]
+            fn to_array(self) -> Self::Array {
+                $name::to_array(self)
+            }
+
+            #[inline(always)]
+            fn from_slice(slice: &[$scalar]) -> $name {
+                $name::from_slice(slice)
+            }
+
+            #[inline(always)]
+            fn as_slice(&self) -> &[$scalar] {
+                $name::as_slice(self)
+            }
+
+            #[inline(always)]
+            fn as_slice_mut(&mut self) -> &mut [$scalar] {
+                $name::as_slice_mut(self)
+            }
+
+            $crate::macros::vector_impls!(@halves $name; $($half)?);
+        }
+
+        impl $crate::types::vector::SimdElement<{ $name::LANES }> for $scalar {
+            type Vector = $name;
+        }
+    };
+}
+
+pub(crate) use vector_impls;
+
 macro_rules! exports {
     (
         $(
@@ -496,7 +687,17 @@ macro_rules! exports {
 pub(crate) use exports;
 
 macro_rules! mask_docs {
-    () => {};
+    (
+        [$lane:ident; $lanes:tt]: $bits:tt
+        $(,)?
+    ) => {
+        ::core::concat!(
+            $crate::macros::vector_docs!(@consonant $bits), " ",
+            ::core::stringify!($bits), "-bit mask containing ",
+            ::core::stringify!($lanes), " [`", ::core::stringify!($lane), "`] lane",
+            $crate::macros::vector_docs!(@plurality $lanes), ".",
+        )
+    };
 }
 
 pub(crate) use mask_docs;
@@ -512,19 +713,566 @@ macro_rules! mask_type {
         }
     ) => {
         $(#[$meta])*
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
         #[repr($repr)]
         $vis enum $name {
+            /// Every bit of the lane is set.
             All = -1,
+            /// No bit of the lane is set.
+            #[default]
             None = 0,
         }
+
+        const _: () = {
+            assert!(
+                size_of::<$name>() * 8 == $bits,
+                concat!(
+                    "The bitwidth of ", stringify!($name), " is not ", stringify!($bits), " bits.",
+                ),
+            );
+        };
+
+        impl $name {
+            /// Create a lane that is set if `value` is `true`.
+            #[inline]
+            #[must_use]
+            pub const fn new(value: bool) -> $name {
+                if value {
+                    $name::All
+                } else {
+                    $name::None
+                }
+            }
+
+            /// Check whether this lane is set.
+            #[inline]
+            #[must_use]
+            pub const fn to_bool(self) -> bool {
+                matches!(self, $name::All)
+            }
+        }
+
+        impl From<bool> for $name {
+            #[inline]
+            fn from(value: bool) -> $name {
+                $name::new(value)
+            }
+        }
+
+        impl From<$name> for bool {
+            #[inline]
+            fn from(lane: $name) -> bool {
+                lane.to_bool()
+            }
+        }
     };
 }
 
 pub(crate) use mask_type;
 
+macro_rules! mask {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            arr: [$lane:ident; $lanes:tt],
+            bits: $bits:tt,
+            $(half: $half:ident,)?
+            int: $int:ident,
+            select: [$($select:ident),* $(,)?],
+            bitmask: $bitmask:ident
+            $(
+                = $bitmask_feature:literal
+                $bitmask_token:ident::$bitmask_method:ident($bitmask_arg:ident)
+            )?,
+            $(blend: $blend_feature:literal $blend_token:ident::$blend_method:ident($bytes:ident),)?
+            $(
+                kmask: $kmask:ident
+                $(
+                    = $kmask_feature:literal
+                    $kmask_token:ident::{$to_kmask:ident, $from_kmask:ident}
+                )?,
+            )?
+        }
+    ) => {
+        #[derive(Clone, Copy)]
+        #[repr(transparent)]
+        #[doc = $crate::macros::mask_docs!([$lane; $lanes]: $bits)]
+        ///
+        /// Every lane is either all ones or all zeros, which is the layout used by
+        /// the comparisons and blends of SSE and AVX.
+        $(#[$meta])*
+        $vis struct $name {
+            pub(crate) int: $int,
+        }
+
+        // Layout checks
+        const _: () = {
+            assert!(
+                size_of::<$name>() == size_of::<[$lane; $lanes]>(),
+                "mask does not have the same size as its corresponding array"
+            );
+
+            assert!(
+                size_of::<$name>() * 8 == $bits,
+                concat!(
+                    "The bitwidth of ", stringify!($name), " is not ", stringify!($bits), " bits.",
+                ),
+            );
+        };
+
+        impl $name {
+            /// Create a mask with every lane set to `value`.
+            #[inline]
+            #[must_use]
+            pub const fn splat(value: bool) -> $name {
+                $name::from_array([$lane::new(value); $lanes])
+            }
+
+            /// Create a new mask from an array of lanes.
+            #[inline]
+            #[must_use]
+            pub const fn from_array(array: [$lane; $lanes]) -> $name {
+                // SAFETY: We know they have the same in-memory representation.
+                unsafe { ::core::mem::transmute(array) }
+            }
+
+            /// Get an array of lanes from a mask.
+            #[inline]
+            #[must_use]
+            pub const fn to_array(self) -> [$lane; $lanes] {
+                // SAFETY: We know they have the same in-memory representation, and
+                //         every lane of the mask is a valid lane.
+                unsafe { ::core::mem::transmute(self) }
+            }
+
+            /// Create a new mask from an array of booleans.
+            #[inline]
+            #[must_use]
+            pub const fn from_bools(bools: [bool; $lanes]) -> $name {
+                let mut array = [$lane::None; $lanes];
+                let mut i = 0;
+
+                while i < $lanes {
+                    array[i] = $lane::new(bools[i]);
+                    i += 1;
+                }
+
+                $name::from_array(array)
+            }
+
+            /// Get an array of booleans from a mask.
+            #[inline]
+            #[must_use]
+            pub const fn to_bools(self) -> [bool; $lanes] {
+                let array = self.to_array();
+                let mut bools = [false; $lanes];
+                let mut i = 0;
+
+                while i < $lanes {
+                    bools[i] = array[i].to_bool();
+                    i += 1;
+                }
+
+                bools
+            }
+
+            /// Create a mask from an integer vector without checking its lanes.
+            ///
+            /// # Safety
+            ///
+            /// Every lane of `int` must be either `0` or `-1`.
+            #[inline]
+            #[must_use]
+            pub const unsafe fn from_int_unchecked(int: $int) -> $name {
+                $name { int }
+            }
+
+            /// Get the integer vector with `-1` in every set lane, and `0` elsewhere.
+            #[inline]
+            #[must_use]
+            pub const fn to_int(self) -> $int {
+                self.int
+            }
+
+            $(
+                /// Create a new mask by joining two halves of a mask.
+                #[inline]
+                #[must_use]
+                pub const fn from_halves(a: $half, b: $half) -> $name {
+                    // SAFETY: We know they have the same in-memory representation.
+                    unsafe { ::core::mem::transmute([a, b]) }
+                }
+
+                /// Split this mask into an array of halves.
+                #[inline]
+                #[must_use]
+                pub const fn to_halves(self) -> [$half; 2] {
+                    // SAFETY: We know they have the same in-memory representation.
+                    unsafe { ::core::mem::transmute(self) }
+                }
+            )?
+
+            /// Check whether the given lane is set.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lane` is out of bounds.
+            #[inline]
+            #[must_use]
+            pub const fn test(self, lane: usize) -> bool {
+                assert!(lane < $lanes, "`lane` is out of bounds");
+
+                self.to_array()[lane].to_bool()
+            }
+
+            /// Get a copy of this mask with the given lane set to `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lane` is out of bounds.
+            #[inline]
+            #[must_use]
+            pub const fn with(self, lane: usize, value: bool) -> $name {
+                assert!(lane < $lanes, "`lane` is out of bounds");
+
+                let mut array = self.to_array();
+                array[lane] = $lane::new(value);
+
+                $name::from_array(array)
+            }
+
+            /// Get a bitmask with bit `i` set if lane `i` is set.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn to_bitmask(self) -> $bitmask {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $bitmask_feature,
+                    ))]
+                    // SAFETY: Both vectors have the same size, and any bit pattern is
+                    //         valid for the argument.
+                    return $crate::core_arch::x86::$bitmask_token::new()
+                        .$bitmask_method(unsafe {
+                            $crate::util::mem::transmute_unchecked::<$int, $bitmask_arg>(self.int)
+                        })
+                        .into();
+                )?
+
+                let mut bitmask: $bitmask = 0;
+
+                for (lane, value) in self.to_bools().into_iter().enumerate() {
+                    bitmask |= (value as $bitmask) << lane;
+                }
+
+                bitmask
+            }
+
+            /// Create a mask with lane `i` set if bit `i` of `bitmask` is set.
+            ///
+            /// Bits beyond the number of lanes are ignored.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn from_bitmask(bitmask: $bitmask) -> $name {
+                $($(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $kmask_feature,
+                    ))]
+                    return $name::from_kmask($crate::core_arch::x86::$kmask::from_bits(bitmask));
+                )?)?
+
+                let mut bools = [false; $lanes];
+
+                for (lane, value) in bools.iter_mut().enumerate() {
+                    *value = (bitmask >> lane) & 1 != 0;
+                }
+
+                $name::from_bools(bools)
+            }
+
+            /// Iterate over the indices of the set lanes, from the lowest to the
+            /// highest.
+            #[inline]
+            #[must_use]
+            pub fn set_lanes(self) -> $crate::types::mask::SetBits {
+                $crate::types::mask::SetBits::new(self.to_bitmask().into())
+            }
+
+            /// Check whether any lane is set.
+            #[inline]
+            #[must_use]
+            pub fn any(self) -> bool {
+                self.int.to_array().iter().any(|&lane| lane != 0)
+            }
+
+            /// Check whether every lane is set.
+            #[inline]
+            #[must_use]
+            pub fn all(self) -> bool {
+                self.int.to_array().iter().all(|&lane| lane != 0)
+            }
+
+            /// Check whether no lane is set.
+            #[inline]
+            #[must_use]
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+
+            /// Compute the lanes that are set in `self`, but not in `rhs`.
+            #[inline]
+            #[must_use]
+            pub fn and_not(self, rhs: $name) -> $name {
+                $name { int: self.int.and_not(rhs.int) }
+            }
+
+            /// Choose each lane from `if_true` where it is set in this mask, and from
+            /// `if_false` otherwise.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn select<V>(self, if_true: V, if_false: V) -> V
+            where
+                V: $crate::types::mask::Select<$name>,
+            {
+                use $crate::util::mem::transmute_unchecked;
+
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $blend_feature,
+                    ))]
+                    // SAFETY: Every vector that can be selected has the same size as this
+                    //         mask, and any bit pattern is valid for the byte vectors.
+                    //         The result holds bytes of `if_true` or `if_false`, so it is
+                    //         valid for `V`.
+                    return unsafe {
+                        transmute_unchecked::<$bytes, V>(
+                            $crate::core_arch::x86::$blend_token::new().$blend_method(
+                                transmute_unchecked(if_false),
+                                transmute_unchecked(if_true),
+                                transmute_unchecked(self),
+                            ),
+                        )
+                    };
+                )?
+
+                // SAFETY: Every vector that can be selected has the same size as this
+                //         mask, and any bit pattern is valid for the integer vector.
+                //         The result holds bits of `if_true` or `if_false`, so it is
+                //         valid for `V`.
+                unsafe {
+                    let if_true = transmute_unchecked::<V, $int>(if_true);
+                    let if_false = transmute_unchecked::<V, $int>(if_false);
+
+                    let selected = (if_true & self.int) | if_false.and_not(self.int);
+
+                    transmute_unchecked::<$int, V>(selected)
+                }
+            }
+        }
+
+        impl $crate::types::mask::Select<$name> for $int {}
+        $(impl $crate::types::mask::Select<$name> for $select {})*
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> $name {
+                $name::splat(false)
+            }
+        }
+
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                self.to_array() == other.to_array()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl ::core::hash::Hash for $name {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.to_array().hash(state);
+            }
+        }
+
+        impl From<[bool; $lanes]> for $name {
+            #[inline]
+            fn from(bools: [bool; $lanes]) -> $name {
+                $name::from_bools(bools)
+            }
+        }
+
+        impl From<$name> for [bool; $lanes] {
+            #[inline]
+            fn from(mask: $name) -> [bool; $lanes] {
+                mask.to_bools()
+            }
+        }
+
+        impl From<[$lane; $lanes]> for $name {
+            #[inline]
+            fn from(array: [$lane; $lanes]) -> $name {
+                $name::from_array(array)
+            }
+        }
+
+        impl From<$name> for [$lane; $lanes] {
+            #[inline]
+            fn from(mask: $name) -> [$lane; $lanes] {
+                mask.to_array()
+            }
+        }
+
+        impl From<$name> for $int {
+            #[inline]
+            fn from(mask: $name) -> $int {
+                mask.to_int()
+            }
+        }
+
+        impl ::core::ops::BitAnd for $name {
+            type Output = $name;
+
+            #[inline]
+            fn bitand(self, rhs: $name) -> $name {
+                $name { int: self.int & rhs.int }
+            }
+        }
+
+        impl ::core::ops::BitOr for $name {
+            type Output = $name;
+
+            #[inline]
+            fn bitor(self, rhs: $name) -> $name {
+                $name { int: self.int | rhs.int }
+            }
+        }
+
+        impl ::core::ops::BitXor for $name {
+            type Output = $name;
+
+            #[inline]
+            fn bitxor(self, rhs: $name) -> $name {
+                $name { int: self.int ^ rhs.int }
+            }
+        }
+
+        impl ::core::ops::Not for $name {
+            type Output = $name;
+
+            #[inline]
+            fn not(self) -> $name {
+                $name { int: !self.int }
+            }
+        }
+
+        impl ::core::ops::BitAndAssign for $name {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: $name) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl ::core::ops::BitOrAssign for $name {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: $name) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl ::core::ops::BitXorAssign for $name {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: $name) {
+                *self = *self ^ rhs;
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                let mut debug = &mut f.debug_tuple("");
+
+                for value in self.to_bools() {
+                    debug = debug.field(&value);
+                }
+
+                debug.finish()
+            }
+        }
+
+        $(
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            impl $name {
+                /// Get the AVX-512 mask with the bit for each set lane set.
+                #[inline]
+                #[must_use]
+                #[allow(unreachable_code)]
+                pub fn to_kmask(self) -> $crate::core_arch::x86::$kmask {
+                    $(
+                        #[cfg(target_feature = $kmask_feature)]
+                        return $crate::core_arch::x86::$kmask_token::new().$to_kmask(self.int);
+                    )?
+
+                    $crate::core_arch::x86::$kmask::from_bits(self.to_bitmask())
+                }
+
+                /// Create a mask with the lanes set whose bit is set in an AVX-512 mask.
+                #[inline]
+                #[must_use]
+                #[allow(unreachable_code)]
+                pub fn from_kmask(kmask: $crate::core_arch::x86::$kmask) -> $name {
+                    $(
+                        #[cfg(target_feature = $kmask_feature)]
+                        return $name {
+                            int: $crate::core_arch::x86::$kmask_token::new().$from_kmask(kmask),
+                        };
+                    )?
+
+                    $name::from_bitmask(kmask.to_bits())
+                }
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            impl From<$crate::core_arch::x86::$kmask> for $name {
+                #[inline]
+                fn from(kmask: $crate::core_arch::x86::$kmask) -> $name {
+                    $name::from_kmask(kmask)
+                }
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            impl From<$name> for $crate::core_arch::x86::$kmask {
+                #[inline]
+                fn from(mask: $name) -> $crate::core_arch::x86::$kmask {
+                    mask.to_kmask()
+                }
+            }
+        )?
+    };
+}
+
+pub(crate) use mask;
+
 macro_rules! masks {
-    () => {};
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident { $($body:tt)* }
+    )*) => {
+        $(
+            $crate::macros::mask!(
+                $(#[$meta])*
+                $vis struct $name {
+                    $($body)*
+                }
+            );
+        )*
+    };
 }
 
 pub(crate) use masks;
@@ -2,3 +2,6 @@
 
 /// Module containing vector types.
 pub mod vector;
+
+/// Module containing mask types.
+pub mod mask;
@@ -0,0 +1,263 @@
+//! Module for SIMD mask types.
+//!
+//! A mask has one lane per lane of the vectors it applies to, and each lane is
+//! either all ones or all zeros.
+
+use core::iter::FusedIterator;
+
+use super::vector::{sealed, Vector};
+
+/// Module for masks of 16-bit lanes.
+pub mod mask16;
+/// Module for masks of 32-bit lanes.
+pub mod mask32;
+/// Module for masks of 64-bit lanes.
+pub mod mask64;
+/// Module for masks of 8-bit lanes.
+pub mod mask8;
+
+pub(crate) mod exports {
+    #![allow(unused_imports)]
+
+    #[doc(inline)]
+    pub use super::mask16::*;
+    #[doc(inline)]
+    pub use super::mask32::*;
+    #[doc(inline)]
+    pub use super::mask64::*;
+    #[doc(inline)]
+    pub use super::mask8::*;
+}
+
+#[doc(inline)]
+pub use exports::*;
+
+/// A vector whose lanes can be chosen by the mask `M`.
+///
+/// This is implemented by the signed integer vector backing `M`, and by the other
+/// vectors with the same number and size of lanes, so that they can be used with
+/// `select` on masks such as [`m32x4::select`], which takes [`i32x4`], [`u32x4`] and
+/// [`f32x4`].
+///
+/// This trait is sealed, and cannot be implemented outside of this crate.
+pub trait Select<M>: Vector + sealed::Sealed {}
+
+/// An iterator over the indices of the set bits of a bitmask, from the lowest to
+/// the highest.
+///
+/// This is returned by `set_lanes` on masks, such as [`m8x16::set_lanes`], and can
+/// be created from any bitmask with [`SetBits::new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SetBits {
+    bits: u64,
+}
+
+impl SetBits {
+    /// Create an iterator over the indices of the set bits of `bits`.
+    #[inline]
+    #[must_use]
+    pub const fn new(bits: u64) -> SetBits {
+        SetBits { bits }
+    }
+
+    /// Get the bits that have not been iterated over yet.
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u64 {
+        self.bits
+    }
+}
+
+impl Iterator for SetBits {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let index = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+
+        Some(index as usize)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<usize> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for SetBits {
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let index = u64::BITS - 1 - self.bits.leading_zeros();
+        self.bits &= !(1 << index);
+
+        Some(index as usize)
+    }
+}
+
+impl ExactSizeIterator for SetBits {
+    #[inline]
+    fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+}
+
+impl FusedIterator for SetBits {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::vector::*,
+        util::test::{assert_lanes, Rng, ROUNDS},
+    };
+
+    use super::*;
+
+    #[test]
+    fn select() {
+        macro_rules! check {
+            ($($mask:ident: $($vector:ident),+;)+) => {$($({
+                let mut rng = Rng::new(line!() as u64);
+
+                for _ in 0..ROUNDS {
+                    let bools = rng.array();
+                    let mask = $mask::from_bools(bools);
+                    let a = $vector::from_array(rng.array());
+                    let b = $vector::from_array(rng.array());
+
+                    let expected: [_; $vector::LANES] = core::array::from_fn(|i| {
+                        if bools[i] { a.to_array()[i] } else { b.to_array()[i] }
+                    });
+
+                    assert_lanes(
+                        &mask.select(a, b).to_array(),
+                        &expected,
+                        (stringify!($mask), stringify!($vector), bools),
+                    );
+                }
+            })+)+};
+        }
+
+        // `m32x4` and `m32x8` blend when SSE4.1 and AVX2 are enabled at compile time,
+        // while the others always take the portable path.
+        check! {
+            m32x4: i32x4, u32x4, f32x4;
+            m32x8: i32x8, u32x8, f32x8;
+            m8x16: i8x16, u8x16;
+            m16x8: i16x8, u16x8;
+            m32x1: f32x1;
+            m64x2: i64x2, f64x2;
+            m8x64: u8x64;
+            m64x8: u64x8, f64x8;
+        }
+    }
+
+    #[test]
+    fn predicates() {
+        macro_rules! check {
+            ($($mask:ident),+) => {$({
+                let mut rng = Rng::new(line!() as u64);
+
+                for _ in 0..ROUNDS {
+                    let a = rng.array();
+                    let b = rng.array();
+                    let mask = $mask::from_bools(a);
+                    let other = $mask::from_bools(b);
+
+                    assert_eq!(mask.any(), a.iter().any(|&lane| lane), "{a:?}");
+                    assert_eq!(mask.all(), a.iter().all(|&lane| lane), "{a:?}");
+                    assert_eq!(mask.none(), !a.iter().any(|&lane| lane), "{a:?}");
+
+                    let mut expected = a;
+                    for (lane, &b) in expected.iter_mut().zip(&b) {
+                        *lane &= !b;
+                    }
+
+                    assert_lanes(
+                        &mask.and_not(other).to_bools(),
+                        &expected,
+                        (stringify!($mask), a, b),
+                    );
+                }
+
+                assert!($mask::splat(true).all() && $mask::splat(true).any());
+                assert!($mask::splat(false).none() && !$mask::splat(false).any());
+            })+};
+        }
+
+        check!(m8x1, m8x16, m8x64, m16x8, m16x32, m32x4, m32x8, m64x2, m64x8);
+    }
+
+    #[test]
+    fn bools() {
+        macro_rules! check {
+            ($($mask:ident),+) => {$({
+                let mut rng = Rng::new(line!() as u64);
+
+                for _ in 0..ROUNDS {
+                    let bools = rng.array();
+                    let mask = $mask::from_bools(bools);
+
+                    assert_lanes(&mask.to_bools(), &bools, stringify!($mask));
+                    assert_lanes(
+                        &mask.to_array().map(|lane| lane.to_bool()),
+                        &bools,
+                        stringify!($mask),
+                    );
+                    assert_lanes(
+                        &mask.to_int().to_array(),
+                        &bools.map(|lane| if lane { -1 } else { 0 }),
+                        stringify!($mask),
+                    );
+                }
+            })+};
+        }
+
+        check!(m8x1, m8x16, m8x64, m16x8, m16x32, m32x4, m32x8, m64x2, m64x8);
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn kmask() {
+        macro_rules! check {
+            ($($mask:ident),+) => {$({
+                let mut rng = Rng::new(line!() as u64);
+
+                for _ in 0..ROUNDS {
+                    let bools = rng.array();
+                    let mask = $mask::from_bools(bools);
+                    let kmask = mask.to_kmask();
+
+                    assert_eq!(kmask.to_bits(), mask.to_bitmask(), "{bools:?}");
+                    assert_lanes(
+                        &$mask::from_kmask(kmask).to_bools(),
+                        &bools,
+                        stringify!($mask),
+                    );
+                }
+            })+};
+        }
+
+        check!(m8x16, m8x32, m8x64, m16x8, m16x16, m16x32, m32x8, m32x16, m64x8);
+    }
+}
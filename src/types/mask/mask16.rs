@@ -0,0 +1,82 @@
+use crate::{
+    macros::{mask_type, masks},
+    types::vector::*,
+};
+
+mask_type! {
+    /// A lane of a mask for vectors of 16-bit scalars.
+    pub enum m16 {
+        repr: i16,
+        bits: 16,
+    }
+}
+
+masks! {
+    pub struct m16x1 {
+        arr: [m16; 1],
+        bits: 16,
+        int: i16x1,
+        select: [u16x1],
+        bitmask: u8,
+    }
+
+    pub struct m16x2 {
+        arr: [m16; 2],
+        bits: 32,
+        half: m16x1,
+        int: i16x2,
+        select: [u16x2],
+        bitmask: u8,
+    }
+
+    pub struct m16x4 {
+        arr: [m16; 4],
+        bits: 64,
+        half: m16x2,
+        int: i16x4,
+        select: [u16x4],
+        bitmask: u8,
+    }
+
+    pub struct m16x8 {
+        arr: [m16; 8],
+        bits: 128,
+        half: m16x4,
+        int: i16x8,
+        select: [u16x8],
+        bitmask: u8,
+        blend: "sse4.1" Sse41::blendv_i8x16(i8x16),
+        kmask: KMask8,
+    }
+
+    pub struct m16x16 {
+        arr: [m16; 16],
+        bits: 256,
+        half: m16x8,
+        int: i16x16,
+        select: [u16x16],
+        bitmask: u16,
+        blend: "avx2" Avx2::blendv_i8x32(i8x32),
+        kmask: KMask16,
+    }
+
+    pub struct m16x32 {
+        arr: [m16; 32],
+        bits: 512,
+        half: m16x16,
+        int: i16x32,
+        select: [u16x32],
+        bitmask: u32 = "avx512bw" Avx512BW::to_kmask_i16x32(i16x32),
+        kmask: KMask32 = "avx512bw" Avx512BW::{to_kmask_i16x32, from_kmask_i16x32},
+    }
+
+    pub struct m16x64 {
+        arr: [m16; 64],
+        bits: 1024,
+        half: m16x32,
+        int: i16x64,
+        select: [u16x64],
+        bitmask: u64,
+        kmask: KMask64,
+    }
+}
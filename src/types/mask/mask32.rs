@@ -0,0 +1,82 @@
+use crate::{
+    macros::{mask_type, masks},
+    types::vector::*,
+};
+
+mask_type! {
+    /// A lane of a mask for vectors of 32-bit scalars.
+    pub enum m32 {
+        repr: i32,
+        bits: 32,
+    }
+}
+
+masks! {
+    pub struct m32x1 {
+        arr: [m32; 1],
+        bits: 32,
+        int: i32x1,
+        select: [u32x1, f32x1],
+        bitmask: u8,
+    }
+
+    pub struct m32x2 {
+        arr: [m32; 2],
+        bits: 64,
+        half: m32x1,
+        int: i32x2,
+        select: [u32x2, f32x2],
+        bitmask: u8,
+    }
+
+    pub struct m32x4 {
+        arr: [m32; 4],
+        bits: 128,
+        half: m32x2,
+        int: i32x4,
+        select: [u32x4, f32x4],
+        bitmask: u8 = "sse" Sse::movemask_f32x4(f32x4),
+        blend: "sse4.1" Sse41::blendv_i8x16(i8x16),
+    }
+
+    pub struct m32x8 {
+        arr: [m32; 8],
+        bits: 256,
+        half: m32x4,
+        int: i32x8,
+        select: [u32x8, f32x8],
+        bitmask: u8 = "avx" Avx::movemask_f32x8(f32x8),
+        blend: "avx2" Avx2::blendv_i8x32(i8x32),
+        kmask: KMask8,
+    }
+
+    pub struct m32x16 {
+        arr: [m32; 16],
+        bits: 512,
+        half: m32x8,
+        int: i32x16,
+        select: [u32x16, f32x16],
+        bitmask: u16 = "avx512dq" Avx512DQ::to_kmask_i32x16(i32x16),
+        kmask: KMask16 = "avx512dq" Avx512DQ::{to_kmask_i32x16, from_kmask_i32x16},
+    }
+
+    pub struct m32x32 {
+        arr: [m32; 32],
+        bits: 1024,
+        half: m32x16,
+        int: i32x32,
+        select: [u32x32, f32x32],
+        bitmask: u32,
+        kmask: KMask32,
+    }
+
+    pub struct m32x64 {
+        arr: [m32; 64],
+        bits: 2048,
+        half: m32x32,
+        int: i32x64,
+        select: [u32x64, f32x64],
+        bitmask: u64,
+        kmask: KMask64,
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    macros::{mask_type, masks},
+    types::vector::*,
+};
+
+mask_type! {
+    /// A lane of a mask for vectors of 64-bit scalars.
+    pub enum m64 {
+        repr: i64,
+        bits: 64,
+    }
+}
+
+masks! {
+    pub struct m64x1 {
+        arr: [m64; 1],
+        bits: 64,
+        int: i64x1,
+        select: [u64x1, f64x1],
+        bitmask: u8,
+    }
+
+    pub struct m64x2 {
+        arr: [m64; 2],
+        bits: 128,
+        half: m64x1,
+        int: i64x2,
+        select: [u64x2, f64x2],
+        bitmask: u8 = "sse2" Sse2::movemask_f64x2(f64x2),
+        blend: "sse4.1" Sse41::blendv_i8x16(i8x16),
+    }
+
+    pub struct m64x4 {
+        arr: [m64; 4],
+        bits: 256,
+        half: m64x2,
+        int: i64x4,
+        select: [u64x4, f64x4],
+        bitmask: u8 = "avx" Avx::movemask_f64x4(f64x4),
+        blend: "avx2" Avx2::blendv_i8x32(i8x32),
+    }
+
+    pub struct m64x8 {
+        arr: [m64; 8],
+        bits: 512,
+        half: m64x4,
+        int: i64x8,
+        select: [u64x8, f64x8],
+        bitmask: u8 = "avx512dq" Avx512DQ::to_kmask_i64x8(i64x8),
+        kmask: KMask8 = "avx512dq" Avx512DQ::{to_kmask_i64x8, from_kmask_i64x8},
+    }
+
+    pub struct m64x16 {
+        arr: [m64; 16],
+        bits: 1024,
+        half: m64x8,
+        int: i64x16,
+        select: [u64x16, f64x16],
+        bitmask: u16,
+        kmask: KMask16,
+    }
+
+    pub struct m64x32 {
+        arr: [m64; 32],
+        bits: 2048,
+        half: m64x16,
+        int: i64x32,
+        select: [u64x32, f64x32],
+        bitmask: u32,
+        kmask: KMask32,
+    }
+
+    pub struct m64x64 {
+        arr: [m64; 64],
+        bits: 4096,
+        half: m64x32,
+        int: i64x64,
+        select: [u64x64, f64x64],
+        bitmask: u64,
+        kmask: KMask64,
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    macros::{mask_type, masks},
+    types::vector::*,
+};
+
+mask_type! {
+    /// A lane of a mask for vectors of 8-bit scalars.
+    pub enum m8 {
+        repr: i8,
+        bits: 8,
+    }
+}
+
+masks! {
+    pub struct m8x1 {
+        arr: [m8; 1],
+        bits: 8,
+        int: i8x1,
+        select: [u8x1],
+        bitmask: u8,
+    }
+
+    pub struct m8x2 {
+        arr: [m8; 2],
+        bits: 16,
+        half: m8x1,
+        int: i8x2,
+        select: [u8x2],
+        bitmask: u8,
+    }
+
+    pub struct m8x4 {
+        arr: [m8; 4],
+        bits: 32,
+        half: m8x2,
+        int: i8x4,
+        select: [u8x4],
+        bitmask: u8,
+    }
+
+    pub struct m8x8 {
+        arr: [m8; 8],
+        bits: 64,
+        half: m8x4,
+        int: i8x8,
+        select: [u8x8],
+        bitmask: u8,
+        kmask: KMask8,
+    }
+
+    pub struct m8x16 {
+        arr: [m8; 16],
+        bits: 128,
+        half: m8x8,
+        int: i8x16,
+        select: [u8x16],
+        bitmask: u16 = "sse2" Sse2::movemask_i8x16(i8x16),
+        blend: "sse4.1" Sse41::blendv_i8x16(i8x16),
+        kmask: KMask16,
+    }
+
+    pub struct m8x32 {
+        arr: [m8; 32],
+        bits: 256,
+        half: m8x16,
+        int: i8x32,
+        select: [u8x32],
+        bitmask: u32 = "avx2" Avx2::movemask_i8x32(i8x32),
+        blend: "avx2" Avx2::blendv_i8x32(i8x32),
+        kmask: KMask32,
+    }
+
+    pub struct m8x64 {
+        arr: [m8; 64],
+        bits: 512,
+        half: m8x32,
+        int: i8x64,
+        select: [u8x64],
+        bitmask: u64 = "avx512bw" Avx512BW::to_kmask_i8x64(i8x64),
+        kmask: KMask64 = "avx512bw" Avx512BW::{to_kmask_i8x64, from_kmask_i8x64},
+    }
+}
@@ -1,4 +1,26 @@
 //! Module for SIMD Vector types.
+//!
+//! Lanes can be accessed with an index known at compile time, which is checked when
+//! compiling:
+//!
+//! ```
+//! use feat::types::vector::u8x16;
+//!
+//! let v = u8x16::from_array(core::array::from_fn(|i| i as u8));
+//!
+//! assert_eq!(v.extract::<15>(), 15);
+//! assert_eq!(v.replace::<0>(42).extract::<0>(), 42);
+//! ```
+//!
+//! ```compile_fail
+//! use feat::types::vector::u8x16;
+//!
+//! let _ = u8x16::splat(0).extract::<16>();
+//! ```
+//!
+//! Or with an index known at runtime, using `get` and `set`.
+
+use core::fmt;
 
 /// Module for [`prim@f32`] vectors.
 pub mod f32;
@@ -23,6 +45,14 @@ pub mod u64;
 /// Module for [`prim@u8`] vectors.
 pub mod u8;
 
+mod arith;
+mod bitmask;
+mod bitwise;
+mod cmp;
+mod reduce;
+mod shuffle;
+mod swizzle;
+
 pub(crate) mod exports {
     #![allow(unused_imports)]
 
@@ -52,3 +82,199 @@ pub(crate) mod exports {
 
 #[doc(inline)]
 pub use exports::*;
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+
+    pub trait SealedElement {}
+}
+
+/// A vector of scalars.
+///
+/// This is implemented by every vector type, so that code can be generic over both
+/// the scalar type and the number of lanes.
+///
+/// This trait is sealed, and cannot be implemented outside of this crate.
+pub trait Vector: Copy + Send + Sync + Unpin + fmt::Debug + 'static + sealed::Sealed {
+    /// The type of each lane.
+    type Scalar: Copy + Default + PartialEq + PartialOrd + fmt::Debug + Send + Sync + 'static;
+
+    /// The array of scalars with the same layout as this vector.
+    type Array: Copy + AsRef<[Self::Scalar]> + AsMut<[Self::Scalar]>;
+
+    /// The vector with the same scalar and half as many lanes.
+    ///
+    /// This is `()` for vectors with a single lane.
+    type Half;
+
+    /// The vector with the same scalar and twice as many lanes.
+    ///
+    /// This is `()` for the widest vectors.
+    type Double;
+
+    /// The number of lanes in this vector.
+    const LANES: usize;
+
+    /// The size of this vector in bits.
+    const BITS: usize;
+
+    /// Create a new vector from an array of scalars.
+    #[must_use]
+    fn from_array(array: Self::Array) -> Self;
+
+    /// Get an array of scalars from a vector.
+    #[must_use]
+    fn to_array(self) -> Self::Array;
+
+    /// Create a new vector by reading a slice of scalars.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice isn't large enough.
+    #[must_use]
+    fn from_slice(slice: &[Self::Scalar]) -> Self;
+
+    /// Get a reference to the inner slice of scalars.
+    #[must_use]
+    fn as_slice(&self) -> &[Self::Scalar];
+
+    /// Get a mutable reference to the inner slice of scalars.
+    #[must_use]
+    fn as_slice_mut(&mut self) -> &mut [Self::Scalar];
+
+    /// Create a new vector by joining two halves of a vector.
+    ///
+    /// Vectors with a single lane have no halves, so this can't be called on them.
+    #[must_use]
+    fn from_halves(a: Self::Half, b: Self::Half) -> Self
+    where
+        Self::Half: Vector;
+
+    /// Split this vector into an array of halves.
+    ///
+    /// Vectors with a single lane have no halves, so this can't be called on them.
+    #[must_use]
+    fn to_halves(self) -> [Self::Half; 2]
+    where
+        Self::Half: Vector;
+}
+
+/// A scalar that can be held in a vector with `N` lanes.
+///
+/// This trait is sealed, and cannot be implemented outside of this crate.
+#[diagnostic::on_unimplemented(
+    message = "there is no vector of `{Self}` with {N} lanes",
+    label = "unsupported vector",
+    note = "vectors have 1, 2, 4, 8, 16, 32 or 64 lanes of an integer or float"
+)]
+pub trait SimdElement<const N: usize>: Copy + sealed::SealedElement {
+    /// The vector with `N` lanes of this scalar.
+    type Vector: Vector<Scalar = Self>;
+}
+
+/// The vector with `N` lanes of `T`.
+///
+/// This resolves to the concrete vector type, such that `Simd<f32, 4>` is
+/// [`f32x4`], and allows writing code that is generic over the number of lanes
+/// with a `T: SimdElement<N>` bound.
+pub type Simd<T, const N: usize> = <T as SimdElement<N>>::Vector;
+
+/// A static permutation of the lanes of vectors with `N` lanes.
+///
+/// Lane `i` of a shuffled vector is lane `INDICES[i]` of the input. When shuffling two
+/// vectors together, indices from `N` onwards refer to the lanes of the second vector.
+///
+/// Stable Rust does not allow arrays as const generic parameters, so the indices are
+/// given by a type implementing this trait. They are checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use feat::types::vector::{u32x4, Swizzle};
+///
+/// struct SwapPairs;
+///
+/// impl Swizzle<4> for SwapPairs {
+///     const INDICES: [usize; 4] = [1, 0, 3, 2];
+/// }
+///
+/// let v = u32x4::from_array([1, 2, 3, 4]);
+///
+/// assert_eq!(v.shuffle::<SwapPairs>().to_array(), [2, 1, 4, 3]);
+/// ```
+pub trait Swizzle<const N: usize> {
+    /// The index of the input lane for each lane of the output.
+    const INDICES: [usize; N];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::{assert_lanes, Random, Rng, Same, ROUNDS};
+
+    use super::*;
+
+    /// Check that splitting a vector gives its lower and upper lanes, and that joining
+    /// them gives the vector back.
+    fn check_halves<V>()
+    where
+        V: Vector<Scalar: Random + Same>,
+        V::Half: Vector<Scalar = V::Scalar>,
+    {
+        let mut rng = Rng::new(V::BITS as u64 ^ V::LANES as u64);
+
+        for _ in 0..ROUNDS {
+            let lanes: Vec<V::Scalar> = (0..V::LANES).map(|_| rng.gen()).collect();
+            let [a, b] = V::from_slice(&lanes).to_halves();
+            let (lo, hi) = lanes.split_at(V::LANES / 2);
+
+            assert_lanes(a.as_slice(), lo, &lanes);
+            assert_lanes(b.as_slice(), hi, &lanes);
+            assert_lanes(V::from_halves(a, b).as_slice(), &lanes, &lanes);
+        }
+    }
+
+    #[test]
+    fn get() {
+        let v = u8x16::from_array(core::array::from_fn(|i| i as u8));
+
+        for lane in 0..u8x16::LANES {
+            assert_eq!(v.get(lane), Some(lane as u8));
+        }
+
+        assert_eq!(v.get(u8x16::LANES), None);
+        assert_eq!(v.get(usize::MAX), None);
+        assert_eq!(f64x8::splat(1.0).get(8), None);
+    }
+
+    #[test]
+    fn set() {
+        let mut v = i32x4::splat(0);
+
+        v.set(0, 1);
+        v.set(3, -1);
+
+        assert_eq!(v.to_array(), [1, 0, 0, -1]);
+    }
+
+    #[test]
+    #[should_panic = "lane index is out of bounds"]
+    fn set_out_of_bounds() {
+        u8x16::splat(0).set(u8x16::LANES, 1);
+    }
+
+    #[test]
+    fn halves() {
+        check_halves::<u8x2>();
+        check_halves::<u8x64>();
+        check_halves::<i8x16>();
+        check_halves::<u16x8>();
+        check_halves::<i16x32>();
+        check_halves::<u32x4>();
+        check_halves::<i32x16>();
+        check_halves::<u64x2>();
+        check_halves::<i64x8>();
+        check_halves::<f32x8>();
+        check_halves::<f64x4>();
+        check_halves::<f64x64>();
+    }
+}
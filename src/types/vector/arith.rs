@@ -0,0 +1,515 @@
+//! Lane-wise arithmetic operators.
+//!
+//! When the target features required by a backend are statically enabled, the
+//! operators use the matching token. Otherwise, they are applied to each half of
+//! the vector, down to a single lane.
+
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::core_arch::x86;
+
+use super::*;
+
+macro_rules! arith {
+    ($(
+        $kind:ident {$(
+            $name:ident $(/ $half:ident)?: $scalar:ident {
+                $(add = $add_feature:literal $add_token:ident::$add_method:ident;)?
+                $(sub = $sub_feature:literal $sub_token:ident::$sub_method:ident;)?
+                $(mul = $mul_feature:literal $mul_token:ident::$mul_method:ident;)?
+                $(div = $div_feature:literal $div_token:ident::$div_method:ident;)?
+            }
+        )*}
+    )*) => {$($(
+        arith!(@kind $kind $name $(/ $half)?: $scalar {
+            add: [$($add_feature $add_token::$add_method)?],
+            sub: [$($sub_feature $sub_token::$sub_method)?],
+            mul: [$($mul_feature $mul_token::$mul_method)?],
+            div: [$($div_feature $div_token::$div_method)?],
+        });
+    )*)*};
+
+    (@kind unsigned $name:ident $(/ $half:ident)?: $scalar:ident {
+        add: $add:tt,
+        sub: $sub:tt,
+        mul: $mul:tt,
+        div: $div:tt,
+    }) => {
+        arith!(
+            @binary Add::add, AddAssign::add_assign,
+            |a, b| a.wrapping_add(b);
+            $name $(/ $half)?: $scalar; $add
+        );
+        arith!(
+            @binary Sub::sub, SubAssign::sub_assign,
+            |a, b| a.wrapping_sub(b);
+            $name $(/ $half)?: $scalar; $sub
+        );
+        arith!(
+            @binary Mul::mul, MulAssign::mul_assign,
+            |a, b| a.wrapping_mul(b);
+            $name $(/ $half)?: $scalar; $mul
+        );
+        arith!(
+            @binary Div::div, DivAssign::div_assign,
+            |a, b| a.wrapping_div(b);
+            $name $(/ $half)?: $scalar; $div
+        );
+        arith!(
+            @binary Rem::rem, RemAssign::rem_assign,
+            |a, b| a.wrapping_rem(b);
+            $name $(/ $half)?: $scalar; []
+        );
+    };
+
+    (@kind signed $name:ident $(/ $half:ident)?: $scalar:ident $ops:tt) => {
+        arith!(@kind unsigned $name $(/ $half)?: $scalar $ops);
+        arith!(@unary Neg::neg, |a| a.wrapping_neg(); $name $(/ $half)?);
+    };
+
+    (@kind float $name:ident $(/ $half:ident)?: $scalar:ident {
+        add: $add:tt,
+        sub: $sub:tt,
+        mul: $mul:tt,
+        div: $div:tt,
+    }) => {
+        arith!(
+            @binary Add::add, AddAssign::add_assign,
+            |a, b| a + b;
+            $name $(/ $half)?: $scalar; $add
+        );
+        arith!(
+            @binary Sub::sub, SubAssign::sub_assign,
+            |a, b| a - b;
+            $name $(/ $half)?: $scalar; $sub
+        );
+        arith!(
+            @binary Mul::mul, MulAssign::mul_assign,
+            |a, b| a * b;
+            $name $(/ $half)?: $scalar; $mul
+        );
+        arith!(
+            @binary Div::div, DivAssign::div_assign,
+            |a, b| a / b;
+            $name $(/ $half)?: $scalar; $div
+        );
+        arith!(
+            @binary Rem::rem, RemAssign::rem_assign,
+            |a, b| a % b;
+            $name $(/ $half)?: $scalar; []
+        );
+        arith!(@unary Neg::neg, |a| -a; $name $(/ $half)?);
+    };
+
+    (
+        @binary $op:ident::$fn:ident, $op_assign:ident::$fn_assign:ident,
+        |$a:ident, $b:ident| $lane:expr;
+        $name:ident $(/ $half:ident)?: $scalar:ident;
+        [$($feature:literal $token:ident::$method:ident)?]
+    ) => {
+        impl $op for $name {
+            type Output = $name;
+
+            #[inline]
+            #[allow(unreachable_code)]
+            fn $fn(self, rhs: $name) -> $name {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $feature,
+                    ))]
+                    return x86::$token::new().$method(self, rhs);
+                )?
+
+                arith!(@portable $op::$fn, |$a, $b| $lane; self, rhs; $name $(/ $half)?)
+            }
+        }
+
+        impl $op<$scalar> for $name {
+            type Output = $name;
+
+            #[inline]
+            fn $fn(self, rhs: $scalar) -> $name {
+                $op::$fn(self, $name::splat(rhs))
+            }
+        }
+
+        impl $op_assign for $name {
+            #[inline]
+            fn $fn_assign(&mut self, rhs: $name) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+
+        impl $op_assign<$scalar> for $name {
+            #[inline]
+            fn $fn_assign(&mut self, rhs: $scalar) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+    };
+
+    (@unary $op:ident::$fn:ident, |$a:ident| $lane:expr; $name:ident $(/ $half:ident)?) => {
+        impl $op for $name {
+            type Output = $name;
+
+            #[inline]
+            fn $fn(self) -> $name {
+                arith!(@portable $op::$fn, |$a| $lane; self; $name $(/ $half)?)
+            }
+        }
+    };
+
+    (
+        @portable $op:ident::$fn:ident, |$($lane_arg:ident),+| $lane:expr;
+        $($arg:ident),+;
+        $name:ident
+    ) => {{
+        $(let [$lane_arg] = $arg.to_array();)+
+
+        $name::from_array([$lane])
+    }};
+
+    (
+        @portable $op:ident::$fn:ident, |$($lane_arg:ident),+| $lane:expr;
+        $($arg:ident),+;
+        $name:ident / $half:ident
+    ) => {{
+        let [$($lane_arg),+] = [$($arg.to_halves()),+];
+
+        $name::from_halves($op::$fn($($lane_arg[0]),+), $op::$fn($($lane_arg[1]),+))
+    }};
+}
+
+arith! {
+    unsigned {
+        u8x1: u8 {}
+        u8x2 / u8x1: u8 {}
+        u8x4 / u8x2: u8 {}
+        u8x8 / u8x4: u8 {}
+        u8x16 / u8x8: u8 {
+            add = "sse2" Sse2::add_u8x16;
+            sub = "sse2" Sse2::sub_u8x16;
+        }
+        u8x32 / u8x16: u8 {
+            add = "avx2" Avx2::add_u8x32;
+            sub = "avx2" Avx2::sub_u8x32;
+        }
+        u8x64 / u8x32: u8 {
+            add = "avx512bw" Avx512BW::add_u8x64;
+            sub = "avx512bw" Avx512BW::sub_u8x64;
+        }
+    }
+    signed {
+        i8x1: i8 {}
+        i8x2 / i8x1: i8 {}
+        i8x4 / i8x2: i8 {}
+        i8x8 / i8x4: i8 {}
+        i8x16 / i8x8: i8 {
+            add = "sse2" Sse2::add_i8x16;
+            sub = "sse2" Sse2::sub_i8x16;
+        }
+        i8x32 / i8x16: i8 {
+            add = "avx2" Avx2::add_i8x32;
+            sub = "avx2" Avx2::sub_i8x32;
+        }
+        i8x64 / i8x32: i8 {
+            add = "avx512bw" Avx512BW::add_i8x64;
+            sub = "avx512bw" Avx512BW::sub_i8x64;
+        }
+    }
+    unsigned {
+        u16x1: u16 {}
+        u16x2 / u16x1: u16 {}
+        u16x4 / u16x2: u16 {}
+        u16x8 / u16x4: u16 {
+            add = "sse2" Sse2::add_u16x8;
+            sub = "sse2" Sse2::sub_u16x8;
+            mul = "sse2" Sse2::mul_u16x8;
+        }
+        u16x16 / u16x8: u16 {
+            add = "avx2" Avx2::add_u16x16;
+            sub = "avx2" Avx2::sub_u16x16;
+            mul = "avx2" Avx2::mul_u16x16;
+        }
+        u16x32 / u16x16: u16 {
+            add = "avx512bw" Avx512BW::add_u16x32;
+            sub = "avx512bw" Avx512BW::sub_u16x32;
+            mul = "avx512bw" Avx512BW::mul_u16x32;
+        }
+        u16x64 / u16x32: u16 {}
+    }
+    signed {
+        i16x1: i16 {}
+        i16x2 / i16x1: i16 {}
+        i16x4 / i16x2: i16 {}
+        i16x8 / i16x4: i16 {
+            add = "sse2" Sse2::add_i16x8;
+            sub = "sse2" Sse2::sub_i16x8;
+            mul = "sse2" Sse2::mul_i16x8;
+        }
+        i16x16 / i16x8: i16 {
+            add = "avx2" Avx2::add_i16x16;
+            sub = "avx2" Avx2::sub_i16x16;
+            mul = "avx2" Avx2::mul_i16x16;
+        }
+        i16x32 / i16x16: i16 {
+            add = "avx512bw" Avx512BW::add_i16x32;
+            sub = "avx512bw" Avx512BW::sub_i16x32;
+            mul = "avx512bw" Avx512BW::mul_i16x32;
+        }
+        i16x64 / i16x32: i16 {}
+    }
+    unsigned {
+        u32x1: u32 {}
+        u32x2 / u32x1: u32 {}
+        u32x4 / u32x2: u32 {
+            add = "sse2" Sse2::add_u32x4;
+            sub = "sse2" Sse2::sub_u32x4;
+            mul = "sse4.1" Sse41::mul_u32x4;
+        }
+        u32x8 / u32x4: u32 {
+            add = "avx2" Avx2::add_u32x8;
+            sub = "avx2" Avx2::sub_u32x8;
+            mul = "avx2" Avx2::mul_u32x8;
+        }
+        u32x16 / u32x8: u32 {
+            add = "avx512f" Avx512F::add_u32x16;
+            sub = "avx512f" Avx512F::sub_u32x16;
+            mul = "avx512f" Avx512F::mul_u32x16;
+        }
+        u32x32 / u32x16: u32 {}
+        u32x64 / u32x32: u32 {}
+    }
+    signed {
+        i32x1: i32 {}
+        i32x2 / i32x1: i32 {}
+        i32x4 / i32x2: i32 {
+            add = "sse2" Sse2::add_i32x4;
+            sub = "sse2" Sse2::sub_i32x4;
+            mul = "sse4.1" Sse41::mul_i32x4;
+        }
+        i32x8 / i32x4: i32 {
+            add = "avx2" Avx2::add_i32x8;
+            sub = "avx2" Avx2::sub_i32x8;
+            mul = "avx2" Avx2::mul_i32x8;
+        }
+        i32x16 / i32x8: i32 {
+            add = "avx512f" Avx512F::add_i32x16;
+            sub = "avx512f" Avx512F::sub_i32x16;
+            mul = "avx512f" Avx512F::mul_i32x16;
+        }
+        i32x32 / i32x16: i32 {}
+        i32x64 / i32x32: i32 {}
+    }
+    unsigned {
+        u64x1: u64 {}
+        u64x2 / u64x1: u64 {
+            add = "sse2" Sse2::add_u64x2;
+            sub = "sse2" Sse2::sub_u64x2;
+        }
+        u64x4 / u64x2: u64 {
+            add = "avx2" Avx2::add_u64x4;
+            sub = "avx2" Avx2::sub_u64x4;
+        }
+        u64x8 / u64x4: u64 {
+            add = "avx512f" Avx512F::add_u64x8;
+            sub = "avx512f" Avx512F::sub_u64x8;
+            mul = "avx512dq" Avx512DQ::mul_u64x8;
+        }
+        u64x16 / u64x8: u64 {}
+        u64x32 / u64x16: u64 {}
+        u64x64 / u64x32: u64 {}
+    }
+    signed {
+        i64x1: i64 {}
+        i64x2 / i64x1: i64 {
+            add = "sse2" Sse2::add_i64x2;
+            sub = "sse2" Sse2::sub_i64x2;
+        }
+        i64x4 / i64x2: i64 {
+            add = "avx2" Avx2::add_i64x4;
+            sub = "avx2" Avx2::sub_i64x4;
+        }
+        i64x8 / i64x4: i64 {
+            add = "avx512f" Avx512F::add_i64x8;
+            sub = "avx512f" Avx512F::sub_i64x8;
+            mul = "avx512dq" Avx512DQ::mul_i64x8;
+        }
+        i64x16 / i64x8: i64 {}
+        i64x32 / i64x16: i64 {}
+        i64x64 / i64x32: i64 {}
+    }
+    float {
+        f32x1: f32 {}
+        f32x2 / f32x1: f32 {}
+        f32x4 / f32x2: f32 {
+            add = "sse" Sse::add_f32x4;
+            sub = "sse" Sse::sub_f32x4;
+            mul = "sse" Sse::mul_f32x4;
+            div = "sse" Sse::div_f32x4;
+        }
+        f32x8 / f32x4: f32 {
+            add = "avx" Avx::add_f32x8;
+            sub = "avx" Avx::sub_f32x8;
+            mul = "avx" Avx::mul_f32x8;
+            div = "avx" Avx::div_f32x8;
+        }
+        f32x16 / f32x8: f32 {
+            add = "avx512f" Avx512F::add_f32x16;
+            sub = "avx512f" Avx512F::sub_f32x16;
+            mul = "avx512f" Avx512F::mul_f32x16;
+            div = "avx512f" Avx512F::div_f32x16;
+        }
+        f32x32 / f32x16: f32 {}
+        f32x64 / f32x32: f32 {}
+    }
+    float {
+        f64x1: f64 {}
+        f64x2 / f64x1: f64 {
+            add = "sse2" Sse2::add_f64x2;
+            sub = "sse2" Sse2::sub_f64x2;
+            mul = "sse2" Sse2::mul_f64x2;
+            div = "sse2" Sse2::div_f64x2;
+        }
+        f64x4 / f64x2: f64 {
+            add = "avx" Avx::add_f64x4;
+            sub = "avx" Avx::sub_f64x4;
+            mul = "avx" Avx::mul_f64x4;
+            div = "avx" Avx::div_f64x4;
+        }
+        f64x8 / f64x4: f64 {
+            add = "avx512f" Avx512F::add_f64x8;
+            sub = "avx512f" Avx512F::sub_f64x8;
+            mul = "avx512f" Avx512F::mul_f64x8;
+            div = "avx512f" Avx512F::div_f64x8;
+        }
+        f64x16 / f64x8: f64 {}
+        f64x32 / f64x16: f64 {}
+        f64x64 / f64x32: f64 {}
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::lanes;
+
+    use super::*;
+
+    #[test]
+    fn int() {
+        lanes!(
+            u8x1, u8x8, u8x16, u8x32, u8x64, i8x1, i8x8, i8x16, i8x32, i8x64,
+            u16x4, u16x8, u16x16, u16x32, u16x64, i16x4, i16x8, i16x16, i16x32, i16x64,
+            u32x2, u32x4, u32x8, u32x16, u32x64, i32x2, i32x4, i32x8, i32x16, i32x64,
+            u64x1, u64x2, u64x4, u64x8, u64x64, i64x1, i64x2, i64x4, i64x8, i64x64;
+            (a, b) => a + b, a.wrapping_add(b)
+        );
+        lanes!(
+            u8x1, u8x8, u8x16, u8x32, u8x64, i8x1, i8x8, i8x16, i8x32, i8x64,
+            u16x4, u16x8, u16x16, u16x32, u16x64, i16x4, i16x8, i16x16, i16x32, i16x64,
+            u32x2, u32x4, u32x8, u32x16, u32x64, i32x2, i32x4, i32x8, i32x16, i32x64,
+            u64x1, u64x2, u64x4, u64x8, u64x64, i64x1, i64x2, i64x4, i64x8, i64x64;
+            (a, b) => a - b, a.wrapping_sub(b)
+        );
+        lanes!(
+            u8x1, u8x8, u8x16, u8x32, u8x64, i8x1, i8x8, i8x16, i8x32, i8x64,
+            u16x4, u16x8, u16x16, u16x32, u16x64, i16x4, i16x8, i16x16, i16x32, i16x64,
+            u32x2, u32x4, u32x8, u32x16, u32x64, i32x2, i32x4, i32x8, i32x16, i32x64,
+            u64x1, u64x2, u64x4, u64x8, u64x64, i64x1, i64x2, i64x4, i64x8, i64x64;
+            (a, b) => a * b, a.wrapping_mul(b)
+        );
+
+        // Odd divisors are never zero, and include `-1` to divide `MIN` by.
+        lanes!(
+            u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u32x16, i32x16, u64x2, i64x2, i64x8;
+            (a, b) => a / (b | 1), a.wrapping_div(b | 1)
+        );
+        lanes!(
+            u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u32x16, i32x16, u64x2, i64x2, i64x8;
+            (a, b) => a % (b | 1), a.wrapping_rem(b | 1)
+        );
+        lanes!(
+            i8x1, i8x16, i8x64, i16x8, i16x32, i32x4, i32x16, i64x2, i64x8;
+            (a) => -a, a.wrapping_neg()
+        );
+    }
+
+    #[test]
+    fn int_wrapping() {
+        assert_eq!((u8x16::splat(u8::MAX) + 1).to_array(), [0; 16]);
+        assert_eq!((u8x32::splat(0) - 1).to_array(), [u8::MAX; 32]);
+        assert_eq!((i8x64::splat(i8::MAX) + 1).to_array(), [i8::MIN; 64]);
+        assert_eq!((i16x8::splat(i16::MIN) - 1).to_array(), [i16::MAX; 8]);
+        assert_eq!((u16x16::splat(0x100) * 0x100).to_array(), [0; 16]);
+        assert_eq!((i32x4::splat(i32::MAX) * 2).to_array(), [-2; 4]);
+        assert_eq!(
+            (u32x8::splat(0x1_0001) * 0xffff).to_array(),
+            [0xffff_ffff; 8]
+        );
+        assert_eq!((i64x2::splat(i64::MIN) + i64::MIN).to_array(), [0; 2]);
+        assert_eq!((u64x8::splat(1 << 32) * (1 << 32)).to_array(), [0; 8]);
+        assert_eq!((-i32x16::splat(i32::MIN)).to_array(), [i32::MIN; 16]);
+        assert_eq!((i64x4::splat(i64::MIN) / -1).to_array(), [i64::MIN; 4]);
+        assert_eq!(
+            (i8x16::splat(i8::MIN) % i8x16::splat(-1)).to_array(),
+            [0; 16]
+        );
+
+        let mut a = u16x8::splat(u16::MAX);
+        a += 2;
+        a *= u16x8::splat(3);
+
+        assert_eq!(a.to_array(), [3; 8]);
+    }
+
+    #[test]
+    #[should_panic = "attempt to divide by zero"]
+    fn int_div_by_zero() {
+        let _ = u32x4::splat(1) / u32x4::from_array([1, 2, 0, 3]);
+    }
+
+    #[test]
+    fn float() {
+        lanes!(
+            f32x1, f32x2, f32x4, f32x8, f32x16, f32x64, f64x1, f64x2, f64x4, f64x8, f64x64;
+            (a, b) => a + b, a + b
+        );
+        lanes!(
+            f32x1, f32x2, f32x4, f32x8, f32x16, f32x64, f64x1, f64x2, f64x4, f64x8, f64x64;
+            (a, b) => a - b, a - b
+        );
+        lanes!(
+            f32x1, f32x2, f32x4, f32x8, f32x16, f32x64, f64x1, f64x2, f64x4, f64x8, f64x64;
+            (a, b) => a * b, a * b
+        );
+        lanes!(
+            f32x1, f32x2, f32x4, f32x8, f32x16, f32x64, f64x1, f64x2, f64x4, f64x8, f64x64;
+            (a, b) => a / b, a / b
+        );
+        lanes!(f32x4, f32x16, f64x2, f64x8; (a, b) => a % b, a % b);
+        lanes!(f32x4, f32x16, f64x2, f64x8; (a) => -a, -a);
+    }
+
+    #[test]
+    fn float_special() {
+        let a = f32x4::from_array([1.0, -0.0, f32::INFINITY, f32::MAX]);
+        let b = f32x4::from_array([0.0, 0.0, f32::NEG_INFINITY, f32::MAX]);
+
+        let sum = (a + b).to_array();
+        assert_eq!(sum[..2], [1.0, 0.0]);
+        assert!(sum[1].is_sign_positive());
+        assert!(sum[2].is_nan());
+        assert_eq!(sum[3], f32::INFINITY);
+
+        let quotient = (f64x2::from_array([1.0, 0.0]) / f64x2::splat(-0.0)).to_array();
+        assert_eq!(quotient[0], f64::NEG_INFINITY);
+        assert!(quotient[1].is_nan());
+
+        assert!((-f32x8::splat(0.0)).to_array()[7].is_sign_negative());
+    }
+}
@@ -0,0 +1,142 @@
+//! Extraction of the highest bit of every byte, as used by byte-scanning code.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::core_arch::x86;
+use crate::types::mask::*;
+
+use super::*;
+
+macro_rules! bitmask {
+    ($(
+        $name:ident: $mask:ident => $bitmask:ident
+        $(= $feature:literal $token:ident::$method:ident($arg:ident))?;
+    )*) => {$(
+        impl $name {
+            /// Get a bitmask with bit `i` set to the highest bit of lane `i`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn to_bitmask(self) -> $bitmask {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $feature,
+                    ))]
+                    // SAFETY: Both vectors are integer vectors of the same size.
+                    return x86::$token::new()
+                        .$method(unsafe {
+                            crate::util::mem::transmute_unchecked::<$name, $arg>(self)
+                        })
+                        .into();
+                )?
+
+                let mut bitmask: $bitmask = 0;
+
+                for (lane, value) in self.to_array().into_iter().enumerate() {
+                    bitmask |= ((value >> 7) as $bitmask) << lane;
+                }
+
+                bitmask
+            }
+
+            /// Create a vector with every bit of lane `i` set if bit `i` of `bitmask`
+            /// is set.
+            #[inline]
+            #[must_use]
+            pub fn from_bitmask(bitmask: $bitmask) -> $name {
+                let mask = $mask::from_bitmask(bitmask);
+
+                // SAFETY: Both vectors are integer vectors of the same size.
+                unsafe { crate::util::mem::transmute_unchecked(mask.to_int()) }
+            }
+        }
+    )*};
+}
+
+bitmask! {
+    u8x16: m8x16 => u16 = "sse2" Sse2::movemask_u8x16(u8x16);
+    u8x32: m8x32 => u32 = "avx2" Avx2::movemask_u8x32(u8x32);
+    u8x64: m8x64 => u64 = "avx512bw" Avx512BW::to_kmask_i8x64(i8x64);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::{Rng, ROUNDS};
+
+    use super::*;
+
+    #[test]
+    fn vector_round_trip() {
+        macro_rules! check {
+            ($($name:ident: $bitmask:ident),*) => {$({
+                let mut rng = Rng::new(line!() as u64);
+
+                for _ in 0..ROUNDS {
+                    let a = $name::from_array(rng.array());
+                    let expected = a
+                        .to_array()
+                        .into_iter()
+                        .enumerate()
+                        .fold(0, |bits, (i, lane)| bits | ((lane >> 7) as $bitmask) << i);
+
+                    assert_eq!(a.to_bitmask(), expected, "{a:?}");
+
+                    let bits: $bitmask = rng.gen();
+                    let b = $name::from_bitmask(bits);
+
+                    assert_eq!(b.to_bitmask(), bits);
+                    assert!(b.to_array().into_iter().all(|lane| lane == 0 || lane == u8::MAX));
+                    assert_eq!($name::from_bitmask(a.to_bitmask()).to_bitmask(), a.to_bitmask());
+                }
+            })*};
+        }
+
+        check!(u8x16: u16, u8x32: u32, u8x64: u64);
+    }
+
+    #[test]
+    fn vector_high_lanes() {
+        let a = u8x64::from_fn(|i| if i >= 32 { 0x80 } else { 0x7f });
+
+        assert_eq!(a.to_bitmask(), 0xffff_ffff_0000_0000);
+        assert_eq!(u8x64::from_bitmask(1 << 63).to_array()[63], u8::MAX);
+        assert_eq!(u8x32::from_bitmask(1 << 31).to_bitmask(), 1 << 31);
+        assert_eq!(u8x32::splat(0x80).to_bitmask(), u32::MAX);
+    }
+
+    #[test]
+    fn mask_round_trip() {
+        macro_rules! check {
+            ($($name:ident: $bitmask:ident = $lanes:literal),*) => {$({
+                let mut rng = Rng::new(line!() as u64);
+
+                for _ in 0..ROUNDS {
+                    let bools: [bool; $lanes] = rng.array();
+                    let mask = $name::from_bools(bools);
+                    let bits = mask.to_bitmask();
+                    let expected = (0..$lanes)
+                        .filter(|&i| bools[i])
+                        .fold(0, |bits, i| bits | 1 << i);
+
+                    assert_eq!(bits, expected, "{bools:?}");
+                    assert_eq!($name::from_bitmask(bits).to_bools(), bools);
+                    assert!(mask.set_lanes().eq((0..$lanes).filter(|&i| bools[i])));
+
+                    // Bits past the last lane are ignored.
+                    let extra: $bitmask = rng.gen();
+                    let masked = $name::from_bitmask(extra).to_bitmask();
+                    let lanes = (0..$lanes).fold(0, |bits, i| bits | 1 << i);
+
+                    assert_eq!(masked, extra & lanes, "{extra:#x}");
+                }
+            })*};
+        }
+
+        check!(
+            m8x16: u16 = 16, m8x32: u32 = 32, m8x64: u64 = 64,
+            m16x8: u8 = 8, m16x16: u16 = 16, m16x32: u32 = 32,
+            m32x2: u8 = 2, m32x4: u8 = 4, m32x8: u8 = 8, m32x16: u16 = 16,
+            m64x2: u8 = 2, m64x4: u8 = 4, m64x8: u8 = 8
+        );
+    }
+}
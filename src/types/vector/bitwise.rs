@@ -0,0 +1,564 @@
+//! Bitwise and shift operators for integer vectors, and bit reinterpretation of
+//! float vectors.
+//!
+//! Like the arithmetic operators, these use the matching token when its target
+//! features are statically enabled, and otherwise apply to each half of the vector.
+//!
+//! Shifts follow `wrapping_shl` and `wrapping_shr`, so the amount is masked by the
+//! bit width of a lane.
+
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+    ShrAssign,
+};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::core_arch::x86;
+
+use super::*;
+
+macro_rules! bitwise {
+    ($(
+        $name:ident $(/ $half:ident)?: $scalar:ident {
+            $(
+                logic = $logic_feature:literal $logic_token:ident:
+                    $and:ident, $or:ident, $xor:ident, $andnot:ident;
+            )?
+            $(shl = $shl_feature:literal $shl_token:ident::$shl_method:ident;)?
+            $(shr = $shr_feature:literal $shr_token:ident::$shr_method:ident;)?
+            $(shl_var = $shl_var_feature:literal $shl_var_token:ident::$shl_var_method:ident;)?
+            $(shr_var = $shr_var_feature:literal $shr_var_token:ident::$shr_var_method:ident;)?
+        }
+    )*) => {$(
+        bitwise!(
+            @binary BitAnd::bitand, BitAndAssign::bitand_assign,
+            |a, b| a & b;
+            $name $(/ $half)?: $scalar; [$($logic_feature $logic_token::$and)?]
+        );
+        bitwise!(
+            @binary BitOr::bitor, BitOrAssign::bitor_assign,
+            |a, b| a | b;
+            $name $(/ $half)?: $scalar; [$($logic_feature $logic_token::$or)?]
+        );
+        bitwise!(
+            @binary BitXor::bitxor, BitXorAssign::bitxor_assign,
+            |a, b| a ^ b;
+            $name $(/ $half)?: $scalar; [$($logic_feature $logic_token::$xor)?]
+        );
+        bitwise!(
+            @shift Shl::shl, ShlAssign::shl_assign,
+            |a, b| a.wrapping_shl(b as u32);
+            $name $(/ $half)?: $scalar;
+            [$($shl_feature $shl_token::$shl_method)?]
+            [$($shl_var_feature $shl_var_token::$shl_var_method)?]
+        );
+        bitwise!(
+            @shift Shr::shr, ShrAssign::shr_assign,
+            |a, b| a.wrapping_shr(b as u32);
+            $name $(/ $half)?: $scalar;
+            [$($shr_feature $shr_token::$shr_method)?]
+            [$($shr_var_feature $shr_var_token::$shr_var_method)?]
+        );
+
+        impl Not for $name {
+            type Output = $name;
+
+            #[inline]
+            fn not(self) -> $name {
+                bitwise!(@portable Not::not, |a| !a; self; $name $(/ $half)?)
+            }
+        }
+
+        impl $name {
+            /// Compute the bitwise AND of `self` and the complement of `rhs`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn and_not(self, rhs: $name) -> $name {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $logic_feature,
+                    ))]
+                    return x86::$logic_token::new().$andnot(rhs, self);
+                )?
+
+                self & !rhs
+            }
+        }
+    )*};
+
+    (
+        @binary $op:ident::$fn:ident, $op_assign:ident::$fn_assign:ident,
+        |$a:ident, $b:ident| $lane:expr;
+        $name:ident $(/ $half:ident)?: $scalar:ident;
+        [$($feature:literal $token:ident::$method:ident)?]
+    ) => {
+        impl $op for $name {
+            type Output = $name;
+
+            #[inline]
+            #[allow(unreachable_code)]
+            fn $fn(self, rhs: $name) -> $name {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $feature,
+                    ))]
+                    return x86::$token::new().$method(self, rhs);
+                )?
+
+                bitwise!(@portable $op::$fn, |$a, $b| $lane; self, rhs; $name $(/ $half)?)
+            }
+        }
+
+        impl $op<$scalar> for $name {
+            type Output = $name;
+
+            #[inline]
+            fn $fn(self, rhs: $scalar) -> $name {
+                $op::$fn(self, $name::splat(rhs))
+            }
+        }
+
+        bitwise!(@assign $op::$fn, $op_assign::$fn_assign; $name: $scalar);
+    };
+
+    (
+        @shift $op:ident::$fn:ident, $op_assign:ident::$fn_assign:ident,
+        |$a:ident, $b:ident| $lane:expr;
+        $name:ident $(/ $half:ident)?: $scalar:ident;
+        [$($feature:literal $token:ident::$method:ident)?]
+        [$($var_feature:literal $var_token:ident::$var_method:ident)?]
+    ) => {
+        impl $op for $name {
+            type Output = $name;
+
+            #[inline]
+            #[allow(unreachable_code)]
+            fn $fn(self, rhs: $name) -> $name {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $var_feature,
+                    ))]
+                    return x86::$var_token::new().$var_method(self, {
+                        let rhs = rhs & ($scalar::BITS - 1) as $scalar;
+
+                        // SAFETY: The shift amounts are integer vectors of the same
+                        //         size, and any bit pattern is valid for them.
+                        unsafe { crate::util::mem::transmute_unchecked(rhs) }
+                    });
+                )?
+
+                bitwise!(@portable $op::$fn, |$a, $b| $lane; self, rhs; $name $(/ $half)?)
+            }
+        }
+
+        impl $op<$scalar> for $name {
+            type Output = $name;
+
+            #[inline]
+            #[allow(unreachable_code)]
+            fn $fn(self, rhs: $scalar) -> $name {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $feature,
+                    ))]
+                    return x86::$token::new().$method(
+                        self,
+                        u64x2::from_array([(rhs as u32 & ($scalar::BITS - 1)) as u64, 0]),
+                    );
+                )?
+
+                $op::$fn(self, $name::splat(rhs))
+            }
+        }
+
+        bitwise!(@assign $op::$fn, $op_assign::$fn_assign; $name: $scalar);
+    };
+
+    (
+        @assign $op:ident::$fn:ident, $op_assign:ident::$fn_assign:ident;
+        $name:ident: $scalar:ident
+    ) => {
+        impl $op_assign for $name {
+            #[inline]
+            fn $fn_assign(&mut self, rhs: $name) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+
+        impl $op_assign<$scalar> for $name {
+            #[inline]
+            fn $fn_assign(&mut self, rhs: $scalar) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+    };
+
+    (
+        @portable $op:ident::$fn:ident, |$($lane_arg:ident),+| $lane:expr;
+        $($arg:ident),+;
+        $name:ident
+    ) => {{
+        $(let [$lane_arg] = $arg.to_array();)+
+
+        $name::from_array([$lane])
+    }};
+
+    (
+        @portable $op:ident::$fn:ident, |$($lane_arg:ident),+| $lane:expr;
+        $($arg:ident),+;
+        $name:ident / $half:ident
+    ) => {{
+        let [$($lane_arg),+] = [$($arg.to_halves()),+];
+
+        $name::from_halves($op::$fn($($lane_arg[0]),+), $op::$fn($($lane_arg[1]),+))
+    }};
+}
+
+bitwise! {
+    u8x1: u8 {}
+    u8x2 / u8x1: u8 {}
+    u8x4 / u8x2: u8 {}
+    u8x8 / u8x4: u8 {}
+    u8x16 / u8x8: u8 {
+        logic = "sse2" Sse2: and_u8x16, or_u8x16, xor_u8x16, andnot_u8x16;
+    }
+    u8x32 / u8x16: u8 {
+        logic = "avx2" Avx2: and_u8x32, or_u8x32, xor_u8x32, andnot_u8x32;
+    }
+    u8x64 / u8x32: u8 {
+        logic = "avx512f" Avx512F: and_u8x64, or_u8x64, xor_u8x64, andnot_u8x64;
+    }
+    i8x1: i8 {}
+    i8x2 / i8x1: i8 {}
+    i8x4 / i8x2: i8 {}
+    i8x8 / i8x4: i8 {}
+    i8x16 / i8x8: i8 {
+        logic = "sse2" Sse2: and_i8x16, or_i8x16, xor_i8x16, andnot_i8x16;
+    }
+    i8x32 / i8x16: i8 {
+        logic = "avx2" Avx2: and_i8x32, or_i8x32, xor_i8x32, andnot_i8x32;
+    }
+    i8x64 / i8x32: i8 {
+        logic = "avx512f" Avx512F: and_i8x64, or_i8x64, xor_i8x64, andnot_i8x64;
+    }
+    u16x1: u16 {}
+    u16x2 / u16x1: u16 {}
+    u16x4 / u16x2: u16 {}
+    u16x8 / u16x4: u16 {
+        logic = "sse2" Sse2: and_u16x8, or_u16x8, xor_u16x8, andnot_u16x8;
+        shl = "sse2" Sse2::shl_by_u16x8;
+        shr = "sse2" Sse2::shr_by_u16x8;
+    }
+    u16x16 / u16x8: u16 {
+        logic = "avx2" Avx2: and_u16x16, or_u16x16, xor_u16x16, andnot_u16x16;
+        shl = "avx2" Avx2::shl_by_u16x16;
+        shr = "avx2" Avx2::shr_by_u16x16;
+    }
+    u16x32 / u16x16: u16 {
+        logic = "avx512f" Avx512F: and_u16x32, or_u16x32, xor_u16x32, andnot_u16x32;
+    }
+    u16x64 / u16x32: u16 {}
+    i16x1: i16 {}
+    i16x2 / i16x1: i16 {}
+    i16x4 / i16x2: i16 {}
+    i16x8 / i16x4: i16 {
+        logic = "sse2" Sse2: and_i16x8, or_i16x8, xor_i16x8, andnot_i16x8;
+        shl = "sse2" Sse2::shl_by_i16x8;
+        shr = "sse2" Sse2::shr_by_i16x8;
+    }
+    i16x16 / i16x8: i16 {
+        logic = "avx2" Avx2: and_i16x16, or_i16x16, xor_i16x16, andnot_i16x16;
+        shl = "avx2" Avx2::shl_by_i16x16;
+        shr = "avx2" Avx2::shr_by_i16x16;
+    }
+    i16x32 / i16x16: i16 {
+        logic = "avx512f" Avx512F: and_i16x32, or_i16x32, xor_i16x32, andnot_i16x32;
+    }
+    i16x64 / i16x32: i16 {}
+    u32x1: u32 {}
+    u32x2 / u32x1: u32 {}
+    u32x4 / u32x2: u32 {
+        logic = "sse2" Sse2: and_u32x4, or_u32x4, xor_u32x4, andnot_u32x4;
+        shl = "sse2" Sse2::shl_by_u32x4;
+        shr = "sse2" Sse2::shr_by_u32x4;
+        shl_var = "avx2" Avx2::shl_var_u32x4;
+        shr_var = "avx2" Avx2::shr_var_u32x4;
+    }
+    u32x8 / u32x4: u32 {
+        logic = "avx2" Avx2: and_u32x8, or_u32x8, xor_u32x8, andnot_u32x8;
+        shl = "avx2" Avx2::shl_by_u32x8;
+        shr = "avx2" Avx2::shr_by_u32x8;
+        shl_var = "avx2" Avx2::shl_var_u32x8;
+        shr_var = "avx2" Avx2::shr_var_u32x8;
+    }
+    u32x16 / u32x8: u32 {
+        logic = "avx512f" Avx512F: and_u32x16, or_u32x16, xor_u32x16, andnot_u32x16;
+        shl_var = "avx512f" Avx512F::shl_var_u32x16;
+        shr_var = "avx512f" Avx512F::shr_var_u32x16;
+    }
+    u32x32 / u32x16: u32 {}
+    u32x64 / u32x32: u32 {}
+    i32x1: i32 {}
+    i32x2 / i32x1: i32 {}
+    i32x4 / i32x2: i32 {
+        logic = "sse2" Sse2: and_i32x4, or_i32x4, xor_i32x4, andnot_i32x4;
+        shl = "sse2" Sse2::shl_by_i32x4;
+        shr = "sse2" Sse2::shr_by_i32x4;
+        shl_var = "avx2" Avx2::shl_var_i32x4;
+        shr_var = "avx2" Avx2::shr_var_i32x4;
+    }
+    i32x8 / i32x4: i32 {
+        logic = "avx2" Avx2: and_i32x8, or_i32x8, xor_i32x8, andnot_i32x8;
+        shl = "avx2" Avx2::shl_by_i32x8;
+        shr = "avx2" Avx2::shr_by_i32x8;
+        shl_var = "avx2" Avx2::shl_var_i32x8;
+        shr_var = "avx2" Avx2::shr_var_i32x8;
+    }
+    i32x16 / i32x8: i32 {
+        logic = "avx512f" Avx512F: and_i32x16, or_i32x16, xor_i32x16, andnot_i32x16;
+        shl_var = "avx512f" Avx512F::shl_var_i32x16;
+        shr_var = "avx512f" Avx512F::shr_var_i32x16;
+    }
+    i32x32 / i32x16: i32 {}
+    i32x64 / i32x32: i32 {}
+    u64x1: u64 {}
+    u64x2 / u64x1: u64 {
+        logic = "sse2" Sse2: and_u64x2, or_u64x2, xor_u64x2, andnot_u64x2;
+        shl = "sse2" Sse2::shl_by_u64x2;
+        shr = "sse2" Sse2::shr_by_u64x2;
+        shl_var = "avx2" Avx2::shl_var_u64x2;
+        shr_var = "avx2" Avx2::shr_var_u64x2;
+    }
+    u64x4 / u64x2: u64 {
+        logic = "avx2" Avx2: and_u64x4, or_u64x4, xor_u64x4, andnot_u64x4;
+        shl = "avx2" Avx2::shl_by_u64x4;
+        shr = "avx2" Avx2::shr_by_u64x4;
+        shl_var = "avx2" Avx2::shl_var_u64x4;
+        shr_var = "avx2" Avx2::shr_var_u64x4;
+    }
+    u64x8 / u64x4: u64 {
+        logic = "avx512f" Avx512F: and_u64x8, or_u64x8, xor_u64x8, andnot_u64x8;
+        shl_var = "avx512f" Avx512F::shl_var_u64x8;
+        shr_var = "avx512f" Avx512F::shr_var_u64x8;
+    }
+    u64x16 / u64x8: u64 {}
+    u64x32 / u64x16: u64 {}
+    u64x64 / u64x32: u64 {}
+    i64x1: i64 {}
+    i64x2 / i64x1: i64 {
+        logic = "sse2" Sse2: and_i64x2, or_i64x2, xor_i64x2, andnot_i64x2;
+        shl = "sse2" Sse2::shl_by_i64x2;
+        shl_var = "avx2" Avx2::shl_var_i64x2;
+    }
+    i64x4 / i64x2: i64 {
+        logic = "avx2" Avx2: and_i64x4, or_i64x4, xor_i64x4, andnot_i64x4;
+        shl = "avx2" Avx2::shl_by_i64x4;
+        shl_var = "avx2" Avx2::shl_var_i64x4;
+    }
+    i64x8 / i64x4: i64 {
+        logic = "avx512f" Avx512F: and_i64x8, or_i64x8, xor_i64x8, andnot_i64x8;
+        shl_var = "avx512f" Avx512F::shl_var_i64x8;
+        shr_var = "avx512f" Avx512F::shr_var_i64x8;
+    }
+    i64x16 / i64x8: i64 {}
+    i64x32 / i64x16: i64 {}
+    i64x64 / i64x32: i64 {}
+}
+
+macro_rules! float_bits {
+    ($($name:ident: $bits:ident),* $(,)?) => {$(
+        impl $name {
+            /// Reinterpret every lane as the bits of its scalar.
+            ///
+            /// This allows bitwise operations on float vectors, such as clearing the
+            /// sign bits with `from_bits(v.to_bits() & !SIGN)`.
+            #[inline]
+            #[must_use]
+            pub const fn to_bits(self) -> $bits {
+                // SAFETY: Both vectors have the same size, and any bit pattern is
+                //         valid for either of them.
+                unsafe { ::core::mem::transmute(self) }
+            }
+
+            /// Create a vector by reinterpreting every lane of `bits` as a scalar.
+            #[inline]
+            #[must_use]
+            pub const fn from_bits(bits: $bits) -> $name {
+                // SAFETY: Both vectors have the same size, and any bit pattern is
+                //         valid for either of them.
+                unsafe { ::core::mem::transmute(bits) }
+            }
+        }
+    )*};
+}
+
+float_bits! {
+    f32x1: u32x1,
+    f32x2: u32x2,
+    f32x4: u32x4,
+    f32x8: u32x8,
+    f32x16: u32x16,
+    f32x32: u32x32,
+    f32x64: u32x64,
+    f64x1: u64x1,
+    f64x2: u64x2,
+    f64x4: u64x4,
+    f64x8: u64x8,
+    f64x16: u64x16,
+    f64x32: u64x32,
+    f64x64: u64x64,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::lanes;
+
+    use super::*;
+
+    #[test]
+    fn logic() {
+        lanes!(
+            u8x4, u8x16, u8x32, u8x64, i8x16, i8x64, u16x8, u16x16, u16x32, i16x32,
+            u32x4, u32x8, u32x16, i32x4, i32x64, u64x2, u64x4, u64x8, i64x2, i64x8;
+            (a, b) => a & b, a & b
+        );
+        lanes!(
+            u8x4, u8x16, u8x32, u8x64, i8x16, i8x64, u16x8, u16x16, u16x32, i16x32,
+            u32x4, u32x8, u32x16, i32x4, i32x64, u64x2, u64x4, u64x8, i64x2, i64x8;
+            (a, b) => a | b, a | b
+        );
+        lanes!(
+            u8x4, u8x16, u8x32, u8x64, i8x16, i8x64, u16x8, u16x16, u16x32, i16x32,
+            u32x4, u32x8, u32x16, i32x4, i32x64, u64x2, u64x4, u64x8, i64x2, i64x8;
+            (a, b) => a ^ b, a ^ b
+        );
+        lanes!(
+            u8x4, u8x16, u8x32, u8x64, i8x16, i8x64, u16x8, u16x16, u16x32, i16x32,
+            u32x4, u32x8, u32x16, i32x4, i32x64, u64x2, u64x4, u64x8, i64x2, i64x8;
+            (a, b) => a.and_not(b), a & !b
+        );
+        lanes!(u8x16, i8x64, u16x1, i32x8, u64x8; (a) => !a, !a);
+    }
+
+    // The counts are random, so most of them are out of range and must be masked.
+    #[test]
+    fn shift_by_vector() {
+        lanes!(
+            u8x16, i8x16, u16x8, u16x16, i16x8, i16x16, u16x32,
+            u32x4, u32x8, u32x16, i32x4, i32x8, i32x16, u32x64,
+            u64x2, u64x4, u64x8, i64x2, i64x4, i64x8, i64x64;
+            (a, b) => a << b, a.wrapping_shl(b as u32)
+        );
+        lanes!(
+            u8x16, i8x16, u16x8, u16x16, i16x8, i16x16, u16x32,
+            u32x4, u32x8, u32x16, i32x4, i32x8, i32x16, u32x64,
+            u64x2, u64x4, u64x8, i64x2, i64x4, i64x8, i64x64;
+            (a, b) => a >> b, a.wrapping_shr(b as u32)
+        );
+    }
+
+    #[test]
+    fn shift_by_vector_out_of_range() {
+        let counts = u32x8::from_array([0, 1, 31, 32, 33, 63, 64, u32::MAX]);
+        let a = u32x8::splat(0x8000_0001);
+
+        assert_eq!(
+            (a << counts).to_array(),
+            [
+                0x8000_0001,
+                2,
+                0x8000_0000,
+                0x8000_0001,
+                2,
+                0x8000_0000,
+                0x8000_0001,
+                0x8000_0000
+            ],
+        );
+        assert_eq!(
+            (a >> counts).to_array(),
+            [
+                0x8000_0001,
+                0x4000_0000,
+                1,
+                0x8000_0001,
+                0x4000_0000,
+                1,
+                0x8000_0001,
+                1
+            ],
+        );
+
+        let counts = i64x4::from_array([63, 64, -1, 65]);
+        let a = i64x4::splat(i64::MIN);
+
+        assert_eq!((a >> counts).to_array(), [-1, i64::MIN, -1, i64::MIN / 2]);
+        assert_eq!((a << counts).to_array(), [0, i64::MIN, 0, 0]);
+    }
+
+    #[test]
+    fn shift_by_scalar() {
+        macro_rules! check {
+            ($($name:ident: $scalar:ident),*) => {$(
+                let a = $name::from_fn(|i| $scalar::MIN.wrapping_add(i as $scalar * 3) | 1);
+
+                for count in (0..3 * $scalar::BITS as $scalar).chain([$scalar::MAX]) {
+                    let (left, right) = (a << count, a >> count);
+
+                    for (i, a) in a.to_array().into_iter().enumerate() {
+                        let context = (stringify!($name), a, count);
+
+                        assert_eq!(left.to_array()[i], a.wrapping_shl(count as u32), "{context:?}");
+                        assert_eq!(right.to_array()[i], a.wrapping_shr(count as u32), "{context:?}");
+                    }
+                }
+            )*};
+        }
+
+        check!(
+            u8x16: u8, i8x16: i8, u16x8: u16, i16x8: i16, u16x16: u16, i16x16: i16,
+            u32x4: u32, i32x4: i32, u32x8: u32, i32x8: i32, i32x16: i32,
+            u64x2: u64, i64x2: i64, u64x4: u64, i64x4: i64, i64x8: i64
+        );
+    }
+
+    #[test]
+    fn signed_shift_right_is_arithmetic() {
+        let a = i16x8::from_array([i16::MIN, -1, -2, -3, 0, 1, i16::MAX, -0x100]);
+
+        assert_eq!(
+            (a >> 1).to_array(),
+            [-0x4000, -1, -1, -2, 0, 0, 0x3fff, -0x80]
+        );
+        assert_eq!((a >> 15).to_array(), [-1, -1, -1, -1, 0, 0, 0, -1]);
+        assert_eq!((a >> 16).to_array(), a.to_array());
+
+        let a = i32x8::splat(-8);
+
+        assert_eq!((a >> 2).to_array(), [-2; 8]);
+        assert_eq!((a >> i32x8::splat(31)).to_array(), [-1; 8]);
+
+        let a = i64x2::from_array([i64::MIN, -1]);
+
+        assert_eq!((a >> 62).to_array(), [-2, -1]);
+    }
+
+    #[test]
+    fn float_bits() {
+        let a = f32x4::from_array([1.0, -0.0, f32::INFINITY, -2.5]);
+        let abs = f32x4::from_bits(a.to_bits() & !0x8000_0000);
+
+        assert_eq!(a.to_bits().to_array()[0], 1.0f32.to_bits());
+        assert_eq!(abs.to_array(), [1.0, 0.0, f32::INFINITY, 2.5]);
+        assert!(abs.to_array()[1].is_sign_positive());
+
+        let b = f64x8::from_fn(|i| i as f64 - 3.5);
+
+        assert_eq!(f64x8::from_bits(b.to_bits()).to_array(), b.to_array());
+    }
+}
@@ -0,0 +1,646 @@
+//! Lane-wise comparisons, which return masks.
+//!
+//! Like the arithmetic operators, these use the matching token when its target
+//! features are statically enabled, and otherwise compare each half of the vector.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::core_arch::x86::{self, float::Cmp, KMask16, KMask32, KMask64, KMask8};
+use crate::types::mask::*;
+
+use super::*;
+
+/// The result of a comparison intrinsic, in which every lane is either all ones or
+/// all zeros.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+trait IntoMask<M> {
+    /// Convert this result into a mask.
+    fn into_mask(self) -> M;
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+macro_rules! kmask_into_mask {
+    ($($kmask:ident => $mask:ident),* $(,)?) => {$(
+        impl IntoMask<$mask> for $kmask {
+            #[inline(always)]
+            fn into_mask(self) -> $mask {
+                $mask::from_kmask(self)
+            }
+        }
+    )*};
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+kmask_into_mask! {
+    KMask64 => m8x64,
+    KMask32 => m16x32,
+    KMask16 => m32x16,
+    KMask8 => m64x8,
+}
+
+/// Comparisons such as `Avx::cmp_f32x8` already return a mask.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl<M> IntoMask<M> for M {
+    #[inline(always)]
+    fn into_mask(self) -> M {
+        self
+    }
+}
+
+macro_rules! cmp {
+    ($(
+        $kind:ident {$(
+            $name:ident $(/ $half:ident)?: $scalar:ident => $mask:ident {
+                $(eq: $(
+                    $eq_feature:literal $eq_token:ident::$eq_method:ident $(($eq_pred:ident))?
+                ),+;)?
+                $(ne: $(
+                    $ne_feature:literal $ne_token:ident::$ne_method:ident $(($ne_pred:ident))?
+                ),+;)?
+                $(lt: $(
+                    $lt_feature:literal $lt_token:ident::$lt_method:ident $(($lt_pred:ident))?
+                ),+;)?
+                $(le: $(
+                    $le_feature:literal $le_token:ident::$le_method:ident $(($le_pred:ident))?
+                ),+;)?
+                $(gt: $(
+                    $gt_feature:literal $gt_token:ident::$gt_method:ident $(($gt_pred:ident))?
+                ),+;)?
+                $(ge: $(
+                    $ge_feature:literal $ge_token:ident::$ge_method:ident $(($ge_pred:ident))?
+                ),+;)?
+                $(cmp: $($cmp_feature:literal $cmp_token:ident::$cmp_method:ident),+;)?
+                $(signed: $signed:ident;)?
+            }
+        )*}
+    )*) => {$($(
+        cmp!(@kind $kind $name $(/ $half)?: $scalar => $mask {
+            eq: [$($($eq_feature $eq_token::$eq_method $(($eq_pred))?),+)?],
+            ne: [$($($ne_feature $ne_token::$ne_method $(($ne_pred))?),+)?],
+            lt: [$($($lt_feature $lt_token::$lt_method $(($lt_pred))?),+)?],
+            le: [$($($le_feature $le_token::$le_method $(($le_pred))?),+)?],
+            gt: [$($($gt_feature $gt_token::$gt_method $(($gt_pred))?),+)?],
+            ge: [$($($ge_feature $ge_token::$ge_method $(($ge_pred))?),+)?],
+            cmp: [$($($cmp_feature $cmp_token::$cmp_method),+)?],
+            signed: [$($signed)?],
+        });
+    )*)*};
+
+    (@kind int $name:ident $(/ $half:ident)?: $scalar:ident => $mask:ident {
+        eq: $eq:tt,
+        ne: $ne:tt,
+        lt: $lt:tt,
+        le: $le:tt,
+        gt: $gt:tt,
+        ge: $ge:tt,
+        cmp: [],
+        signed: [$($signed:ident)?],
+    }) => {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        impl IntoMask<$mask> for $name {
+            #[inline(always)]
+            fn into_mask(self) -> $mask {
+                // SAFETY: Both vectors are integer vectors of the same size, and every
+                //         lane of a comparison result is either `0` or `-1`.
+                unsafe { $mask::from_int_unchecked(crate::util::mem::transmute_unchecked(self)) }
+            }
+        }
+
+        impl $name {
+            /// Check whether each lane of `self` is equal to the same lane of `rhs`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_eq(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $eq);
+                cmp!(@portable simd_eq(), |a, b| a == b; self, rhs; $name $(/ $half)? => $mask)
+            }
+
+            /// Check whether each lane of `self` is not equal to the same lane of
+            /// `rhs`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_ne(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $ne);
+                !self.simd_eq(rhs)
+            }
+
+            /// Check whether each lane of `self` is less than the same lane of `rhs`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_lt(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $lt);
+                rhs.simd_gt(self)
+            }
+
+            /// Check whether each lane of `self` is less than or equal to the same
+            /// lane of `rhs`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_le(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $le);
+                !self.simd_gt(rhs)
+            }
+
+            /// Check whether each lane of `self` is greater than the same lane of
+            /// `rhs`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_gt(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $gt);
+
+                $(
+                    // Flipping the sign bits maps the unsigned order onto the signed one.
+                    let sign = 1 << ($scalar::BITS - 1);
+
+                    // SAFETY: Both vectors are integer vectors of the same size.
+                    let [a, b] = unsafe {
+                        [
+                            crate::util::mem::transmute_unchecked::<$name, $signed>(self ^ sign),
+                            crate::util::mem::transmute_unchecked::<$name, $signed>(rhs ^ sign),
+                        ]
+                    };
+
+                    return a.simd_gt(b);
+                )?
+
+                cmp!(@portable simd_gt(), |a, b| a > b; self, rhs; $name $(/ $half)? => $mask)
+            }
+
+            /// Check whether each lane of `self` is greater than or equal to the same
+            /// lane of `rhs`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_ge(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $ge);
+                !self.simd_lt(rhs)
+            }
+        }
+    };
+
+    (@kind float $name:ident $(/ $half:ident)?: $scalar:ident => $mask:ident {
+        eq: $eq:tt,
+        ne: $ne:tt,
+        lt: $lt:tt,
+        le: $le:tt,
+        gt: $gt:tt,
+        ge: $ge:tt,
+        cmp: $cmp:tt,
+        signed: [],
+    }) => {
+        impl $name {
+            /// Check whether each lane of `self` is equal to the same lane of `rhs`.
+            ///
+            /// Lanes containing NaN are not equal to anything.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_eq(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $eq);
+                cmp!(@portable simd_eq(), |a, b| a == b; self, rhs; $name $(/ $half)? => $mask)
+            }
+
+            /// Check whether each lane of `self` is not equal to the same lane of
+            /// `rhs`.
+            ///
+            /// Lanes containing NaN are not equal to anything.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_ne(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $ne);
+                cmp!(@portable simd_ne(), |a, b| a != b; self, rhs; $name $(/ $half)? => $mask)
+            }
+
+            /// Check whether each lane of `self` is less than the same lane of `rhs`.
+            ///
+            /// Lanes containing NaN are never less than anything.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_lt(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $lt);
+                cmp!(@portable simd_lt(), |a, b| a < b; self, rhs; $name $(/ $half)? => $mask)
+            }
+
+            /// Check whether each lane of `self` is less than or equal to the same
+            /// lane of `rhs`.
+            ///
+            /// Lanes containing NaN are never less than or equal to anything.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_le(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $le);
+                cmp!(@portable simd_le(), |a, b| a <= b; self, rhs; $name $(/ $half)? => $mask)
+            }
+
+            /// Check whether each lane of `self` is greater than the same lane of
+            /// `rhs`.
+            ///
+            /// Lanes containing NaN are never greater than anything.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_gt(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $gt);
+                cmp!(@portable simd_gt(), |a, b| a > b; self, rhs; $name $(/ $half)? => $mask)
+            }
+
+            /// Check whether each lane of `self` is greater than or equal to the same
+            /// lane of `rhs`.
+            ///
+            /// Lanes containing NaN are never greater than or equal to anything.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_ge(self, rhs: $name) -> $mask {
+                cmp!(@accel (self, rhs) $ge);
+                cmp!(@portable simd_ge(), |a, b| a >= b; self, rhs; $name $(/ $half)? => $mask)
+            }
+
+            /// Compare each lane of `self` with the same lane of `rhs` using the
+            /// predicate `cmp`.
+            ///
+            /// Every predicate gives the same result as the comparison instructions,
+            /// including for lanes containing NaN, whichever backend is used.
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn simd_cmp(self, rhs: $name, cmp: Cmp) -> $mask {
+                cmp!(@accel (self, rhs, cmp) $cmp);
+                cmp!(
+                    @portable simd_cmp(cmp), |a, b| cmp!(@eval $scalar, cmp, a, b);
+                    self, rhs;
+                    $name $(/ $half)? => $mask
+                )
+            }
+        }
+    };
+
+    (@accel ($($arg:expr),+) []) => {};
+    (
+        @accel ($($arg:expr),+)
+        [$feature:literal $token:ident::$method:ident $(($pred:ident))? $(, $($rest:tt)*)?]
+    ) => {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = $feature,
+        ))]
+        return IntoMask::into_mask(x86::$token::new().$method($($arg,)+ $(Cmp::$pred)?));
+
+        cmp!(@accel ($($arg),+) [$($($rest)*)?]);
+    };
+
+    (@eval f32, $cmp:ident, $a:ident, $b:ident) => {
+        $cmp.eval_f32($a, $b)
+    };
+    (@eval f64, $cmp:ident, $a:ident, $b:ident) => {
+        $cmp.eval_f64($a, $b)
+    };
+
+    (
+        @portable $method:ident($($extra:expr),*), |$a:ident, $b:ident| $lane:expr;
+        $lhs:ident, $rhs:ident;
+        $name:ident => $mask:ident
+    ) => {{
+        let [$a] = $lhs.to_array();
+        let [$b] = $rhs.to_array();
+
+        $mask::from_bools([$lane])
+    }};
+
+    (
+        @portable $method:ident($($extra:expr),*), |$a:ident, $b:ident| $lane:expr;
+        $lhs:ident, $rhs:ident;
+        $name:ident / $half:ident => $mask:ident
+    ) => {{
+        let [a, b] = [$lhs.to_halves(), $rhs.to_halves()];
+
+        $mask::from_halves(a[0].$method(b[0] $(, $extra)*), a[1].$method(b[1] $(, $extra)*))
+    }};
+}
+
+cmp! {
+    int {
+        u8x1: u8 => m8x1 {}
+        u8x2 / u8x1: u8 => m8x2 {}
+        u8x4 / u8x2: u8 => m8x4 {}
+        u8x8 / u8x4: u8 => m8x8 {}
+        u8x16 / u8x8: u8 => m8x16 {
+            eq: "sse2" Sse2::eq_u8x16;
+            signed: i8x16;
+        }
+        u8x32 / u8x16: u8 => m8x32 {
+            eq: "avx2" Avx2::eq_u8x32;
+            signed: i8x32;
+        }
+        u8x64 / u8x32: u8 => m8x64 {
+            eq: "avx512bw" Avx512BW::eq_u8x64;
+            ne: "avx512bw" Avx512BW::ne_u8x64;
+            lt: "avx512bw" Avx512BW::lt_u8x64;
+            le: "avx512bw" Avx512BW::le_u8x64;
+            gt: "avx512bw" Avx512BW::gt_u8x64;
+            ge: "avx512bw" Avx512BW::ge_u8x64;
+        }
+        i8x1: i8 => m8x1 {}
+        i8x2 / i8x1: i8 => m8x2 {}
+        i8x4 / i8x2: i8 => m8x4 {}
+        i8x8 / i8x4: i8 => m8x8 {}
+        i8x16 / i8x8: i8 => m8x16 {
+            eq: "sse2" Sse2::eq_i8x16;
+            lt: "sse2" Sse2::lt_i8x16;
+            gt: "sse2" Sse2::gt_i8x16;
+        }
+        i8x32 / i8x16: i8 => m8x32 {
+            eq: "avx2" Avx2::eq_i8x32;
+            lt: "avx2" Avx2::lt_i8x32;
+            gt: "avx2" Avx2::gt_i8x32;
+        }
+        i8x64 / i8x32: i8 => m8x64 {
+            eq: "avx512bw" Avx512BW::eq_i8x64;
+            ne: "avx512bw" Avx512BW::ne_i8x64;
+            lt: "avx512bw" Avx512BW::lt_i8x64;
+            le: "avx512bw" Avx512BW::le_i8x64;
+            gt: "avx512bw" Avx512BW::gt_i8x64;
+            ge: "avx512bw" Avx512BW::ge_i8x64;
+        }
+        u16x1: u16 => m16x1 {}
+        u16x2 / u16x1: u16 => m16x2 {}
+        u16x4 / u16x2: u16 => m16x4 {}
+        u16x8 / u16x4: u16 => m16x8 {
+            eq: "sse2" Sse2::eq_u16x8;
+            signed: i16x8;
+        }
+        u16x16 / u16x8: u16 => m16x16 {
+            eq: "avx2" Avx2::eq_u16x16;
+            signed: i16x16;
+        }
+        u16x32 / u16x16: u16 => m16x32 {
+            eq: "avx512bw" Avx512BW::eq_u16x32;
+            ne: "avx512bw" Avx512BW::ne_u16x32;
+            lt: "avx512bw" Avx512BW::lt_u16x32;
+            le: "avx512bw" Avx512BW::le_u16x32;
+            gt: "avx512bw" Avx512BW::gt_u16x32;
+            ge: "avx512bw" Avx512BW::ge_u16x32;
+        }
+        u16x64 / u16x32: u16 => m16x64 {}
+        i16x1: i16 => m16x1 {}
+        i16x2 / i16x1: i16 => m16x2 {}
+        i16x4 / i16x2: i16 => m16x4 {}
+        i16x8 / i16x4: i16 => m16x8 {
+            eq: "sse2" Sse2::eq_i16x8;
+            lt: "sse2" Sse2::lt_i16x8;
+            gt: "sse2" Sse2::gt_i16x8;
+        }
+        i16x16 / i16x8: i16 => m16x16 {
+            eq: "avx2" Avx2::eq_i16x16;
+            lt: "avx2" Avx2::lt_i16x16;
+            gt: "avx2" Avx2::gt_i16x16;
+        }
+        i16x32 / i16x16: i16 => m16x32 {
+            eq: "avx512bw" Avx512BW::eq_i16x32;
+            ne: "avx512bw" Avx512BW::ne_i16x32;
+            lt: "avx512bw" Avx512BW::lt_i16x32;
+            le: "avx512bw" Avx512BW::le_i16x32;
+            gt: "avx512bw" Avx512BW::gt_i16x32;
+            ge: "avx512bw" Avx512BW::ge_i16x32;
+        }
+        i16x64 / i16x32: i16 => m16x64 {}
+        u32x1: u32 => m32x1 {}
+        u32x2 / u32x1: u32 => m32x2 {}
+        u32x4 / u32x2: u32 => m32x4 {
+            eq: "sse2" Sse2::eq_u32x4;
+            signed: i32x4;
+        }
+        u32x8 / u32x4: u32 => m32x8 {
+            eq: "avx2" Avx2::eq_u32x8;
+            signed: i32x8;
+        }
+        u32x16 / u32x8: u32 => m32x16 {
+            eq: "avx512f" Avx512F::eq_u32x16;
+            ne: "avx512f" Avx512F::ne_u32x16;
+            lt: "avx512f" Avx512F::lt_u32x16;
+            le: "avx512f" Avx512F::le_u32x16;
+            gt: "avx512f" Avx512F::gt_u32x16;
+            ge: "avx512f" Avx512F::ge_u32x16;
+        }
+        u32x32 / u32x16: u32 => m32x32 {}
+        u32x64 / u32x32: u32 => m32x64 {}
+        i32x1: i32 => m32x1 {}
+        i32x2 / i32x1: i32 => m32x2 {}
+        i32x4 / i32x2: i32 => m32x4 {
+            eq: "sse2" Sse2::eq_i32x4;
+            lt: "sse2" Sse2::lt_i32x4;
+            gt: "sse2" Sse2::gt_i32x4;
+        }
+        i32x8 / i32x4: i32 => m32x8 {
+            eq: "avx2" Avx2::eq_i32x8;
+            lt: "avx2" Avx2::lt_i32x8;
+            gt: "avx2" Avx2::gt_i32x8;
+        }
+        i32x16 / i32x8: i32 => m32x16 {
+            eq: "avx512f" Avx512F::eq_i32x16;
+            ne: "avx512f" Avx512F::ne_i32x16;
+            lt: "avx512f" Avx512F::lt_i32x16;
+            le: "avx512f" Avx512F::le_i32x16;
+            gt: "avx512f" Avx512F::gt_i32x16;
+            ge: "avx512f" Avx512F::ge_i32x16;
+        }
+        i32x32 / i32x16: i32 => m32x32 {}
+        i32x64 / i32x32: i32 => m32x64 {}
+        u64x1: u64 => m64x1 {}
+        u64x2 / u64x1: u64 => m64x2 {
+            eq: "sse4.1" Sse41::eq_u64x2;
+            signed: i64x2;
+        }
+        u64x4 / u64x2: u64 => m64x4 {
+            eq: "avx2" Avx2::eq_u64x4;
+            signed: i64x4;
+        }
+        u64x8 / u64x4: u64 => m64x8 {
+            eq: "avx512f" Avx512F::eq_u64x8;
+            ne: "avx512f" Avx512F::ne_u64x8;
+            lt: "avx512f" Avx512F::lt_u64x8;
+            le: "avx512f" Avx512F::le_u64x8;
+            gt: "avx512f" Avx512F::gt_u64x8;
+            ge: "avx512f" Avx512F::ge_u64x8;
+        }
+        u64x16 / u64x8: u64 => m64x16 {}
+        u64x32 / u64x16: u64 => m64x32 {}
+        u64x64 / u64x32: u64 => m64x64 {}
+        i64x1: i64 => m64x1 {}
+        i64x2 / i64x1: i64 => m64x2 {
+            eq: "sse4.1" Sse41::eq_i64x2;
+            lt: "sse4.2" Sse42::lt_i64x2;
+            gt: "sse4.2" Sse42::gt_i64x2;
+        }
+        i64x4 / i64x2: i64 => m64x4 {
+            eq: "avx2" Avx2::eq_i64x4;
+            lt: "avx2" Avx2::lt_i64x4;
+            gt: "avx2" Avx2::gt_i64x4;
+        }
+        i64x8 / i64x4: i64 => m64x8 {
+            eq: "avx512f" Avx512F::eq_i64x8;
+            ne: "avx512f" Avx512F::ne_i64x8;
+            lt: "avx512f" Avx512F::lt_i64x8;
+            le: "avx512f" Avx512F::le_i64x8;
+            gt: "avx512f" Avx512F::gt_i64x8;
+            ge: "avx512f" Avx512F::ge_i64x8;
+        }
+        i64x16 / i64x8: i64 => m64x16 {}
+        i64x32 / i64x16: i64 => m64x32 {}
+        i64x64 / i64x32: i64 => m64x64 {}
+    }
+
+    float {
+        f32x1: f32 => m32x1 {}
+        f32x2 / f32x1: f32 => m32x2 {}
+        f32x4 / f32x2: f32 => m32x4 {
+            eq: "avx" Avx::cmp_f32x4(EQ), "sse" Sse::eq_f32x4;
+            ne: "avx" Avx::cmp_f32x4(NEQ_UNORD), "sse" Sse::neq_f32x4;
+            lt: "avx" Avx::cmp_f32x4(LT), "sse" Sse::lt_f32x4;
+            le: "avx" Avx::cmp_f32x4(LE), "sse" Sse::le_f32x4;
+            gt: "avx" Avx::cmp_f32x4(GT), "sse" Sse::gt_f32x4;
+            ge: "avx" Avx::cmp_f32x4(GE), "sse" Sse::ge_f32x4;
+            cmp: "avx" Avx::cmp_f32x4;
+        }
+        f32x8 / f32x4: f32 => m32x8 {
+            eq: "avx" Avx::cmp_f32x8(EQ);
+            ne: "avx" Avx::cmp_f32x8(NEQ_UNORD);
+            lt: "avx" Avx::cmp_f32x8(LT);
+            le: "avx" Avx::cmp_f32x8(LE);
+            gt: "avx" Avx::cmp_f32x8(GT);
+            ge: "avx" Avx::cmp_f32x8(GE);
+            cmp: "avx" Avx::cmp_f32x8;
+        }
+        f32x16 / f32x8: f32 => m32x16 {
+            eq: "avx512f" Avx512F::cmp_f32x16(EQ);
+            ne: "avx512f" Avx512F::cmp_f32x16(NEQ_UNORD);
+            lt: "avx512f" Avx512F::cmp_f32x16(LT);
+            le: "avx512f" Avx512F::cmp_f32x16(LE);
+            gt: "avx512f" Avx512F::cmp_f32x16(GT);
+            ge: "avx512f" Avx512F::cmp_f32x16(GE);
+            cmp: "avx512f" Avx512F::cmp_f32x16;
+        }
+        f32x32 / f32x16: f32 => m32x32 {}
+        f32x64 / f32x32: f32 => m32x64 {}
+        f64x1: f64 => m64x1 {}
+        f64x2 / f64x1: f64 => m64x2 {
+            eq: "avx" Avx::cmp_f64x2(EQ), "sse2" Sse2::eq_f64x2;
+            ne: "avx" Avx::cmp_f64x2(NEQ_UNORD), "sse2" Sse2::neq_f64x2;
+            lt: "avx" Avx::cmp_f64x2(LT), "sse2" Sse2::lt_f64x2;
+            le: "avx" Avx::cmp_f64x2(LE), "sse2" Sse2::le_f64x2;
+            gt: "avx" Avx::cmp_f64x2(GT), "sse2" Sse2::gt_f64x2;
+            ge: "avx" Avx::cmp_f64x2(GE), "sse2" Sse2::ge_f64x2;
+            cmp: "avx" Avx::cmp_f64x2;
+        }
+        f64x4 / f64x2: f64 => m64x4 {
+            eq: "avx" Avx::cmp_f64x4(EQ);
+            ne: "avx" Avx::cmp_f64x4(NEQ_UNORD);
+            lt: "avx" Avx::cmp_f64x4(LT);
+            le: "avx" Avx::cmp_f64x4(LE);
+            gt: "avx" Avx::cmp_f64x4(GT);
+            ge: "avx" Avx::cmp_f64x4(GE);
+            cmp: "avx" Avx::cmp_f64x4;
+        }
+        f64x8 / f64x4: f64 => m64x8 {
+            eq: "avx512f" Avx512F::cmp_f64x8(EQ);
+            ne: "avx512f" Avx512F::cmp_f64x8(NEQ_UNORD);
+            lt: "avx512f" Avx512F::cmp_f64x8(LT);
+            le: "avx512f" Avx512F::cmp_f64x8(LE);
+            gt: "avx512f" Avx512F::cmp_f64x8(GT);
+            ge: "avx512f" Avx512F::cmp_f64x8(GE);
+            cmp: "avx512f" Avx512F::cmp_f64x8;
+        }
+        f64x16 / f64x8: f64 => m64x16 {}
+        f64x32 / f64x16: f64 => m64x32 {}
+        f64x64 / f64x32: f64 => m64x64 {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::{assert_lanes, Rng, ROUNDS};
+
+    use super::*;
+
+    /// Check every comparison on random vectors of the given types against the
+    /// comparison operators on scalars.
+    macro_rules! check {
+        ($($name:ident),* $(,)?) => {$({
+            let mut rng = Rng::new(line!() as u64);
+
+            for _ in 0..ROUNDS {
+                let a = $name::from_array(rng.array()).to_array();
+                let mut b = $name::from_array(rng.array()).to_array();
+
+                // Make sure that some of the lanes are equal.
+                for (a, b) in a.iter().zip(&mut b) {
+                    if rng.gen() {
+                        *b = *a;
+                    }
+                }
+
+                let (va, vb) = ($name::from_array(a), $name::from_array(b));
+                let model = |f: fn(&_, &_) -> bool| -> [bool; $name::LANES] {
+                    core::array::from_fn(|i| f(&a[i], &b[i]))
+                };
+
+                assert_lanes(&va.simd_eq(vb).to_bools(), &model(PartialEq::eq), ("eq", a, b));
+                assert_lanes(&va.simd_ne(vb).to_bools(), &model(PartialEq::ne), ("ne", a, b));
+                assert_lanes(&va.simd_lt(vb).to_bools(), &model(PartialOrd::lt), ("lt", a, b));
+                assert_lanes(&va.simd_le(vb).to_bools(), &model(PartialOrd::le), ("le", a, b));
+                assert_lanes(&va.simd_gt(vb).to_bools(), &model(PartialOrd::gt), ("gt", a, b));
+                assert_lanes(&va.simd_ge(vb).to_bools(), &model(PartialOrd::ge), ("ge", a, b));
+            }
+        })*};
+    }
+
+    #[test]
+    fn int() {
+        check!(
+            u8x1, u8x8, u8x16, u8x32, u8x64, i8x16, i8x32, i8x64, u16x8, u16x16, u16x32, i16x4,
+            i16x8, i16x16, i16x32, u32x4, u32x8, u32x16, i32x4, i32x8, i32x16, i32x64, u64x2,
+            u64x4, u64x8, i64x1, i64x2, i64x4, i64x8,
+        );
+    }
+
+    #[test]
+    fn float() {
+        check!(f32x2, f32x4, f32x8, f32x16, f32x64, f64x1, f64x2, f64x4, f64x8, f64x64);
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn float_predicates() {
+        macro_rules! check_cmp {
+            ($($name:ident => $eval:ident),*) => {$({
+                let mut rng = Rng::new(line!() as u64);
+
+                for _ in 0..ROUNDS {
+                    let a = $name::from_array(rng.array()).to_array();
+                    let b = $name::from_array(rng.array()).to_array();
+                    let (va, vb) = ($name::from_array(a), $name::from_array(b));
+
+                    for cmp in (0..32).filter_map(Cmp::from_i32) {
+                        let expected: [bool; $name::LANES] =
+                            core::array::from_fn(|i| cmp.$eval(a[i], b[i]));
+
+                        assert_lanes(&va.simd_cmp(vb, cmp).to_bools(), &expected, (cmp, a, b));
+                    }
+                }
+            })*};
+        }
+
+        check_cmp!(
+            f32x1 => eval_f32, f32x4 => eval_f32, f32x8 => eval_f32, f32x16 => eval_f32,
+            f64x2 => eval_f64, f64x4 => eval_f64, f64x8 => eval_f64, f64x16 => eval_f64
+        );
+    }
+}
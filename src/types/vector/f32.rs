@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct f32x1 {
@@ -64,3 +64,5 @@ vectors! {
         half: f32x32,
     }
 }
+
+vector_impls!(f32: f32x1, f32x2, f32x4, f32x8, f32x16, f32x32, f32x64);
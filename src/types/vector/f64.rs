@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct f64x1 {
@@ -64,3 +64,5 @@ vectors! {
         half: f64x32,
     }
 }
+
+vector_impls!(f64: f64x1, f64x2, f64x4, f64x8, f64x16, f64x32, f64x64);
@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct i16x1 {
@@ -65,3 +65,5 @@ vectors! {
         half: i16x32,
     }
 }
+
+vector_impls!(i16: i16x1, i16x2, i16x4, i16x8, i16x16, i16x32, i16x64);
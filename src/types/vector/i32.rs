@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct i32x1 {
@@ -64,3 +64,5 @@ vectors! {
         half: i32x32,
     }
 }
+
+vector_impls!(i32: i32x1, i32x2, i32x4, i32x8, i32x16, i32x32, i32x64);
@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct i64x1 {
@@ -65,3 +65,5 @@ vectors! {
         half: i64x32,
     }
 }
+
+vector_impls!(i64: i64x1, i64x2, i64x4, i64x8, i64x16, i64x32, i64x64);
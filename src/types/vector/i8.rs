@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct i8x1 {
@@ -60,8 +60,10 @@ vectors! {
         half: i8x32,
 
         #[cfg(target_arch = "x86_64")]
-        avx: x86_64::__m512i,
+        avx512: x86_64::__m512i,
         #[cfg(target_arch = "x86")]
-        avx: x86::__m512i,
+        avx512: x86::__m512i,
     }
 }
+
+vector_impls!(i8: i8x1, i8x2, i8x4, i8x8, i8x16, i8x32, i8x64);
@@ -0,0 +1,472 @@
+//! Horizontal reductions of every lane of a vector into a scalar.
+//!
+//! Reductions are evaluated as a tree, by combining the lower and upper halves of the
+//! vector lane-wise until a single lane remains. As the lane-wise operators, and the
+//! minimum and maximum of the halves, use the matching token, this becomes the usual
+//! sequence of extracting and combining halves with SSE, AVX and AVX-512, with a few
+//! dedicated instructions for the last steps of sums.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::core_arch::x86;
+
+use super::*;
+
+/// Sum the bytes with `psadbw`, which adds each group of eight bytes into a 64-bit lane.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+fn sum_u8x16(vector: u8x16) -> u8 {
+    let [lo, hi] = x86::Sse2::new()
+        .sum_abs_diff_u8x16(vector, u8x16::ZERO)
+        .to_array();
+
+    lo.wrapping_add(hi) as u8
+}
+
+/// Sum the bytes with `psadbw`, as wrapping addition is the same for signed integers.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+fn sum_i8x16(vector: i8x16) -> i8 {
+    // SAFETY: Both vectors are integer vectors of the same size.
+    sum_u8x16(unsafe { crate::util::mem::transmute_unchecked::<i8x16, u8x16>(vector) }) as i8
+}
+
+/// Add the upper half to the lower half, and then add the remaining two lanes with
+/// `haddps`.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse3"
+))]
+#[inline(always)]
+fn sum_f32x4(vector: f32x4) -> f32 {
+    // SAFETY: Both vectors are 128 bits, and moving the bits of the upper half down
+    //         keeps them valid floats.
+    let hi = unsafe {
+        let bits = crate::util::mem::transmute_unchecked::<f32x4, f64x2>(vector);
+
+        crate::util::mem::transmute_unchecked::<f64x2, f32x4>(
+            x86::Sse2::new().unpack_hi_f64x2(bits, bits),
+        )
+    };
+    let sum = vector + hi;
+
+    x86::Sse3::new().hadd_f32x4(sum, sum).to_array()[0]
+}
+
+/// Add the two lanes with `haddpd`.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse3"
+))]
+#[inline(always)]
+fn sum_f64x2(vector: f64x2) -> f64 {
+    x86::Sse3::new().hadd_f64x2(vector, vector).to_array()[0]
+}
+
+macro_rules! reduce {
+    ($(
+        $kind:ident {$(
+            $name:ident $(/ $half:ident)?: $scalar:ident
+            $(= $feature:literal $sum:ident)?
+            $(, pick = $pick_feature:literal $token:ident::{$min:ident, $max:ident})?;
+        )*}
+    )*) => {$($(
+        reduce!(
+            @kind $kind $name $(/ $half)?: $scalar;
+            [$($feature $sum)?]
+            [$($pick_feature $token::{$min, $max})?]
+        );
+    )*)*};
+
+    (
+        @kind int $name:ident $(/ $half:ident)?: $scalar:ident;
+        [$($feature:literal $sum:ident)?]
+        [$($pick_feature:literal $token:ident::{$min:ident, $max:ident})?]
+    ) => {
+        impl $name {
+            /// Add every lane together, wrapping on overflow.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn reduce_sum(self) -> $scalar {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $feature,
+                    ))]
+                    return $sum(self);
+                )?
+
+                reduce!(@fold self $(/ $half)?, |lo, hi| lo + hi, reduce_sum)
+            }
+
+            /// Multiply every lane together, wrapping on overflow.
+            #[inline]
+            #[must_use]
+            pub fn reduce_product(self) -> $scalar {
+                reduce!(@fold self $(/ $half)?, |lo, hi| lo * hi, reduce_product)
+            }
+
+            /// Get the smallest lane.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn reduce_min(self) -> $scalar {
+                reduce!(
+                    @pick self $(/ $half)?, |lo, hi| lo < hi, reduce_min;
+                    [$($pick_feature $token::$min)?]
+                )
+            }
+
+            /// Get the largest lane.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn reduce_max(self) -> $scalar {
+                reduce!(
+                    @pick self $(/ $half)?, |lo, hi| lo > hi, reduce_max;
+                    [$($pick_feature $token::$max)?]
+                )
+            }
+
+            /// Get the bitwise AND of every lane.
+            #[inline]
+            #[must_use]
+            pub fn reduce_and(self) -> $scalar {
+                reduce!(@fold self $(/ $half)?, |lo, hi| lo & hi, reduce_and)
+            }
+
+            /// Get the bitwise OR of every lane.
+            #[inline]
+            #[must_use]
+            pub fn reduce_or(self) -> $scalar {
+                reduce!(@fold self $(/ $half)?, |lo, hi| lo | hi, reduce_or)
+            }
+
+            /// Get the bitwise XOR of every lane.
+            #[inline]
+            #[must_use]
+            pub fn reduce_xor(self) -> $scalar {
+                reduce!(@fold self $(/ $half)?, |lo, hi| lo ^ hi, reduce_xor)
+            }
+        }
+    };
+
+    (
+        @kind float $name:ident $(/ $half:ident)?: $scalar:ident;
+        [$($feature:literal $sum:ident)?]
+        [$($pick_feature:literal $token:ident::{$min:ident, $max:ident})?]
+    ) => {
+        impl $name {
+            /// Add every lane together.
+            ///
+            /// The upper half of the vector is added to the lower half until a single lane
+            /// remains, such that `[a, b, c, d]` is summed as `(a + c) + (b + d)`.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn reduce_sum(self) -> $scalar {
+                $(
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $feature,
+                    ))]
+                    return $sum(self);
+                )?
+
+                reduce!(@fold self $(/ $half)?, |lo, hi| lo + hi, reduce_sum)
+            }
+
+            /// Multiply every lane together.
+            ///
+            /// The upper half of the vector is multiplied with the lower half until a
+            /// single lane remains, such that `[a, b, c, d]` is multiplied as
+            /// `(a * c) * (b * d)`.
+            #[inline]
+            #[must_use]
+            pub fn reduce_product(self) -> $scalar {
+                reduce!(@fold self $(/ $half)?, |lo, hi| lo * hi, reduce_product)
+            }
+
+            /// Get the smallest lane.
+            ///
+            /// The lanes of the lower and upper halves of the vector are compared until a
+            /// single lane remains, keeping the lane of the upper half unless the lane of
+            /// the lower half is less than it, as `minps` does. So whether a NaN lane is
+            /// returned depends on its position.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn reduce_min(self) -> $scalar {
+                reduce!(
+                    @pick self $(/ $half)?, |lo, hi| lo < hi, reduce_min;
+                    [$($pick_feature $token::$min)?]
+                )
+            }
+
+            /// Get the largest lane.
+            ///
+            /// The lanes of the lower and upper halves of the vector are compared until a
+            /// single lane remains, keeping the lane of the upper half unless the lane of
+            /// the lower half is greater than it, as `maxps` does. So whether a NaN lane
+            /// is returned depends on its position.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn reduce_max(self) -> $scalar {
+                reduce!(
+                    @pick self $(/ $half)?, |lo, hi| lo > hi, reduce_max;
+                    [$($pick_feature $token::$max)?]
+                )
+            }
+        }
+    };
+
+    (@fold $self:ident / $half:ident, |$lo:ident, $hi:ident| $op:expr, $method:ident) => {{
+        let [$lo, $hi] = $self.to_halves();
+
+        $op.$method()
+    }};
+    (@fold $self:ident, |$lo:ident, $hi:ident| $op:expr, $method:ident) => {
+        $self.to_array()[0]
+    };
+
+    (
+        @pick $self:ident / $half:ident, |$lo:ident, $hi:ident| $keep:expr, $method:ident;
+        [$($feature:literal $token:ident::$pick:ident)?]
+    ) => {{
+        let [lo, hi] = $self.to_halves();
+
+        $(
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = $feature,
+            ))]
+            return x86::$token::new().$pick(lo, hi).$method();
+        )?
+
+        let (lo, hi) = (lo.to_array(), hi.to_array());
+
+        $half::from_fn(|i| {
+            let ($lo, $hi) = (lo[i], hi[i]);
+
+            if $keep {
+                $lo
+            } else {
+                $hi
+            }
+        })
+        .$method()
+    }};
+    (@pick $self:ident, |$lo:ident, $hi:ident| $keep:expr, $method:ident; []) => {
+        $self.to_array()[0]
+    };
+}
+
+reduce! {
+    int {
+        u8x1: u8;
+        u8x2 / u8x1: u8;
+        u8x4 / u8x2: u8;
+        u8x8 / u8x4: u8;
+        u8x16 / u8x8: u8 = "sse2" sum_u8x16;
+        u8x32 / u8x16: u8, pick = "sse2" Sse2::{min_u8x16, max_u8x16};
+        u8x64 / u8x32: u8, pick = "avx2" Avx2::{min_u8x32, max_u8x32};
+
+        i8x1: i8;
+        i8x2 / i8x1: i8;
+        i8x4 / i8x2: i8;
+        i8x8 / i8x4: i8;
+        i8x16 / i8x8: i8 = "sse2" sum_i8x16;
+        i8x32 / i8x16: i8, pick = "sse4.1" Sse41::{min_i8x16, max_i8x16};
+        i8x64 / i8x32: i8, pick = "avx2" Avx2::{min_i8x32, max_i8x32};
+
+        u16x1: u16;
+        u16x2 / u16x1: u16;
+        u16x4 / u16x2: u16;
+        u16x8 / u16x4: u16;
+        u16x16 / u16x8: u16, pick = "sse4.1" Sse41::{min_u16x8, max_u16x8};
+        u16x32 / u16x16: u16, pick = "avx2" Avx2::{min_u16x16, max_u16x16};
+        u16x64 / u16x32: u16, pick = "avx512bw" Avx512BW::{min_u16x32, max_u16x32};
+
+        i16x1: i16;
+        i16x2 / i16x1: i16;
+        i16x4 / i16x2: i16;
+        i16x8 / i16x4: i16;
+        i16x16 / i16x8: i16, pick = "sse2" Sse2::{min_i16x8, max_i16x8};
+        i16x32 / i16x16: i16, pick = "avx2" Avx2::{min_i16x16, max_i16x16};
+        i16x64 / i16x32: i16, pick = "avx512bw" Avx512BW::{min_i16x32, max_i16x32};
+
+        u32x1: u32;
+        u32x2 / u32x1: u32;
+        u32x4 / u32x2: u32;
+        u32x8 / u32x4: u32, pick = "sse4.1" Sse41::{min_u32x4, max_u32x4};
+        u32x16 / u32x8: u32, pick = "avx2" Avx2::{min_u32x8, max_u32x8};
+        u32x32 / u32x16: u32, pick = "avx512f" Avx512F::{min_u32x16, max_u32x16};
+        u32x64 / u32x32: u32;
+
+        i32x1: i32;
+        i32x2 / i32x1: i32;
+        i32x4 / i32x2: i32;
+        i32x8 / i32x4: i32, pick = "sse4.1" Sse41::{min_i32x4, max_i32x4};
+        i32x16 / i32x8: i32, pick = "avx2" Avx2::{min_i32x8, max_i32x8};
+        i32x32 / i32x16: i32, pick = "avx512f" Avx512F::{min_i32x16, max_i32x16};
+        i32x64 / i32x32: i32;
+
+        u64x1: u64;
+        u64x2 / u64x1: u64;
+        u64x4 / u64x2: u64;
+        u64x8 / u64x4: u64;
+        u64x16 / u64x8: u64, pick = "avx512f" Avx512F::{min_u64x8, max_u64x8};
+        u64x32 / u64x16: u64;
+        u64x64 / u64x32: u64;
+
+        i64x1: i64;
+        i64x2 / i64x1: i64;
+        i64x4 / i64x2: i64;
+        i64x8 / i64x4: i64;
+        i64x16 / i64x8: i64, pick = "avx512f" Avx512F::{min_i64x8, max_i64x8};
+        i64x32 / i64x16: i64;
+        i64x64 / i64x32: i64;
+    }
+    float {
+        f32x1: f32;
+        f32x2 / f32x1: f32;
+        f32x4 / f32x2: f32 = "sse3" sum_f32x4;
+        f32x8 / f32x4: f32, pick = "sse" Sse::{min_f32x4, max_f32x4};
+        f32x16 / f32x8: f32, pick = "avx" Avx::{min_f32x8, max_f32x8};
+        f32x32 / f32x16: f32, pick = "avx512f" Avx512F::{min_f32x16, max_f32x16};
+        f32x64 / f32x32: f32;
+
+        f64x1: f64;
+        f64x2 / f64x1: f64 = "sse3" sum_f64x2;
+        f64x4 / f64x2: f64, pick = "sse2" Sse2::{min_f64x2, max_f64x2};
+        f64x8 / f64x4: f64, pick = "avx" Avx::{min_f64x4, max_f64x4};
+        f64x16 / f64x8: f64, pick = "avx512f" Avx512F::{min_f64x8, max_f64x8};
+        f64x32 / f64x16: f64;
+        f64x64 / f64x32: f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::{assert_lanes, Rng, ROUNDS};
+
+    use super::*;
+
+    /// Combine the lower and upper halves lane-wise until a single lane remains.
+    fn tree<T: Copy>(lanes: &[T], op: fn(T, T) -> T) -> T {
+        if let [lane] = lanes {
+            return *lane;
+        }
+
+        let (lo, hi) = lanes.split_at(lanes.len() / 2);
+        let lanes: Vec<T> = lo.iter().zip(hi).map(|(&lo, &hi)| op(lo, hi)).collect();
+
+        tree(&lanes, op)
+    }
+
+    /// Check every reduction against combining the lanes in the same order.
+    macro_rules! check {
+        ($($name:ident: $($method:ident = $op:expr),+;)*) => {$({
+            let mut rng = Rng::new(line!() as u64);
+
+            for _ in 0..ROUNDS {
+                let vector = $name::from_array(rng.array());
+
+                $(
+                    assert_lanes(
+                        &[vector.$method()],
+                        &[tree(&vector.to_array(), $op)],
+                        (stringify!($name), stringify!($method), vector),
+                    );
+                )+
+            }
+        })*};
+        (int: $($name:ident),*) => {
+            check! {$(
+                $name:
+                    reduce_sum = |a, b| a.wrapping_add(b),
+                    reduce_product = |a, b| a.wrapping_mul(b),
+                    reduce_min = |a, b| a.min(b),
+                    reduce_max = |a, b| a.max(b),
+                    reduce_and = |a, b| a & b,
+                    reduce_or = |a, b| a | b,
+                    reduce_xor = |a, b| a ^ b;
+            )*}
+        };
+        (float: $($name:ident),*) => {
+            check! {$(
+                $name:
+                    reduce_sum = |a, b| a + b,
+                    reduce_product = |a, b| a * b,
+                    reduce_min = |a, b| if a < b { a } else { b },
+                    reduce_max = |a, b| if a > b { a } else { b };
+            )*}
+        };
+    }
+
+    #[test]
+    fn int() {
+        check!(int: u8x1, u8x2, u8x4, u8x8, u8x16, u8x32, u8x64);
+        check!(int: i8x1, i8x2, i8x4, i8x8, i8x16, i8x32, i8x64);
+        check!(int: u16x1, u16x2, u16x4, u16x8, u16x16, u16x32, u16x64);
+        check!(int: i16x1, i16x2, i16x4, i16x8, i16x16, i16x32, i16x64);
+        check!(int: u32x1, u32x2, u32x4, u32x8, u32x16, u32x32, u32x64);
+        check!(int: i32x1, i32x2, i32x4, i32x8, i32x16, i32x32, i32x64);
+        check!(int: u64x1, u64x2, u64x4, u64x8, u64x16, u64x32, u64x64);
+        check!(int: i64x1, i64x2, i64x4, i64x8, i64x16, i64x32, i64x64);
+    }
+
+    #[test]
+    fn float() {
+        check!(float: f32x1, f32x2, f32x4, f32x8, f32x16, f32x32, f32x64);
+        check!(float: f64x1, f64x2, f64x4, f64x8, f64x16, f64x32, f64x64);
+    }
+
+    #[test]
+    fn float_order() {
+        // `(1e20 + -1e20) + (1.0 + 1.0)`, where adding in lane order would lose both ones,
+        // and `(1e300 * 1e-300) * (1e300 * 1e-300)`, which would overflow in lane order.
+        assert_eq!(f32x4::from_array([1e20, 1.0, -1e20, 1.0]).reduce_sum(), 2.0);
+        assert_eq!(f64x2::from_array([1e300, -1e300]).reduce_sum(), 0.0);
+        assert_eq!(
+            f32x8::from_array([1e20, 1.0, 0.0, 0.0, -1e20, 1.0, 0.0, 0.0]).reduce_sum(),
+            2.0
+        );
+        assert_eq!(
+            f64x4::from_array([1e300, 1e300, 1e-300, 1e-300]).reduce_product(),
+            1.0
+        );
+
+        // The lane of the upper half is kept unless the lower one is less than it.
+        assert_eq!(
+            f32x4::from_array([f32::NAN, 1.0, 2.0, 3.0]).reduce_min(),
+            1.0
+        );
+        assert_eq!(
+            f32x4::from_array([1.0, 2.0, f32::NAN, 3.0]).reduce_min(),
+            2.0
+        );
+        assert!(f32x4::from_array([1.0, 2.0, 3.0, f32::NAN])
+            .reduce_min()
+            .is_nan());
+        assert!(f64x2::from_array([1.0, f64::NAN]).reduce_max().is_nan());
+        assert_eq!(f64x2::from_array([f64::NAN, 1.0]).reduce_max(), 1.0);
+
+        assert!(f32x2::from_array([0.0, -0.0])
+            .reduce_min()
+            .is_sign_negative());
+        assert!(f32x2::from_array([-0.0, 0.0])
+            .reduce_min()
+            .is_sign_positive());
+        assert!(f64x8::from_fn(|i| if i < 4 { -0.0 } else { 0.0 })
+            .reduce_max()
+            .is_sign_positive());
+    }
+}
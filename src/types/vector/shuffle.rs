@@ -0,0 +1,511 @@
+//! Static shuffles of the lanes of one or two vectors.
+//!
+//! The indices of a shuffle are known at compile time, and are expanded into a constant
+//! index vector for the widest permute instruction available for the vector's size.
+//! Otherwise, the lanes are gathered from arrays, which LLVM lowers to its own shuffles.
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "ssse3"
+))]
+use crate::core_arch::x86;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+))]
+use crate::types::mask::*;
+
+use super::*;
+
+/// Panic if any of the indices isn't less than `bound`.
+const fn check<const N: usize>(indices: [usize; N], bound: usize) {
+    let mut i = 0;
+
+    while i < N {
+        assert!(indices[i] < bound, "shuffle index is out of bounds");
+        i += 1;
+    }
+}
+
+/// Split each of `N` lane indices into the indices of the `M / N` units in that lane.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "ssse3"
+))]
+const fn expand<const N: usize, const M: usize>(indices: [usize; N]) -> [usize; M] {
+    let ratio = M / N;
+    let mut units = [0; M];
+    let mut i = 0;
+
+    while i < M {
+        units[i] = indices[i / ratio] * ratio + i % ratio;
+        i += 1;
+    }
+
+    units
+}
+
+/// Get byte indices relative to `offset`, where bytes outside of the 16 bytes from
+/// `offset` are zeroed.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "ssse3"
+))]
+const fn bytes(units: [usize; 16], offset: usize) -> u8x16 {
+    let mut bytes = [0; 16];
+    let mut i = 0;
+
+    while i < 16 {
+        bytes[i] = if units[i] >= offset && units[i] < offset + 16 {
+            (units[i] - offset) as u8
+        } else {
+            0x80
+        };
+        i += 1;
+    }
+
+    u8x16::from_array(bytes)
+}
+
+/// Get 32-bit lane indices modulo `modulo`.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+))]
+const fn dwords<const M: usize>(units: [usize; M], modulo: usize) -> [u32; M] {
+    let mut dwords = [0; M];
+    let mut i = 0;
+
+    while i < M {
+        dwords[i] = (units[i] % modulo) as u32;
+        i += 1;
+    }
+
+    dwords
+}
+
+/// Get whether each unit is at least `bound`, and so comes from the second vector.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+))]
+const fn second<const M: usize>(units: [usize; M], bound: usize) -> [bool; M] {
+    let mut second = [false; M];
+    let mut i = 0;
+
+    while i < M {
+        second[i] = units[i] >= bound;
+        i += 1;
+    }
+
+    second
+}
+
+macro_rules! swizzles {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident$(<const $param:ident: usize>)? = |$i:ident, $n:ident| $index:expr;
+    )*) => {$(
+        $(#[$meta])*
+        struct $name$(<const $param: usize>)?;
+
+        impl<const $n: usize $(, const $param: usize)?> Swizzle<$n> for $name$(<$param>)? {
+            const INDICES: [usize; $n] = {
+                let mut indices = [0; $n];
+                let mut $i = 0;
+
+                while $i < $n {
+                    indices[$i] = $index;
+                    $i += 1;
+                }
+
+                indices
+            };
+        }
+    )*};
+}
+
+swizzles! {
+    /// The lanes in reverse order.
+    Reverse = |i, N| N - 1 - i;
+    /// The lanes rotated towards the start by `K` lanes.
+    RotateLeft<const K: usize> = |i, N| (i + K % N) % N;
+    /// The lanes rotated towards the end by `K` lanes.
+    RotateRight<const K: usize> = |i, N| (i + N - K % N) % N;
+    /// The lanes of the lower halves of two vectors, alternating between them.
+    InterleaveLo = |i, N| i / 2 + (i % 2) * N;
+    /// The lanes of the upper halves of two vectors, alternating between them.
+    InterleaveHi = |i, N| (i + N) / 2 + ((i + N) % 2) * N;
+    /// The even lanes of two vectors, one after the other.
+    DeinterleaveEven = |i, N| 2 * i;
+    /// The odd lanes of two vectors, one after the other.
+    DeinterleaveOdd = |i, N| 2 * i + 1;
+}
+
+macro_rules! shuffle {
+    ($($kind:ident { $($name:ident),* $(,)? })*) => {$($(
+        impl $name {
+            /// Shuffle the lanes of this vector, such that lane `i` of the result is lane
+            /// `S::INDICES[i]` of `self`.
+            ///
+            /// This fails to compile if any of the indices is out of bounds.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn shuffle<S: Swizzle<{ $name::LANES }>>(self) -> $name {
+                const { check(S::INDICES, $name::LANES) };
+
+                shuffle!(@one $kind $name(self) S);
+
+                let a = self.to_array();
+
+                $name::from_fn(|i| a[S::INDICES[i]])
+            }
+
+            /// Shuffle the lanes of this vector and `other`, such that lane `i` of the
+            /// result is lane `S::INDICES[i]` of `self` followed by `other`.
+            ///
+            /// This fails to compile if any of the indices is out of bounds.
+            #[inline]
+            #[must_use]
+            #[allow(unreachable_code)]
+            pub fn shuffle2<S: Swizzle<{ $name::LANES }>>(self, other: $name) -> $name {
+                const { check(S::INDICES, 2 * $name::LANES) };
+
+                shuffle!(@two $kind $name(self, other) S);
+
+                let (a, b) = (self.to_array(), other.to_array());
+
+                $name::from_fn(|i| match S::INDICES[i] {
+                    index if index < $name::LANES => a[index],
+                    index => b[index - $name::LANES],
+                })
+            }
+
+            /// Reverse the order of the lanes.
+            #[inline]
+            #[must_use]
+            pub fn reverse(self) -> $name {
+                self.shuffle::<Reverse>()
+            }
+
+            /// Rotate the lanes towards the start by `K` lanes, such that lane `i` of the
+            /// result is lane `(i + K) % LANES`.
+            #[inline]
+            #[must_use]
+            pub fn rotate_lanes_left<const K: usize>(self) -> $name {
+                self.shuffle::<RotateLeft<K>>()
+            }
+
+            /// Rotate the lanes towards the end by `K` lanes, such that lane `(i + K) %
+            /// LANES` of the result is lane `i`.
+            #[inline]
+            #[must_use]
+            pub fn rotate_lanes_right<const K: usize>(self) -> $name {
+                self.shuffle::<RotateRight<K>>()
+            }
+
+            /// Interleave the lanes of the lower halves of this vector and `other`, as
+            /// in `[self[0], other[0], self[1], other[1], ..]`.
+            #[inline]
+            #[must_use]
+            pub fn interleave_lo(self, other: $name) -> $name {
+                self.shuffle2::<InterleaveLo>(other)
+            }
+
+            /// Interleave the lanes of the upper halves of this vector and `other`.
+            #[inline]
+            #[must_use]
+            pub fn interleave_hi(self, other: $name) -> $name {
+                self.shuffle2::<InterleaveHi>(other)
+            }
+
+            /// Interleave the lanes of this vector and `other`, returning the lower and
+            /// upper halves of the result.
+            #[inline]
+            #[must_use]
+            pub fn zip(self, other: $name) -> ($name, $name) {
+                (self.interleave_lo(other), self.interleave_hi(other))
+            }
+
+            /// Get the even lanes of this vector followed by the even lanes of `other`.
+            #[inline]
+            #[must_use]
+            pub fn deinterleave_even(self, other: $name) -> $name {
+                self.shuffle2::<DeinterleaveEven>(other)
+            }
+
+            /// Get the odd lanes of this vector followed by the odd lanes of `other`.
+            #[inline]
+            #[must_use]
+            pub fn deinterleave_odd(self, other: $name) -> $name {
+                self.shuffle2::<DeinterleaveOdd>(other)
+            }
+
+            /// Deinterleave the lanes of this vector and `other`, returning the even and
+            /// odd lanes. This is the inverse of [`zip`](Self::zip).
+            #[inline]
+            #[must_use]
+            pub fn unzip(self, other: $name) -> ($name, $name) {
+                (self.deinterleave_even(other), self.deinterleave_odd(other))
+            }
+        }
+    )*)*};
+
+    (@one bytes $name:ident($a:ident) $s:ident) => {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "ssse3",
+        ))]
+        {
+            let idx = const { bytes(expand::<{ $name::LANES }, 16>($s::INDICES), 0) };
+
+            // SAFETY: Both vectors are 128 bits, and any bit pattern is a valid byte.
+            return unsafe {
+                let a = crate::util::mem::transmute_unchecked::<$name, u8x16>($a);
+
+                crate::util::mem::transmute_unchecked::<u8x16, $name>(
+                    x86::Ssse3::new().shuffle_bytes_u8x16(a, idx),
+                )
+            };
+        }
+    };
+    (@two bytes $name:ident($a:ident, $b:ident) $s:ident) => {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "ssse3",
+        ))]
+        {
+            let a_idx = const { bytes(expand::<{ $name::LANES }, 16>($s::INDICES), 0) };
+            let b_idx = const { bytes(expand::<{ $name::LANES }, 16>($s::INDICES), 16) };
+            let token = x86::Ssse3::new();
+
+            // SAFETY: Both vectors are 128 bits, and any bit pattern is a valid byte.
+            return unsafe {
+                let a = crate::util::mem::transmute_unchecked::<$name, u8x16>($a);
+                let b = crate::util::mem::transmute_unchecked::<$name, u8x16>($b);
+
+                crate::util::mem::transmute_unchecked::<u8x16, $name>(
+                    token.shuffle_bytes_u8x16(a, a_idx) | token.shuffle_bytes_u8x16(b, b_idx),
+                )
+            };
+        }
+    };
+
+    (@one avx2 $name:ident($a:ident) $s:ident) => {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx2",
+        ))]
+        {
+            let idx = const {
+                u32x8::from_array(dwords(expand::<{ $name::LANES }, 8>($s::INDICES), 8))
+            };
+
+            // SAFETY: Both vectors are 256 bits, and any bit pattern is a valid integer.
+            return unsafe {
+                let a = crate::util::mem::transmute_unchecked::<$name, u32x8>($a);
+
+                crate::util::mem::transmute_unchecked::<u32x8, $name>(
+                    x86::Avx2::new().permute_lanes_u32x8(a, idx),
+                )
+            };
+        }
+    };
+    (@two avx2 $name:ident($a:ident, $b:ident) $s:ident) => {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx2",
+        ))]
+        {
+            let idx = const {
+                u32x8::from_array(dwords(expand::<{ $name::LANES }, 8>($s::INDICES), 8))
+            };
+            let mask = const {
+                m32x8::from_bools(second(expand::<{ $name::LANES }, 8>($s::INDICES), 8))
+            };
+            let token = x86::Avx2::new();
+
+            // SAFETY: Both vectors are 256 bits, and any bit pattern is a valid integer.
+            return unsafe {
+                let a = crate::util::mem::transmute_unchecked::<$name, u32x8>($a);
+                let b = crate::util::mem::transmute_unchecked::<$name, u32x8>($b);
+
+                crate::util::mem::transmute_unchecked::<u32x8, $name>(mask.select(
+                    token.permute_lanes_u32x8(b, idx),
+                    token.permute_lanes_u32x8(a, idx),
+                ))
+            };
+        }
+    };
+
+    (@one avx512f $name:ident($a:ident) $s:ident) => {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx512f",
+        ))]
+        {
+            let idx = const {
+                u32x16::from_array(dwords(expand::<{ $name::LANES }, 16>($s::INDICES), 16))
+            };
+
+            // SAFETY: Both vectors are 512 bits, and any bit pattern is a valid integer.
+            return unsafe {
+                let a = crate::util::mem::transmute_unchecked::<$name, u32x16>($a);
+
+                crate::util::mem::transmute_unchecked::<u32x16, $name>(
+                    x86::Avx512F::new().permute_lanes_u32x16(a, idx),
+                )
+            };
+        }
+    };
+    (@two avx512f $name:ident($a:ident, $b:ident) $s:ident) => {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx512f",
+        ))]
+        {
+            let idx = const {
+                u32x16::from_array(dwords(expand::<{ $name::LANES }, 16>($s::INDICES), 32))
+            };
+
+            // SAFETY: Both vectors are 512 bits, and any bit pattern is a valid integer.
+            return unsafe {
+                let a = crate::util::mem::transmute_unchecked::<$name, u32x16>($a);
+                let b = crate::util::mem::transmute_unchecked::<$name, u32x16>($b);
+
+                crate::util::mem::transmute_unchecked::<u32x16, $name>(
+                    x86::Avx512F::new().permute_lanes2_u32x16(a, idx, b),
+                )
+            };
+        }
+    };
+
+    (@$arity:ident portable $name:ident($($arg:ident),*) $s:ident) => {};
+}
+
+shuffle! {
+    bytes {
+        u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2,
+    }
+    avx2 {
+        u32x8, i32x8, f32x8, u64x4, i64x4, f64x4,
+    }
+    avx512f {
+        u32x16, i32x16, f32x16, u64x8, i64x8, f64x8,
+    }
+    portable {
+        u8x1, u8x2, u8x4, u8x8, u8x32, u8x64, i8x1, i8x2, i8x4, i8x8, i8x32, i8x64, u16x1,
+        u16x2, u16x4, u16x16, u16x32, u16x64, i16x1, i16x2, i16x4, i16x16, i16x32, i16x64,
+        u32x1, u32x2, u32x32, u32x64, i32x1, i32x2, i32x32, i32x64, f32x1, f32x2, f32x32,
+        f32x64, u64x1, u64x16, u64x32, u64x64, i64x1, i64x16, i64x32, i64x64, f64x1, f64x16,
+        f64x32, f64x64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::{assert_lanes, Rng};
+
+    use super::*;
+
+    swizzles! {
+        /// Lanes of one vector in a scattered order, with repeats.
+        Scatter = |i, N| (i * 5 + 3) % N;
+        /// Lanes of two vectors in a scattered order, starting from the second one.
+        Scatter2 = |i, N| (i * 7 + N) % (2 * N);
+        /// The last lane of the second vector in every lane.
+        LastOfSecond = |_i, N| 2 * N - 1;
+    }
+
+    /// Check shuffles of one and two vectors against gathering lanes from arrays.
+    macro_rules! check {
+        ($($name:ident),* $(,)?) => {$({
+            let mut rng = Rng::new(line!() as u64);
+            let (a, b) = ($name::from_array(rng.array()), $name::from_array(rng.array()));
+            let lanes: Vec<_> = a.to_array().into_iter().chain(b.to_array()).collect();
+
+            let gather = |indices: &[usize]| indices.iter().map(|&i| lanes[i]).collect::<Vec<_>>();
+            let context = (stringify!($name), a, b);
+
+            assert_lanes(
+                &a.shuffle::<Scatter>().to_array(),
+                &gather(&<Scatter as Swizzle<{ $name::LANES }>>::INDICES),
+                context,
+            );
+            assert_lanes(
+                &a.shuffle2::<Scatter2>(b).to_array(),
+                &gather(&<Scatter2 as Swizzle<{ $name::LANES }>>::INDICES),
+                context,
+            );
+            assert_lanes(
+                &a.shuffle2::<LastOfSecond>(b).to_array(),
+                &gather(&<LastOfSecond as Swizzle<{ $name::LANES }>>::INDICES),
+                context,
+            );
+            assert_lanes(&a.reverse().to_array(), &gather(&<Reverse as Swizzle<{ $name::LANES }>>::INDICES), context);
+            assert_lanes(
+                &a.rotate_lanes_left::<3>().to_array(),
+                &gather(&<RotateLeft<3> as Swizzle<{ $name::LANES }>>::INDICES),
+                context,
+            );
+            assert_lanes(
+                &a.rotate_lanes_right::<3>().to_array(),
+                &gather(&<RotateRight<3> as Swizzle<{ $name::LANES }>>::INDICES),
+                context,
+            );
+
+            let (lo, hi) = a.zip(b);
+            let (even, odd) = lo.unzip(hi);
+
+            assert_lanes(&even.to_array(), &a.to_array(), context);
+            assert_lanes(&odd.to_array(), &b.to_array(), context);
+        })*};
+    }
+
+    #[test]
+    fn bytes() {
+        check!(u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2);
+    }
+
+    #[test]
+    fn avx2() {
+        check!(u32x8, i32x8, f32x8, u64x4, i64x4, f64x4);
+    }
+
+    #[test]
+    fn avx512f() {
+        check!(u32x16, i32x16, f32x16, u64x8, i64x8, f64x8);
+    }
+
+    #[test]
+    fn portable() {
+        check!(u8x1, u8x2, u8x8, u8x32, u8x64, i16x16, u32x2, f32x32, u64x1, f64x16, i64x64);
+    }
+
+    #[test]
+    fn named() {
+        let a = u32x8::from_fn(|i| i as u32);
+        let b = u32x8::from_fn(|i| 10 + i as u32);
+
+        assert_eq!(a.reverse().to_array(), [7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(
+            a.rotate_lanes_left::<3>().to_array(),
+            [3, 4, 5, 6, 7, 0, 1, 2]
+        );
+        assert_eq!(
+            a.rotate_lanes_right::<11>().to_array(),
+            [5, 6, 7, 0, 1, 2, 3, 4]
+        );
+        assert_eq!(a.interleave_lo(b).to_array(), [0, 10, 1, 11, 2, 12, 3, 13]);
+        assert_eq!(a.interleave_hi(b).to_array(), [4, 14, 5, 15, 6, 16, 7, 17]);
+        assert_eq!(
+            a.deinterleave_even(b).to_array(),
+            [0, 2, 4, 6, 10, 12, 14, 16]
+        );
+        assert_eq!(
+            a.deinterleave_odd(b).to_array(),
+            [1, 3, 5, 7, 11, 13, 15, 17]
+        );
+    }
+}
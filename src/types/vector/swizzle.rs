@@ -0,0 +1,259 @@
+//! Byte lookups with indices known only at runtime, as used for nibble-based
+//! classification.
+//!
+//! Every index that is out of bounds results in zero. The wider vectors can either look
+//! up bytes from the whole vector, or from within each 128-bit lane, which maps to a
+//! single `pshufb` for every lane.
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "ssse3"
+))]
+use crate::core_arch::x86;
+
+use super::*;
+
+impl u8x16 {
+    /// Look up a byte of this vector for every lane of `idx`, such that lane `i` of the
+    /// result is `self[idx[i]]`, or zero if `idx[i]` is out of bounds.
+    #[inline]
+    #[must_use]
+    #[allow(unreachable_code)]
+    pub fn swizzle_dyn(self, idx: u8x16) -> u8x16 {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "ssse3",
+        ))]
+        {
+            // Adding `0x70` with saturation keeps the lowest four bits of the valid indices,
+            // and sets the highest bit of every other index, so that they result in zero.
+            let idx = x86::Sse2::new().saturating_add_u8x16(idx, u8x16::splat(0x70));
+
+            return x86::Ssse3::new().shuffle_bytes_u8x16(self, idx);
+        }
+
+        let (table, idx) = (self.to_array(), idx.to_array());
+
+        u8x16::from_fn(|i| table.get(idx[i] as usize).copied().unwrap_or(0))
+    }
+}
+
+impl u8x32 {
+    /// Look up a byte of this vector for every lane of `idx`, such that lane `i` of the
+    /// result is `self[idx[i]]`, or zero if `idx[i]` is out of bounds.
+    ///
+    /// Bytes can be taken from across the whole vector, which takes several instructions
+    /// with AVX2. Use [`swizzle_dyn_128`](Self::swizzle_dyn_128) when every lookup
+    /// table fits in 16 bytes.
+    #[inline]
+    #[must_use]
+    #[allow(unreachable_code)]
+    pub fn swizzle_dyn(self, idx: u8x32) -> u8x32 {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx2",
+        ))]
+        {
+            let token = x86::Avx2::new();
+            let offset = u8x32::splat(0x70);
+
+            // SAFETY: Both vectors are integer vectors of the same size.
+            let (lo, hi) = unsafe {
+                let table = crate::util::mem::transmute_unchecked::<u8x32, u64x4>(self);
+
+                (
+                    crate::util::mem::transmute_unchecked::<u64x4, u8x32>(
+                        token.permute_lanes_u64x4::<0x44>(table),
+                    ),
+                    crate::util::mem::transmute_unchecked::<u64x4, u8x32>(
+                        token.permute_lanes_u64x4::<0xee>(table),
+                    ),
+                )
+            };
+
+            // Look up the indices below 16 in the lower half, and flip bit 4 of the indices
+            // to look up those from 16 to 31 in the upper half.
+            let lo = token.shuffle_bytes_u8x32(lo, token.saturating_add_u8x32(idx, offset));
+            let hi = token.shuffle_bytes_u8x32(hi, token.saturating_add_u8x32(idx ^ 0x10, offset));
+
+            return lo | hi;
+        }
+
+        let [lo, hi] = self.to_halves();
+        let lookup = |idx: u8x16| lo.swizzle_dyn(idx) | hi.swizzle_dyn(idx ^ 0x10);
+        let [a, b] = idx.to_halves();
+
+        u8x32::from_halves(lookup(a), lookup(b))
+    }
+
+    /// Look up a byte within the same 128-bit lane of this vector for every lane of
+    /// `idx`, such that lane `i` of the result is `self[i / 16 * 16 + idx[i]]`, or zero if
+    /// `idx[i]` is not less than 16.
+    #[inline]
+    #[must_use]
+    #[allow(unreachable_code)]
+    pub fn swizzle_dyn_128(self, idx: u8x32) -> u8x32 {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx2",
+        ))]
+        {
+            let token = x86::Avx2::new();
+
+            return token
+                .shuffle_bytes_u8x32(self, token.saturating_add_u8x32(idx, u8x32::splat(0x70)));
+        }
+
+        let ([lo, hi], [a, b]) = (self.to_halves(), idx.to_halves());
+
+        u8x32::from_halves(lo.swizzle_dyn(a), hi.swizzle_dyn(b))
+    }
+}
+
+impl u8x64 {
+    /// Look up a byte of this vector for every lane of `idx`, such that lane `i` of the
+    /// result is `self[idx[i]]`, or zero if `idx[i]` is out of bounds.
+    ///
+    /// Bytes can be taken from across the whole vector, which is a single instruction
+    /// with AVX-512VBMI, but takes several otherwise. Use
+    /// [`swizzle_dyn_128`](Self::swizzle_dyn_128) when every lookup table fits in 16
+    /// bytes.
+    #[inline]
+    #[must_use]
+    #[allow(unreachable_code)]
+    pub fn swizzle_dyn(self, idx: u8x64) -> u8x64 {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx512vbmi",
+        ))]
+        {
+            let k = x86::Avx512BW::new().lt_u8x64(idx, u8x64::splat(64));
+
+            return x86::Avx512Vbmi::new().permute_bytes_u8x64_maskz(k, self, idx);
+        }
+
+        let [lo, hi] = self.to_halves();
+        let lookup = |idx: u8x32| lo.swizzle_dyn(idx) | hi.swizzle_dyn(idx ^ 0x20);
+        let [a, b] = idx.to_halves();
+
+        u8x64::from_halves(lookup(a), lookup(b))
+    }
+
+    /// Look up a byte within the same 128-bit lane of this vector for every lane of
+    /// `idx`, such that lane `i` of the result is `self[i / 16 * 16 + idx[i]]`, or zero if
+    /// `idx[i]` is not less than 16.
+    #[inline]
+    #[must_use]
+    #[allow(unreachable_code)]
+    pub fn swizzle_dyn_128(self, idx: u8x64) -> u8x64 {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx512bw",
+        ))]
+        {
+            let token = x86::Avx512BW::new();
+
+            return token
+                .shuffle_bytes_u8x64(self, token.saturating_add_u8x64(idx, u8x64::splat(0x70)));
+        }
+
+        let ([lo, hi], [a, b]) = (self.to_halves(), idx.to_halves());
+
+        u8x64::from_halves(lo.swizzle_dyn_128(a), hi.swizzle_dyn_128(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::{assert_lanes, Rng, ROUNDS};
+
+    use super::*;
+
+    /// Indices around the edges: the last valid and first invalid ones for every width,
+    /// and those that overflow into the highest bit when offset by `0x70`.
+    const EDGES: [u8; 12] = [0, 15, 16, 31, 32, 63, 64, 0x7f, 0x80, 0x8f, 0x90, 0xff];
+
+    /// Random indices, mostly below `len`, with some just above it and at the edges.
+    fn indices<const N: usize>(rng: &mut Rng, len: usize) -> [u8; N] {
+        core::array::from_fn(|_| {
+            let bits = rng.next_u64();
+
+            match bits % 4 {
+                0 => EDGES[(bits >> 8) as usize % EDGES.len()],
+                1 => ((bits >> 8) % (2 * len as u64)) as u8,
+                _ => ((bits >> 8) % len as u64) as u8,
+            }
+        })
+    }
+
+    /// Look up bytes from across the whole table.
+    fn model(table: &[u8], idx: &[u8]) -> Vec<u8> {
+        idx.iter()
+            .map(|&idx| table.get(idx as usize).copied().unwrap_or(0))
+            .collect()
+    }
+
+    /// Look up bytes from within the 16 bytes of the same 128-bit lane.
+    fn model_128(table: &[u8], idx: &[u8]) -> Vec<u8> {
+        table
+            .chunks(16)
+            .zip(idx.chunks(16))
+            .flat_map(|(table, idx)| model(table, idx))
+            .collect()
+    }
+
+    /// Check a lookup with every index, and with random tables and indices.
+    macro_rules! check {
+        ($method:ident, $model:ident: $($name:ident),*) => {$({
+            let mut rng = Rng::new(line!() as u64);
+            let table = $name::from_array(rng.array());
+
+            for start in (0..=255).step_by($name::LANES) {
+                let idx = $name::from_fn(|i| (start + i) as u8);
+
+                assert_lanes(
+                    &table.$method(idx).to_array(),
+                    &$model(&table.to_array(), &idx.to_array()),
+                    (stringify!($name), table, idx),
+                );
+            }
+
+            for _ in 0..ROUNDS {
+                let table = $name::from_array(rng.array());
+                let idx = $name::from_array(indices(&mut rng, $name::LANES));
+
+                assert_lanes(
+                    &table.$method(idx).to_array(),
+                    &$model(&table.to_array(), &idx.to_array()),
+                    (stringify!($name), table, idx),
+                );
+            }
+        })*};
+    }
+
+    #[test]
+    fn swizzle_dyn() {
+        check!(swizzle_dyn, model: u8x16, u8x32, u8x64);
+    }
+
+    #[test]
+    fn swizzle_dyn_128() {
+        check!(swizzle_dyn_128, model_128: u8x32, u8x64);
+    }
+
+    #[test]
+    fn lanes() {
+        let table = u8x32::from_fn(|i| i as u8 + 100);
+        let idx = u8x32::from_fn(|i| [31, 16, 15, 0][i % 4]);
+
+        assert_eq!(
+            table.swizzle_dyn(idx).to_array(),
+            u8x32::from_fn(|i| [131, 116, 115, 100][i % 4]).to_array()
+        );
+        assert_eq!(
+            table.swizzle_dyn_128(idx).to_array(),
+            u8x32::from_fn(|i| [0, 0, i / 16 * 16 + 115, i / 16 * 16 + 100][i % 4] as u8)
+                .to_array()
+        );
+    }
+}
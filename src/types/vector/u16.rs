@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct u16x1 {
@@ -65,3 +65,5 @@ vectors! {
         half: u16x32,
     }
 }
+
+vector_impls!(u16: u16x1, u16x2, u16x4, u16x8, u16x16, u16x32, u16x64);
@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct u32x1 {
@@ -64,3 +64,5 @@ vectors! {
         half: u32x32,
     }
 }
+
+vector_impls!(u32: u32x1, u32x2, u32x4, u32x8, u32x16, u32x32, u32x64);
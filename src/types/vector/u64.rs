@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct u64x1 {
@@ -65,3 +65,5 @@ vectors! {
         half: u64x32,
     }
 }
+
+vector_impls!(u64: u64x1, u64x2, u64x4, u64x8, u64x16, u64x32, u64x64);
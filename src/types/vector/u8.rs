@@ -1,4 +1,4 @@
-use crate::macros::vectors;
+use crate::macros::{vector_impls, vectors};
 
 vectors! {
     pub struct u8x1 {
@@ -60,8 +60,10 @@ vectors! {
         half: u8x32,
 
         #[cfg(target_arch = "x86_64")]
-        avx: x86_64::__m512i,
+        avx512: x86_64::__m512i,
         #[cfg(target_arch = "x86")]
-        avx: x86::__m512i,
+        avx512: x86::__m512i,
     }
 }
+
+vector_impls!(u8: u8x1, u8x2, u8x4, u8x8, u8x16, u8x32, u8x64);
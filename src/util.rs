@@ -283,3 +283,162 @@ pub(crate) mod bytes {
     const _: () = assert!(matches!(find(b"_", b"help_me"), Some(4)));
     const _: () = assert!(matches!(split(b"_", b"help_me"), Some((b"help", b"me"))));
 }
+
+/// Helpers shared by the unit tests.
+#[cfg(test)]
+pub(crate) mod test {
+    use core::fmt::Debug;
+
+    /// The number of random inputs each operation is checked with.
+    pub(crate) const ROUNDS: usize = 256;
+
+    /// A small xorshift generator, so that tests are reproducible without any
+    /// dependencies.
+    pub(crate) struct Rng(u64);
+
+    impl Rng {
+        pub(crate) fn new(seed: u64) -> Rng {
+            Rng(seed | 1)
+        }
+
+        pub(crate) fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+        }
+
+        pub(crate) fn gen<T: Random>(&mut self) -> T {
+            T::random(self)
+        }
+
+        pub(crate) fn array<T: Random, const N: usize>(&mut self) -> [T; N] {
+            core::array::from_fn(|_| self.gen())
+        }
+    }
+
+    /// A value that can be generated by [`Rng`], biased towards the edge cases.
+    pub(crate) trait Random {
+        fn random(rng: &mut Rng) -> Self;
+    }
+
+    macro_rules! random_int {
+        ($($int:ident),*) => {$(
+            impl Random for $int {
+                fn random(rng: &mut Rng) -> $int {
+                    let bits = rng.next_u64();
+
+                    match bits % 8 {
+                        0 => [0, 1, $int::MIN, $int::MAX, $int::MAX / 2][(bits >> 8) as usize % 5],
+                        1 => (bits >> 8) as $int % 16,
+                        _ => (bits >> 8) as $int,
+                    }
+                }
+            }
+        )*};
+    }
+
+    random_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+    macro_rules! random_float {
+        ($($float:ident),*) => {$(
+            impl Random for $float {
+                fn random(rng: &mut Rng) -> $float {
+                    let bits = rng.next_u64();
+                    let special = [
+                        0.0,
+                        -0.0,
+                        1.0,
+                        $float::INFINITY,
+                        $float::NEG_INFINITY,
+                        $float::NAN,
+                        $float::MIN_POSITIVE,
+                        $float::MAX,
+                    ];
+
+                    match bits % 8 {
+                        0 => special[(bits >> 8) as usize % special.len()],
+                        1 => ((bits >> 8) % 16) as $float,
+                        _ => ((bits >> 8) as i32) as $float / 1024.0,
+                    }
+                }
+            }
+        )*};
+    }
+
+    random_float!(f32, f64);
+
+    impl Random for bool {
+        fn random(rng: &mut Rng) -> bool {
+            rng.next_u64() & 1 != 0
+        }
+    }
+
+    /// Equality where any two NaNs are the same, and zeros of different signs aren't.
+    pub(crate) trait Same: Copy + Debug {
+        fn same(self, other: Self) -> bool;
+    }
+
+    macro_rules! same {
+        (int: $($int:ident),*; float: $($float:ident),*) => {
+            $(
+                impl Same for $int {
+                    fn same(self, other: $int) -> bool {
+                        self == other
+                    }
+                }
+            )*
+            $(
+                impl Same for $float {
+                    fn same(self, other: $float) -> bool {
+                        self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+                    }
+                }
+            )*
+        };
+    }
+
+    same!(int: u8, u16, u32, u64, i8, i16, i32, i64, bool; float: f32, f64);
+
+    /// Assert that every lane of `actual` is the same as in `expected`.
+    #[track_caller]
+    pub(crate) fn assert_lanes<T: Same>(actual: &[T], expected: &[T], context: impl Debug) {
+        assert_eq!(actual.len(), expected.len());
+
+        for (lane, (&actual, &expected)) in actual.iter().zip(expected).enumerate() {
+            assert!(
+                actual.same(expected),
+                "lane {lane} is {actual:?} instead of {expected:?} for {context:?}",
+            );
+        }
+    }
+
+    /// Check an operation on vectors against a model of a single lane, using random
+    /// vectors of every given type as the arguments.
+    macro_rules! lanes {
+        ($($vector:ident),+; $args:tt => $op:expr, $lane:expr) => {$(
+            $crate::util::test::lanes!(@vector $vector; $args => $op, $lane);
+        )+};
+
+        (@vector $vector:ident; ($($arg:ident),+) => $op:expr, $lane:expr) => {{
+            let mut rng = $crate::util::test::Rng::new(line!() as u64);
+
+            for _ in 0..$crate::util::test::ROUNDS {
+                $(let $arg = $vector::from_array(rng.array());)+
+                let expected: [_; $vector::LANES] = core::array::from_fn(|i| {
+                    $(let $arg = $arg.to_array()[i];)+
+                    $lane
+                });
+                let actual: $vector = $op;
+
+                $crate::util::test::assert_lanes(
+                    &actual.to_array(),
+                    &expected,
+                    (stringify!($vector), $($arg),+),
+                );
+            }
+        }};
+    }
+
+    pub(crate) use lanes;
+}